@@ -22,6 +22,15 @@ impl DbDriver {
             anyhow::bail!("Unsupported database URL. Supported: mysql://, postgres://, sqlite://")
         }
     }
+
+    /// 数据库类型名称（与 `sql` 子命令的 `--database` 取值一致）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MySql => "mysql",
+            Self::Postgres => "postgres",
+            Self::Sqlite => "sqlite",
+        }
+    }
 }
 
 /// 数据库连接池（枚举类型以支持不同数据库）
@@ -58,6 +67,40 @@ impl DbPool {
         }
     }
 
+    /// 使用单个连接连接到数据库
+    ///
+    /// 迁移使用单连接执行：SQLite 的其他连接可能缓存旧的表结构，导致后续 DDL 失败
+    pub async fn connect_single(url: &str) -> Result<Self> {
+        let driver = DbDriver::from_url(url)?;
+
+        match driver {
+            DbDriver::MySql => {
+                let pool = sqlx::mysql::MySqlPoolOptions::new()
+                    .max_connections(1)
+                    .connect(url)
+                    .await
+                    .context("Failed to connect to MySQL database")?;
+                Ok(Self::MySql(pool))
+            }
+            DbDriver::Postgres => {
+                let pool = sqlx::postgres::PgPoolOptions::new()
+                    .max_connections(1)
+                    .connect(url)
+                    .await
+                    .context("Failed to connect to PostgreSQL database")?;
+                Ok(Self::Postgres(pool))
+            }
+            DbDriver::Sqlite => {
+                let pool = sqlx::sqlite::SqlitePoolOptions::new()
+                    .max_connections(1)
+                    .connect(url)
+                    .await
+                    .context("Failed to connect to SQLite database")?;
+                Ok(Self::Sqlite(pool))
+            }
+        }
+    }
+
     /// 获取驱动类型
     pub fn driver(&self) -> DbDriver {
         match self {
@@ -323,42 +366,40 @@ impl DbPool {
                     .await
                     .context("Failed to query SQLite table columns")?;
 
-                // SQLite 索引信息需要通过 sqlite_master 查询
-                // 注意：SQLite 的索引查询比较复杂，这里简化处理
-                let indexes_query = sqlx::query(
-                    r#"
-                    SELECT 
-                        sql
-                    FROM sqlite_master
-                    WHERE type = 'index' AND tbl_name = ? AND name NOT LIKE 'sqlite_%'
-                    "#,
-                )
-                .bind(table_name)
-                .fetch_all(pool)
-                .await
-                .context("Failed to query SQLite indexes")?;
+                // SQLite 索引信息通过 PRAGMA index_list / index_info 查询
+                // index_list 包含 UNIQUE 约束自动创建的索引（sqlite_autoindex_*），不包含主键 rowid
+                let index_list_query = format!("PRAGMA index_list(\"{}\")", table_name);
+                let index_list = sqlx::query(&index_list_query)
+                    .fetch_all(pool)
+                    .await
+                    .context("Failed to query SQLite indexes")?;
 
-                // 构建索引映射（简化处理，从 SQL 中解析）
+                // 构建索引映射（一个字段可能有多个索引，优先 unique）
                 let mut index_map: std::collections::HashMap<String, (bool, bool)> = std::collections::HashMap::new();
-                for row in indexes_query {
-                    let sql: Option<String> = row.get(0);
-                    if let Some(sql) = sql {
-                        // 简单解析：如果包含 UNIQUE 则是唯一索引
-                        let is_unique = sql.to_uppercase().contains("UNIQUE");
-                        // 从 SQL 中提取列名（简化处理）
-                        if let Some(start) = sql.find('(') {
-                            if let Some(end) = sql.find(')') {
-                                let cols_str = &sql[start + 1..end];
-                                for col in cols_str.split(',') {
-                                    let col_name = col.trim().trim_matches('"').trim_matches('\'').to_string();
-                                    let entry = index_map.entry(col_name).or_insert((false, false));
-                                    if is_unique {
-                                        entry.0 = true;
-                                    } else {
-                                        entry.1 = true;
-                                    }
-                                }
-                            }
+                for index_row in index_list {
+                    let index_name: String = index_row.get(1);
+                    let is_unique: bool = index_row.get::<i32, _>(2) != 0;
+                    let origin: String = index_row.get(3);
+                    // 跳过主键索引
+                    if origin == "pk" {
+                        continue;
+                    }
+
+                    let index_info_query = format!("PRAGMA index_info(\"{}\")", index_name);
+                    let index_columns = sqlx::query(&index_info_query)
+                        .fetch_all(pool)
+                        .await
+                        .context("Failed to query SQLite index columns")?;
+                    for column_row in index_columns {
+                        let col_name: Option<String> = column_row.get(2);
+                        let Some(col_name) = col_name else {
+                            continue;
+                        };
+                        let entry = index_map.entry(col_name).or_insert((false, false));
+                        if is_unique {
+                            entry.0 = true;
+                        } else {
+                            entry.1 = true;
                         }
                     }
                 }
@@ -440,7 +481,7 @@ impl From<ColumnRow> for super::generator::ColumnInfo {
 }
 
 /// 从 SQL 类型中提取长度（如 VARCHAR(255) -> 255）
-fn extract_length_from_sql_type(sql_type: &str) -> Option<u32> {
+pub fn extract_length_from_sql_type(sql_type: &str) -> Option<u32> {
     // 匹配类似 VARCHAR(255), CHAR(10) 等格式
     if let Some(start) = sql_type.find('(') {
         if let Some(end) = sql_type.find(')') {
@@ -0,0 +1,855 @@
+use crate::database::extract_length_from_sql_type;
+use crate::generator::{ColumnInfo, TableInfo};
+use anyhow::Result;
+
+/// SQL 词法单元
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// 关键字、未加引号的标识符或数字
+    Word(String),
+    /// 加引号的标识符（`name` / "name" / [name]）
+    Quoted(String),
+    /// 字符串字面量（'text'，MySQL 中也包括 "text"）
+    Str(String),
+    Symbol(char),
+}
+
+/// 解析 DDL 文件（CREATE TABLE / CREATE INDEX / ALTER TABLE ... ADD / COMMENT ON），
+/// 生成与从数据库读取时相同的表结构信息
+///
+/// 不认识的语句（INSERT、SET、CREATE SEQUENCE 等）会被忽略
+pub fn parse_ddl(sql: &str, dialect: &str) -> Result<Vec<TableInfo>> {
+    let dialect = dialect.to_lowercase();
+    if !matches!(dialect.as_str(), "mysql" | "postgres" | "sqlite") {
+        anyhow::bail!("Unsupported dialect: {}. Supported: mysql, postgres, sqlite", dialect);
+    }
+
+    let tokens = tokenize(sql, &dialect)?;
+    let mut tables: Vec<TableInfo> = Vec::new();
+    for statement in tokens.split(|t| *t == Token::Symbol(';')) {
+        if is_kw(statement.first(), "CREATE") {
+            parse_create(statement, &mut tables);
+        } else if is_kw(statement.first(), "ALTER") && is_kw(statement.get(1), "TABLE") {
+            parse_alter_table(statement, &mut tables);
+        } else if is_kw(statement.first(), "COMMENT") && is_kw(statement.get(1), "ON") {
+            parse_comment(statement, &mut tables);
+        }
+    }
+
+    Ok(tables)
+}
+
+/// 词法分析：跳过注释，识别标识符、字符串和符号
+fn tokenize(sql: &str, dialect: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    // 读取以 close 结尾的引号内容：连续两个 close 为转义，backslash 表示支持反斜杠转义（MySQL）
+    let read_quoted = |i: &mut usize, close: char, backslash: bool| -> Result<String> {
+        let start = *i;
+        let mut value = String::new();
+        *i += 1;
+        while *i < chars.len() {
+            let c = chars[*i];
+            if backslash && c == '\\' && *i + 1 < chars.len() {
+                value.push(match chars[*i + 1] {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '0' => '\0',
+                    other => other,
+                });
+                *i += 2;
+            } else if c == close {
+                if chars.get(*i + 1) == Some(&close) {
+                    value.push(close);
+                    *i += 2;
+                } else {
+                    *i += 1;
+                    return Ok(value);
+                }
+            } else {
+                value.push(c);
+                *i += 1;
+            }
+        }
+        let line = chars[..start].iter().filter(|c| **c == '\n').count() + 1;
+        anyhow::bail!("Unterminated quoted text starting at line {}", line)
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '-' if next == Some('-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '#' if dialect == "mysql" => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            '\'' => tokens.push(Token::Str(read_quoted(&mut i, '\'', dialect == "mysql")?)),
+            '"' if dialect == "mysql" => tokens.push(Token::Str(read_quoted(&mut i, '"', true)?)),
+            '"' => tokens.push(Token::Quoted(read_quoted(&mut i, '"', false)?)),
+            '`' => tokens.push(Token::Quoted(read_quoted(&mut i, '`', false)?)),
+            '[' if dialect == "sqlite" => tokens.push(Token::Quoted(read_quoted(&mut i, ']', false)?)),
+            // PostgreSQL 美元符号引用（$$ ... $$ / $tag$ ... $tag$），整体作为字符串
+            '$' if dialect == "postgres" => {
+                let tag_end = (i + 1..chars.len())
+                    .find(|&j| !(chars[j].is_alphanumeric() || chars[j] == '_'))
+                    .filter(|&j| chars[j] == '$');
+                match tag_end {
+                    Some(tag_end) => {
+                        let tag: String = chars[i..=tag_end].iter().collect();
+                        let body_start = tag_end + 1;
+                        let rest: String = chars[body_start..].iter().collect();
+                        let body_len = rest.find(&tag).map(|p| rest[..p].chars().count());
+                        let Some(body_len) = body_len else {
+                            anyhow::bail!("Unterminated dollar-quoted string {}", tag);
+                        };
+                        tokens.push(Token::Str(chars[body_start..body_start + body_len].iter().collect()));
+                        i = body_start + body_len + tag.chars().count();
+                    }
+                    None => {
+                        tokens.push(Token::Symbol(c));
+                        i += 1;
+                    }
+                }
+            }
+            _ if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                let numeric = c.is_ascii_digit();
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric()
+                        || chars[i] == '_'
+                        || chars[i] == '$'
+                        || (numeric && chars[i] == '.'))
+                {
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            }
+            _ => {
+                tokens.push(Token::Symbol(c));
+                i += 1;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// CREATE [TEMPORARY] TABLE / CREATE [UNIQUE] INDEX
+fn parse_create(statement: &[Token], tables: &mut Vec<TableInfo>) {
+    // 只在开头的修饰关键字中查找（CREATE [OR REPLACE] [TEMPORARY] [UNIQUE] TABLE / INDEX ...），避免误识别触发器等语句体
+    let Some(kind) = statement.iter().take(5).position(|t| {
+        is_kw(Some(t), "TABLE") || is_kw(Some(t), "INDEX") || is_kw(Some(t), "VIEW") || is_kw(Some(t), "SEQUENCE")
+    }) else {
+        return;
+    };
+
+    if is_kw(statement.get(kind), "INDEX") {
+        let unique = statement[..kind].iter().any(|t| is_kw(Some(t), "UNIQUE"));
+        let Some(on) = statement.iter().position(|t| is_kw(Some(t), "ON")) else {
+            return;
+        };
+        let mut i = on + 1;
+        skip_keywords(statement, &mut i, &["ONLY"]);
+        let Some(table_name) = qualified_name(statement, &mut i) else {
+            return;
+        };
+        if let (Some(table), Some(columns)) = (find_table(tables, &table_name), first_group(statement, i)) {
+            mark_index(table, &column_list(columns), unique);
+        }
+        return;
+    }
+    if !is_kw(statement.get(kind), "TABLE") {
+        return;
+    }
+
+    let mut i = kind + 1;
+    skip_keywords(statement, &mut i, &["IF", "NOT", "EXISTS"]);
+    let Some(table_name) = qualified_name(statement, &mut i) else {
+        return;
+    };
+    // CREATE TABLE ... AS SELECT / LIKE 不包含列定义
+    if statement.get(i) != Some(&Token::Symbol('(')) {
+        return;
+    }
+    let (body, end) = group(statement, i);
+
+    let mut table = TableInfo {
+        name: table_name,
+        columns: Vec::new(),
+        table_comment: None,
+    };
+    for definition in split_top_level(body) {
+        if !parse_table_constraint(definition, &mut table) {
+            if let Some(column) = parse_column(definition) {
+                table.columns.push(column);
+            }
+        }
+    }
+
+    // 表选项：MySQL 的 COMMENT [=] 'text'
+    let options = &statement[end..];
+    if let Some(pos) = options.iter().position(|t| is_kw(Some(t), "COMMENT")) {
+        let value = match options.get(pos + 1) {
+            Some(Token::Symbol('=')) => options.get(pos + 2),
+            other => other,
+        };
+        if let Some(Token::Str(comment)) = value {
+            table.table_comment = non_empty(comment);
+        }
+    }
+
+    tables.retain(|t| !t.name.eq_ignore_ascii_case(&table.name));
+    tables.push(table);
+}
+
+/// ALTER TABLE name ADD ... / ALTER COLUMN ... SET DEFAULT / ADD GENERATED（pg_dump 的输出格式）
+fn parse_alter_table(statement: &[Token], tables: &mut [TableInfo]) {
+    let mut i = 2;
+    skip_keywords(statement, &mut i, &["IF", "EXISTS", "ONLY"]);
+    let Some(table_name) = qualified_name(statement, &mut i) else {
+        return;
+    };
+    let Some(table) = find_table(tables, &table_name) else {
+        return;
+    };
+
+    for action in split_top_level(&statement[i..]) {
+        if is_kw(action.first(), "ADD") {
+            let rest = &action[1..];
+            if is_kw(rest.first(), "COLUMN") {
+                let mut j = 1;
+                skip_keywords(rest, &mut j, &["IF", "NOT", "EXISTS"]);
+                if let Some(column) = parse_column(&rest[j..]) {
+                    table.columns.push(column);
+                }
+            } else if !parse_table_constraint(rest, table) {
+                if let Some(column) = parse_column(rest) {
+                    table.columns.push(column);
+                }
+            }
+        } else if is_kw(action.first(), "ALTER") {
+            let mut j = 1;
+            skip_keywords(action, &mut j, &["COLUMN"]);
+            let Some(column_name) = action.get(j).and_then(ident) else {
+                continue;
+            };
+            let Some(column) = find_column(table, &column_name) else {
+                continue;
+            };
+            let rest = &action[j + 1..];
+            if is_kw(rest.first(), "SET") && is_kw(rest.get(1), "DEFAULT") {
+                let (default, _) = parse_default(rest, 2);
+                if default.as_deref().is_some_and(|d| d.starts_with("nextval")) {
+                    column.auto_increment = true;
+                }
+                column.default = default;
+            } else if is_kw(rest.first(), "ADD") && is_kw(rest.get(1), "GENERATED") {
+                column.auto_increment = true;
+            } else if is_kw(rest.first(), "SET") && is_kw(rest.get(1), "NOT") {
+                column.nullable = false;
+            }
+        }
+    }
+}
+
+/// COMMENT ON TABLE name IS '...' / COMMENT ON COLUMN table.column IS '...'
+fn parse_comment(statement: &[Token], tables: &mut [TableInfo]) {
+    let mut i = 3;
+    let mut parts = Vec::new();
+    while let Some(part) = statement.get(i).and_then(ident) {
+        parts.push(part);
+        i += 1;
+        if statement.get(i) != Some(&Token::Symbol('.')) {
+            break;
+        }
+        i += 1;
+    }
+    if !is_kw(statement.get(i), "IS") {
+        return;
+    }
+    let comment = match statement.get(i + 1) {
+        Some(Token::Str(comment)) => non_empty(comment),
+        _ => None,
+    };
+
+    if is_kw(statement.get(2), "TABLE") {
+        if let Some(table) = parts.last().and_then(|name| find_table(tables, name)) {
+            table.table_comment = comment;
+        }
+    } else if is_kw(statement.get(2), "COLUMN") && parts.len() >= 2 {
+        let column_name = &parts[parts.len() - 1];
+        let table_name = &parts[parts.len() - 2];
+        if let Some(column) = find_table(tables, table_name).and_then(|t| find_column(t, column_name)) {
+            column.comment = comment;
+        }
+    }
+}
+
+/// 表级约束和索引（PRIMARY KEY / UNIQUE / KEY / INDEX / CONSTRAINT / FOREIGN KEY / CHECK），
+/// 返回 false 表示该定义是列定义
+fn parse_table_constraint(definition: &[Token], table: &mut TableInfo) -> bool {
+    let mut i = 0;
+    if is_kw(definition.first(), "CONSTRAINT") {
+        i = 2;
+    }
+    let Some(Token::Word(keyword)) = definition.get(i) else {
+        return false;
+    };
+
+    let columns = || first_group(definition, i).map(column_list).unwrap_or_default();
+    match keyword.to_uppercase().as_str() {
+        "PRIMARY" => {
+            for name in columns() {
+                if let Some(column) = find_column(table, &name) {
+                    column.is_pk = true;
+                    column.nullable = false;
+                }
+            }
+        }
+        "UNIQUE" => mark_index(table, &columns(), true),
+        "KEY" | "INDEX" | "FULLTEXT" | "SPATIAL" => mark_index(table, &columns(), false),
+        "FOREIGN" | "CHECK" | "EXCLUDE" | "PERIOD" => {}
+        _ => return false,
+    }
+    true
+}
+
+/// 列定义：列名 类型 [约束...]
+fn parse_column(definition: &[Token]) -> Option<ColumnInfo> {
+    let name = ident(definition.first()?)?;
+
+    // 类型：直到遇到列约束关键字为止，括号内的参数附加到前一个单词（varchar(255)、decimal(10,2)）
+    let mut type_parts: Vec<String> = Vec::new();
+    let mut i = 1;
+    while i < definition.len() {
+        match &definition[i] {
+            Token::Word(word) if is_column_keyword(word, !type_parts.is_empty()) => break,
+            Token::Word(word) | Token::Quoted(word) => {
+                type_parts.push(word.clone());
+                i += 1;
+            }
+            Token::Symbol('(') => {
+                let (args, end) = group(definition, i);
+                let args = render(args).replace(' ', "");
+                match type_parts.last_mut() {
+                    Some(last) => last.push_str(&format!("({})", args)),
+                    None => type_parts.push(format!("({})", args)),
+                }
+                i = end;
+            }
+            Token::Symbol('[') => {
+                // PostgreSQL 数组类型 int[]
+                let end = definition[i..]
+                    .iter()
+                    .position(|t| *t == Token::Symbol(']'))
+                    .map_or(definition.len(), |p| i + p + 1);
+                if let Some(last) = type_parts.last_mut() {
+                    last.push_str("[]");
+                }
+                i = end;
+            }
+            _ => break,
+        }
+    }
+    // SQLite 允许省略类型
+    let sql_type = if type_parts.is_empty() {
+        "TEXT".to_string()
+    } else {
+        type_parts.join(" ")
+    };
+
+    let mut column = ColumnInfo {
+        name,
+        length: extract_length_from_sql_type(&sql_type),
+        auto_increment: matches!(
+            sql_type.to_uppercase().as_str(),
+            "SERIAL" | "BIGSERIAL" | "SMALLSERIAL"
+        ),
+        sql_type,
+        nullable: true,
+        is_pk: false,
+        default: None,
+        is_unique: false,
+        has_index: false,
+        comment: None,
+    };
+
+    while i < definition.len() {
+        let token = &definition[i];
+        let Token::Word(word) = token else {
+            i = if *token == Token::Symbol('(') { group(definition, i).1 } else { i + 1 };
+            continue;
+        };
+        match word.to_uppercase().as_str() {
+            "NOT" if is_kw(definition.get(i + 1), "NULL") => {
+                column.nullable = false;
+                i += 2;
+            }
+            "NULL" => {
+                column.nullable = true;
+                i += 1;
+            }
+            "DEFAULT" => {
+                let (default, end) = parse_default(definition, i + 1);
+                if default.as_deref().is_some_and(|d| d.starts_with("nextval")) {
+                    column.auto_increment = true;
+                }
+                column.default = default;
+                i = end;
+            }
+            "PRIMARY" => {
+                column.is_pk = true;
+                column.nullable = false;
+                i += 1;
+            }
+            "KEY" if !column.is_unique => {
+                // MySQL 的列级 KEY 等同于 PRIMARY KEY
+                column.is_pk = true;
+                column.nullable = false;
+                i += 1;
+            }
+            "UNIQUE" => {
+                column.is_unique = true;
+                i += 1;
+            }
+            "AUTO_INCREMENT" | "AUTOINCREMENT" | "IDENTITY" => {
+                column.auto_increment = true;
+                i += 1;
+            }
+            "COMMENT" => {
+                if let Some(Token::Str(comment)) = definition.get(i + 1) {
+                    column.comment = non_empty(comment);
+                }
+                i += 2;
+            }
+            "CONSTRAINT" => i += 2,
+            _ => i += 1,
+        }
+    }
+
+    Some(column)
+}
+
+/// 解析 DEFAULT 后的表达式，返回 (默认值, 结束位置)
+///
+/// 字符串字面量去掉引号（包括 PostgreSQL 的 'text'::type），与从 MySQL 读取的默认值格式一致；
+/// 函数调用和数字保持原样，DEFAULT NULL 视为没有默认值
+fn parse_default(tokens: &[Token], start: usize) -> (Option<String>, usize) {
+    let mut end = start;
+    while end < tokens.len() {
+        match &tokens[end] {
+            Token::Word(word) if end > start && is_column_keyword(word, true) => break,
+            Token::Symbol('(') => end = group(tokens, end).1,
+            _ => end += 1,
+        }
+    }
+
+    let mut expr = &tokens[start..end];
+    // SQLite 的 DEFAULT (0) / DEFAULT (CURRENT_TIMESTAMP)
+    if expr.first() == Some(&Token::Symbol('(')) && group(expr, 0).1 == expr.len() {
+        expr = group(expr, 0).0;
+    }
+    let is_cast = |rest: &[Token]| rest.starts_with(&[Token::Symbol(':'), Token::Symbol(':')]);
+    let default = match expr {
+        [] => None,
+        [Token::Word(word)] if word.eq_ignore_ascii_case("NULL") => None,
+        [Token::Str(value)] => Some(value.clone()),
+        [Token::Str(value), rest @ ..] if is_cast(rest) => Some(value.clone()),
+        _ => Some(render(expr)),
+    };
+    (default, end)
+}
+
+/// 列约束关键字（类型名之后出现，表示类型结束）
+fn is_column_keyword(word: &str, after_type: bool) -> bool {
+    let upper = word.to_uppercase();
+    matches!(
+        upper.as_str(),
+        "NOT"
+            | "NULL"
+            | "DEFAULT"
+            | "AUTO_INCREMENT"
+            | "AUTOINCREMENT"
+            | "PRIMARY"
+            | "UNIQUE"
+            | "KEY"
+            | "COMMENT"
+            | "REFERENCES"
+            | "CHECK"
+            | "COLLATE"
+            | "GENERATED"
+            | "ON"
+            | "CONSTRAINT"
+            | "AS"
+            | "CHARSET"
+            | "STORED"
+            | "VIRTUAL"
+            | "INVISIBLE"
+            | "VISIBLE"
+            | "IDENTITY"
+    ) || (after_type && upper == "CHARACTER")
+}
+
+/// 还原表达式文本：相邻单词之间加空格，其余紧密拼接（b'0' 等前缀字面量保持相连）
+fn render(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    let mut previous_word = false;
+    for token in tokens {
+        let is_word = matches!(token, Token::Word(_) | Token::Quoted(_));
+        if is_word && previous_word {
+            out.push(' ');
+        }
+        match token {
+            Token::Word(word) => out.push_str(word),
+            Token::Quoted(name) => out.push_str(&format!("\"{}\"", name)),
+            Token::Str(value) => out.push_str(&format!("'{}'", value.replace('\'', "''"))),
+            Token::Symbol(c) => out.push(*c),
+        }
+        previous_word = is_word || matches!(token, Token::Str(_));
+    }
+    out
+}
+
+/// 返回从 start（左括号）开始的括号内的词法单元和右括号之后的位置
+fn group(tokens: &[Token], start: usize) -> (&[Token], usize) {
+    let mut depth = 0;
+    for (offset, token) in tokens[start..].iter().enumerate() {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return (&tokens[start + 1..start + offset], start + offset + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    (&tokens[(start + 1).min(tokens.len())..], tokens.len())
+}
+
+/// 从 start 开始的第一个括号组
+fn first_group(tokens: &[Token], start: usize) -> Option<&[Token]> {
+    let open = tokens[start..].iter().position(|t| *t == Token::Symbol('('))?;
+    Some(group(tokens, start + open).0)
+}
+
+/// 按顶层逗号拆分
+fn split_top_level(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth -= 1,
+            Token::Symbol(',') if depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// 索引列列表：每项取第一个标识符（忽略 ASC/DESC、前缀长度等）
+fn column_list(tokens: &[Token]) -> Vec<String> {
+    split_top_level(tokens)
+        .into_iter()
+        .filter_map(|part| part.first().and_then(ident))
+        .collect()
+}
+
+/// 读取可能带 schema 前缀的名称（schema.table），返回最后一段
+fn qualified_name(tokens: &[Token], i: &mut usize) -> Option<String> {
+    let mut name = tokens.get(*i).and_then(ident)?;
+    *i += 1;
+    while tokens.get(*i) == Some(&Token::Symbol('.')) {
+        name = tokens.get(*i + 1).and_then(ident)?;
+        *i += 2;
+    }
+    Some(name)
+}
+
+fn skip_keywords(tokens: &[Token], i: &mut usize, keywords: &[&str]) {
+    while keywords.iter().any(|kw| is_kw(tokens.get(*i), kw)) {
+        *i += 1;
+    }
+}
+
+fn mark_index(table: &mut TableInfo, columns: &[String], unique: bool) {
+    for name in columns {
+        if let Some(column) = find_column(table, name) {
+            if unique {
+                column.is_unique = true;
+            } else {
+                column.has_index = true;
+            }
+        }
+    }
+}
+
+fn find_table<'a>(tables: &'a mut [TableInfo], name: &str) -> Option<&'a mut TableInfo> {
+    tables.iter_mut().find(|t| t.name.eq_ignore_ascii_case(name))
+}
+
+fn find_column<'a>(table: &'a mut TableInfo, name: &str) -> Option<&'a mut ColumnInfo> {
+    table.columns.iter_mut().find(|c| c.name.eq_ignore_ascii_case(name))
+}
+
+fn ident(token: &Token) -> Option<String> {
+    match token {
+        Token::Word(word) | Token::Quoted(word) => Some(word.clone()),
+        _ => None,
+    }
+}
+
+fn is_kw(token: Option<&Token>, keyword: &str) -> bool {
+    matches!(token, Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (类型, 可空, 主键, 默认值, 自增, 唯一, 索引, 注释)
+    type Summary<'a> = (&'a str, bool, bool, Option<&'a str>, bool, bool, bool, Option<&'a str>);
+
+    fn summary(column: &ColumnInfo) -> Summary<'_> {
+        (
+            column.sql_type.as_str(),
+            column.nullable,
+            column.is_pk,
+            column.default.as_deref(),
+            column.auto_increment,
+            column.is_unique,
+            column.has_index,
+            column.comment.as_deref(),
+        )
+    }
+
+    fn assert_columns(table: &TableInfo, expected: &[(&str, Summary<'_>)]) {
+        let names: Vec<&str> = table.columns.iter().map(|c| c.name.as_str()).collect();
+        let expected_names: Vec<&str> = expected.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, expected_names, "columns of {}", table.name);
+        for (column, (name, expected)) in table.columns.iter().zip(expected) {
+            assert_eq!(summary(column), *expected, "{}.{}", table.name, name);
+        }
+    }
+
+    const MYSQL_DUMP: &str = r#"
+-- MySQL dump 10.13  Distrib 8.0.36, for Linux (x86_64)
+/*!40101 SET @OLD_CHARACTER_SET_CLIENT=@@CHARACTER_SET_CLIENT */;
+/*!40101 SET NAMES utf8mb4 */;
+
+DROP TABLE IF EXISTS `user`;
+CREATE TABLE `user` (
+  `id` bigint(20) unsigned NOT NULL AUTO_INCREMENT,
+  `email` varchar(64) NOT NULL COMMENT 'user''s email',
+  `nick``name` varchar(32) DEFAULT 'it\'s me',
+  `is_del` tinyint(1) NOT NULL DEFAULT '0',
+  `created_at` datetime(3) DEFAULT CURRENT_TIMESTAMP(3),
+  `score` decimal(10,2) DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_email` (`email`),
+  KEY `idx_created` (`created_at`)
+) ENGINE=InnoDB AUTO_INCREMENT=3 DEFAULT CHARSET=utf8mb4 COMMENT='users; and more';
+
+LOCK TABLES `user` WRITE;
+INSERT INTO `user` VALUES (1,'a@b.c','x;y',0,'2024-01-01 00:00:00.000',NULL);
+UNLOCK TABLES;
+
+# column-level KEY means PRIMARY KEY
+CREATE TABLE tag (
+  id int KEY,
+  name varchar(20) UNIQUE KEY
+) COMMENT = 'tags';
+"#;
+
+    #[test]
+    fn test_parse_mysql_dump() {
+        let tables = parse_ddl(MYSQL_DUMP, "mysql").unwrap();
+        assert_eq!(tables.len(), 2);
+
+        let user = &tables[0];
+        assert_eq!(user.name, "user");
+        assert_eq!(user.table_comment.as_deref(), Some("users; and more"));
+        assert_columns(
+            user,
+            &[
+                ("id", ("bigint(20) unsigned", false, true, None, true, false, false, None)),
+                ("email", ("varchar(64)", false, false, None, false, true, false, Some("user's email"))),
+                ("nick`name", ("varchar(32)", true, false, Some("it's me"), false, false, false, None)),
+                ("is_del", ("tinyint(1)", false, false, Some("0"), false, false, false, None)),
+                ("created_at", ("datetime(3)", true, false, Some("CURRENT_TIMESTAMP(3)"), false, false, true, None)),
+                ("score", ("decimal(10,2)", true, false, None, false, false, false, None)),
+            ],
+        );
+        assert_eq!(user.columns[1].length, Some(64));
+
+        let tag = &tables[1];
+        assert_eq!(tag.table_comment.as_deref(), Some("tags"));
+        assert_columns(
+            tag,
+            &[
+                ("id", ("int", false, true, None, false, false, false, None)),
+                ("name", ("varchar(20)", true, false, None, false, true, false, None)),
+            ],
+        );
+    }
+
+    const PG_DUMP: &str = r#"
+--
+-- PostgreSQL database dump
+--
+SET statement_timeout = 0;
+SELECT pg_catalog.set_config('search_path', '', false);
+
+CREATE FUNCTION public.touch_updated_at() RETURNS trigger
+    LANGUAGE plpgsql
+    AS $_$
+BEGIN
+    NEW.updated_at := now(); -- CREATE TABLE fake (x int);
+    RETURN NEW;
+END;
+$_$;
+
+CREATE TABLE public."user" (
+    id bigint NOT NULL,
+    email character varying(64) NOT NULL,
+    status character varying(16) DEFAULT 'active'::character varying,
+    "Display Name" text,
+    score numeric(10,2) DEFAULT 0.0,
+    tags text[],
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+COMMENT ON TABLE public."user" IS 'users';
+COMMENT ON COLUMN public."user".email IS 'user''s email';
+
+CREATE SEQUENCE public.user_id_seq
+    START WITH 1
+    INCREMENT BY 1
+    NO MINVALUE
+    NO MAXVALUE
+    CACHE 1;
+ALTER SEQUENCE public.user_id_seq OWNED BY public."user".id;
+ALTER TABLE ONLY public."user" ALTER COLUMN id SET DEFAULT nextval('public.user_id_seq'::regclass);
+
+ALTER TABLE ONLY public."user"
+    ADD CONSTRAINT user_pkey PRIMARY KEY (id);
+ALTER TABLE ONLY public."user"
+    ADD CONSTRAINT user_email_key UNIQUE (email);
+CREATE INDEX idx_user_created_at ON public."user" USING btree (created_at);
+"#;
+
+    #[test]
+    fn test_parse_pg_dump() {
+        let tables = parse_ddl(PG_DUMP, "postgres").unwrap();
+        assert_eq!(tables.len(), 1);
+
+        let user = &tables[0];
+        assert_eq!(user.name, "user");
+        assert_eq!(user.table_comment.as_deref(), Some("users"));
+        assert_columns(
+            user,
+            &[
+                (
+                    "id",
+                    ("bigint", false, true, Some("nextval('public.user_id_seq'::regclass)"), true, false, false, None),
+                ),
+                (
+                    "email",
+                    ("character varying(64)", false, false, None, false, true, false, Some("user's email")),
+                ),
+                ("status", ("character varying(16)", true, false, Some("active"), false, false, false, None)),
+                ("Display Name", ("text", true, false, None, false, false, false, None)),
+                ("score", ("numeric(10,2)", true, false, Some("0.0"), false, false, false, None)),
+                ("tags", ("text[]", true, false, None, false, false, false, None)),
+                (
+                    "created_at",
+                    ("timestamp with time zone", false, false, Some("now()"), false, false, true, None),
+                ),
+            ],
+        );
+        assert_eq!(user.columns[1].length, Some(64));
+    }
+
+    const SQLITE_DUMP: &str = r#"
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE IF NOT EXISTS "post" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  [title] TEXT NOT NULL DEFAULT '',
+  `body`,
+  views INTEGER DEFAULT (0),
+  created_at TEXT DEFAULT (CURRENT_TIMESTAMP),
+  user_id INTEGER REFERENCES user(id) ON DELETE CASCADE,
+  UNIQUE (title)
+);
+INSERT INTO post VALUES(1,'a;b','x',0,'2024-01-01',1);
+CREATE INDEX idx_post_user ON post (user_id);
+CREATE TABLE note (id INTEGER, content TEXT, PRIMARY KEY (id));
+CREATE TABLE note_copy AS SELECT * FROM note;
+COMMIT;
+"#;
+
+    #[test]
+    fn test_parse_sqlite_dump() {
+        let tables = parse_ddl(SQLITE_DUMP, "sqlite").unwrap();
+        let names: Vec<&str> = tables.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["post", "note"]);
+
+        assert_columns(
+            &tables[0],
+            &[
+                ("id", ("INTEGER", false, true, None, true, false, false, None)),
+                ("title", ("TEXT", false, false, Some(""), false, true, false, None)),
+                ("body", ("TEXT", true, false, None, false, false, false, None)),
+                ("views", ("INTEGER", true, false, Some("0"), false, false, false, None)),
+                ("created_at", ("TEXT", true, false, Some("CURRENT_TIMESTAMP"), false, false, false, None)),
+                ("user_id", ("INTEGER", true, false, None, false, false, true, None)),
+            ],
+        );
+        assert_columns(
+            &tables[1],
+            &[
+                ("id", ("INTEGER", false, true, None, false, false, false, None)),
+                ("content", ("TEXT", true, false, None, false, false, false, None)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_ddl_errors() {
+        assert!(parse_ddl("CREATE TABLE t (id int)", "oracle").is_err());
+
+        let err = parse_ddl("CREATE TABLE t (\n  name text DEFAULT 'oops\n);", "postgres").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+
+        assert!(parse_ddl("CREATE FUNCTION f() AS $body$ SELECT 1;", "postgres").is_err());
+    }
+}
@@ -0,0 +1,601 @@
+use crate::generator::{ColumnInfo, TableInfo};
+use crate::sql_generator::{ModelColumn, ModelTable, SqlGenerator};
+use std::fmt;
+
+/// 模型与数据库表结构之间的一处差异
+#[derive(Debug, Clone)]
+pub enum Difference {
+    /// 数据库中缺少整张表
+    MissingTable,
+    /// 模型中有、数据库中缺少的列
+    AddedColumn(String),
+    /// 数据库中有、模型中没有的列
+    RemovedColumn(String),
+    /// 列类型不同
+    Type { column: String, model: String, database: String },
+    /// 列类型相同但长度不同（如 VARCHAR(64) / VARCHAR(255)）
+    Length { column: String, model: String, database: String },
+    /// 可空性不同
+    Nullable { column: String, model: bool, database: bool },
+    /// 默认值不同
+    Default { column: String, model: Option<String>, database: Option<String> },
+    /// 列注释不同
+    Comment { column: String, model: Option<String>, database: Option<String> },
+    /// 数据库中缺少模型声明的索引
+    MissingIndex { name: String, columns: Vec<String>, unique: bool },
+    /// 数据库中有、模型中没有声明的索引（只报告，不生成 DROP INDEX，因为无法得知索引名）
+    ExtraIndex { column: String, unique: bool },
+    /// 表注释不同
+    TableComment { model: Option<String>, database: Option<String> },
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn show(value: &Option<String>) -> String {
+            match value {
+                Some(value) => format!("{:?}", value),
+                None => "none".to_string(),
+            }
+        }
+        fn nullable(value: bool) -> &'static str {
+            if value {
+                "NULL"
+            } else {
+                "NOT NULL"
+            }
+        }
+        fn kind(unique: bool) -> &'static str {
+            if unique {
+                "unique index"
+            } else {
+                "index"
+            }
+        }
+
+        match self {
+            Self::MissingTable => write!(f, "table is missing in database"),
+            Self::AddedColumn(column) => write!(f, "+ column `{}` (missing in database)", column),
+            Self::RemovedColumn(column) => write!(f, "- column `{}` (not in model)", column),
+            Self::Type { column, model, database } => {
+                write!(f, "~ column `{}` type: model {} / database {}", column, model, database)
+            }
+            Self::Length { column, model, database } => {
+                write!(f, "~ column `{}` length: model {} / database {}", column, model, database)
+            }
+            Self::Nullable { column, model, database } => write!(
+                f,
+                "~ column `{}` nullability: model {} / database {}",
+                column,
+                nullable(*model),
+                nullable(*database)
+            ),
+            Self::Default { column, model, database } => write!(
+                f,
+                "~ column `{}` default: model {} / database {}",
+                column,
+                show(model),
+                show(database)
+            ),
+            Self::Comment { column, model, database } => write!(
+                f,
+                "~ column `{}` comment: model {} / database {}",
+                column,
+                show(model),
+                show(database)
+            ),
+            Self::MissingIndex { name, columns, unique } => write!(
+                f,
+                "+ {} `{}` ({}) (missing in database)",
+                kind(*unique),
+                name,
+                columns.join(", ")
+            ),
+            Self::ExtraIndex { column, unique } => {
+                write!(f, "- {} on column `{}` (not in model)", kind(*unique), column)
+            }
+            Self::TableComment { model, database } => write!(
+                f,
+                "~ table comment: model {} / database {}",
+                show(model),
+                show(database)
+            ),
+        }
+    }
+}
+
+/// 单张表的对比结果
+#[derive(Debug, Clone)]
+pub struct TableDiff {
+    pub table: String,
+    pub differences: Vec<Difference>,
+    /// 用于同步数据库到模型的 SQL 语句
+    pub statements: Vec<String>,
+}
+
+/// 对比模型与数据库中的表结构（`live` 为 None 表示数据库中不存在该表）
+///
+/// `allow_drop` 为 false 时，删除列的语句以 SQL 注释的形式输出，需要手动确认后执行
+pub fn diff_table(
+    model: &ModelTable,
+    live: Option<&TableInfo>,
+    database: &str,
+    allow_drop: bool,
+) -> TableDiff {
+    let mut diff = TableDiff {
+        table: model.name.clone(),
+        differences: Vec::new(),
+        statements: Vec::new(),
+    };
+
+    let Some(live) = live else {
+        diff.differences.push(Difference::MissingTable);
+        diff.statements.push(SqlGenerator::render_create_table(model, database));
+        return diff;
+    };
+
+    let table = SqlGenerator::escape_identifier(database, &model.name);
+    let supports_comment = database != "sqlite";
+
+    // 1. 列
+    for column in &model.columns {
+        let Some(live_column) = live.columns.iter().find(|c| c.name == column.name) else {
+            diff.differences.push(Difference::AddedColumn(column.name.clone()));
+            diff.statements.push(format!("ALTER TABLE {} ADD COLUMN {};", table, column.definition));
+            if database == "postgres" && column.comment.is_some() {
+                diff.statements.push(SqlGenerator::column_comment_sql(
+                    database,
+                    &model.name,
+                    &column.name,
+                    column.comment.as_deref(),
+                ));
+            }
+            continue;
+        };
+
+        let changes = diff_column(column, live_column, supports_comment);
+        if changes.is_empty() {
+            continue;
+        }
+        diff.statements.extend(alter_column_sql(&model.name, column, &changes, database));
+        diff.differences.extend(changes);
+    }
+
+    for live_column in &live.columns {
+        if !model.columns.iter().any(|c| c.name == live_column.name) {
+            diff.differences.push(Difference::RemovedColumn(live_column.name.clone()));
+            let sql = format!(
+                "ALTER TABLE {} DROP COLUMN {};",
+                table,
+                SqlGenerator::escape_identifier(database, &live_column.name)
+            );
+            diff.statements.push(if allow_drop { sql } else { format!("-- {}", sql) });
+        }
+    }
+
+    // 2. 索引：数据库只提供每列是否有（唯一）索引，按列判断
+    let live_column = |name: &str| live.columns.iter().find(|c| c.name == name);
+    let model_indexes = model
+        .unique_indexes
+        .iter()
+        .map(|(name, field)| (name, vec![field.clone()], true))
+        .chain(model.indexes.iter().map(|(name, field)| (name, vec![field.clone()], false)))
+        .chain(model.composite_indexes.iter().map(|(name, fields)| (name, fields.clone(), false)));
+    for (name, columns, unique) in model_indexes {
+        let exists = columns.iter().all(|column| match live_column(column) {
+            Some(c) if unique => c.is_unique,
+            Some(c) => c.has_index || c.is_unique,
+            // 新增列上的索引同样需要创建
+            None => false,
+        });
+        if !exists {
+            diff.statements.push(SqlGenerator::create_index_sql(
+                database,
+                model,
+                name,
+                &columns,
+                unique,
+            ));
+            diff.differences.push(Difference::MissingIndex {
+                name: name.clone(),
+                columns,
+                unique,
+            });
+        }
+    }
+
+    for live_column in live.columns.iter().filter(|c| !c.is_pk) {
+        let name = &live_column.name;
+        if live_column.is_unique && !model.unique_indexes.iter().any(|(_, field)| field == name) {
+            diff.differences.push(Difference::ExtraIndex {
+                column: name.clone(),
+                unique: true,
+            });
+        }
+        let indexed = model.indexes.iter().any(|(_, field)| field == name)
+            || model.composite_indexes.iter().any(|(_, fields)| fields.contains(name));
+        if live_column.has_index && !indexed {
+            diff.differences.push(Difference::ExtraIndex {
+                column: name.clone(),
+                unique: false,
+            });
+        }
+    }
+
+    // 3. 表注释
+    let live_comment = non_empty(&live.table_comment);
+    if supports_comment && model.table_comment != live_comment {
+        let comment_sql = match &model.table_comment {
+            Some(comment) => format!("'{}'", comment.replace('\'', "''")),
+            None if database == "mysql" => "''".to_string(),
+            None => "NULL".to_string(),
+        };
+        diff.statements.push(match database {
+            "mysql" => format!("ALTER TABLE {} COMMENT = {};", table, comment_sql),
+            _ => format!("COMMENT ON TABLE {} IS {};", table, comment_sql),
+        });
+        diff.differences.push(Difference::TableComment {
+            model: model.table_comment.clone(),
+            database: live_comment,
+        });
+    }
+
+    diff
+}
+
+/// 对比单列的类型、长度、可空性、默认值和注释
+fn diff_column(column: &ModelColumn, live: &ColumnInfo, supports_comment: bool) -> Vec<Difference> {
+    let mut changes = Vec::new();
+    let name = || column.name.clone();
+
+    let model_type = normalize_type(&column.sql_type);
+    let live_type = normalize_type(&live.sql_type);
+    if model_type != live_type {
+        if strip_length(&model_type) == strip_length(&live_type) {
+            changes.push(Difference::Length {
+                column: name(),
+                model: model_type,
+                database: live_type,
+            });
+        } else {
+            changes.push(Difference::Type {
+                column: name(),
+                model: model_type,
+                database: live_type,
+            });
+        }
+    }
+
+    // 主键列的可空性由数据库决定（SQLite 主键列可能报告为可空）
+    if !column.is_pk && column.nullable != live.nullable {
+        changes.push(Difference::Nullable {
+            column: name(),
+            model: column.nullable,
+            database: live.nullable,
+        });
+    }
+
+    // 自增列的默认值由数据库生成（如 PostgreSQL 的 nextval(...)）
+    if !live.auto_increment {
+        let model_default = column.default.as_deref().map(normalize_default);
+        let live_default = live
+            .default
+            .as_deref()
+            .map(normalize_default)
+            .filter(|value| value != "null");
+        if model_default != live_default {
+            changes.push(Difference::Default {
+                column: name(),
+                model: column.default.clone(),
+                database: live.default.clone(),
+            });
+        }
+    }
+
+    let live_comment = non_empty(&live.comment);
+    if supports_comment && column.comment != live_comment {
+        changes.push(Difference::Comment {
+            column: name(),
+            model: column.comment.clone(),
+            database: live_comment,
+        });
+    }
+
+    changes
+}
+
+/// 生成修改列的语句
+///
+/// - MySQL：`MODIFY COLUMN` 使用模型的完整列定义
+/// - PostgreSQL：按差异分别生成 `ALTER COLUMN ... TYPE / SET NOT NULL / SET DEFAULT` 和 `COMMENT ON COLUMN`
+/// - SQLite：不支持修改列，输出需要重建表的提示注释
+fn alter_column_sql(
+    table_name: &str,
+    column: &ModelColumn,
+    changes: &[Difference],
+    database: &str,
+) -> Vec<String> {
+    let table = SqlGenerator::escape_identifier(database, table_name);
+    let name = SqlGenerator::escape_identifier(database, &column.name);
+
+    match database {
+        "mysql" => vec![format!("ALTER TABLE {} MODIFY COLUMN {};", table, column.definition)],
+        "postgres" => {
+            let mut statements = Vec::new();
+            for change in changes {
+                match change {
+                    Difference::Type { .. } | Difference::Length { .. } => statements.push(format!(
+                        "ALTER TABLE {} ALTER COLUMN {} TYPE {};",
+                        table, name, column.sql_type
+                    )),
+                    Difference::Nullable { model, .. } => statements.push(format!(
+                        "ALTER TABLE {} ALTER COLUMN {} {} NOT NULL;",
+                        table,
+                        name,
+                        if *model { "DROP" } else { "SET" }
+                    )),
+                    Difference::Default { .. } => statements.push(match &column.default {
+                        Some(default) => format!(
+                            "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {};",
+                            table, name, default
+                        ),
+                        None => format!("ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;", table, name),
+                    }),
+                    Difference::Comment { .. } => statements.push(SqlGenerator::column_comment_sql(
+                        database,
+                        table_name,
+                        &column.name,
+                        column.comment.as_deref(),
+                    )),
+                    _ => {}
+                }
+            }
+            statements
+        }
+        _ => vec![format!(
+            "-- SQLite does not support altering column {} of {}; rebuild the table to apply: {}",
+            name, table, column.definition
+        )],
+    }
+}
+
+/// 规范化 SQL 类型以便比较：小写、统一 PostgreSQL 的类型名（timestamp without time zone -> timestamp）、
+/// 去掉 MySQL 整数类型的显示宽度
+fn normalize_type(sql_type: &str) -> String {
+    let lower = sql_type
+        .trim()
+        .to_lowercase()
+        .replace("character varying", "varchar")
+        .replace("character", "char")
+        .replace(" without time zone", "");
+    let lower = lower.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    // bigint(20) -> bigint，int(11) unsigned -> int unsigned；保留 tinyint(1)（布尔）
+    if let (Some(start), Some(end)) = (lower.find('('), lower.find(')')) {
+        let base = &lower[..start];
+        let params = &lower[start + 1..end];
+        let is_integer = matches!(base, "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "bigint");
+        if is_integer && !(base == "tinyint" && params == "1") {
+            return format!("{}{}", base, &lower[end + 1..]);
+        }
+    }
+    lower
+}
+
+/// 去掉类型中的长度部分（varchar(64) -> varchar）
+fn strip_length(sql_type: &str) -> String {
+    match (sql_type.find('('), sql_type.find(')')) {
+        (Some(start), Some(end)) if start < end => {
+            format!("{}{}", &sql_type[..start], &sql_type[end + 1..])
+        }
+        _ => sql_type.to_string(),
+    }
+}
+
+/// 规范化默认值以便比较：去掉 PostgreSQL 的类型转换（'a'::character varying）、外层括号和引号，忽略大小写
+fn normalize_default(value: &str) -> String {
+    let mut value = value.trim();
+    if let Some(index) = value.rfind("::") {
+        let cast = &value[index + 2..];
+        if cast.chars().all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '_') {
+            value = &value[..index];
+        }
+    }
+    while value.len() >= 2 && value.starts_with('(') && value.ends_with(')') {
+        value = &value[1..value.len() - 1];
+    }
+    let unquoted = if value.len() >= 2
+        && ((value.starts_with('\'') && value.ends_with('\''))
+            || (value.starts_with('"') && value.ends_with('"')))
+    {
+        value[1..value.len() - 1].replace("''", "'")
+    } else {
+        value.to_string()
+    };
+    unquoted.to_lowercase()
+}
+
+/// 空注释视为没有注释（MySQL 对无注释的列返回空字符串）
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value.clone().filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model_column(name: &str, sql_type: &str, definition: &str) -> ModelColumn {
+        ModelColumn {
+            name: name.to_string(),
+            sql_type: sql_type.to_string(),
+            nullable: true,
+            is_pk: false,
+            default: None,
+            comment: None,
+            definition: definition.to_string(),
+        }
+    }
+
+    fn live_column(name: &str, sql_type: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            sql_type: sql_type.to_string(),
+            nullable: true,
+            is_pk: false,
+            default: None,
+            auto_increment: false,
+            is_unique: false,
+            has_index: false,
+            comment: None,
+            length: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_type() {
+        let cases = [
+            ("int(11)", "int"),
+            ("INT", "int"),
+            ("int(10) unsigned", "int unsigned"),
+            ("bigint(20)", "bigint"),
+            ("tinyint(1)", "tinyint(1)"),
+            ("tinyint(4)", "tinyint"),
+            ("character varying(64)", "varchar(64)"),
+            ("VARCHAR(64)", "varchar(64)"),
+            ("character(36)", "char(36)"),
+            ("timestamp  with time zone", "timestamp with time zone"),
+            ("timestamp without time zone", "timestamp"),
+            ("time without time zone", "time"),
+            ("decimal(10,2)", "decimal(10,2)"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(normalize_type(input), expected, "normalize_type({:?})", input);
+        }
+    }
+
+    #[test]
+    fn test_normalize_default() {
+        let cases = [
+            ("'a'::character varying", "a"),
+            ("'guest'::text", "guest"),
+            ("(0)", "0"),
+            ("((1))", "1"),
+            ("'0'", "0"),
+            ("'it''s'", "it's"),
+            ("CURRENT_TIMESTAMP", "current_timestamp"),
+            ("now()", "now()"),
+            ("NULL::character varying", "null"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(normalize_default(input), expected, "normalize_default({:?})", input);
+        }
+    }
+
+    #[test]
+    fn test_diff_column_ignores_equivalent_forms() {
+        let mut column = model_column("status", "INT", "`status` INT DEFAULT 0");
+        column.default = Some("0".to_string());
+        let mut live = live_column("status", "int(11)");
+        live.default = Some("(0)".to_string());
+        assert!(diff_column(&column, &live, true).is_empty());
+
+        let mut column = model_column("name", "VARCHAR(64)", "\"name\" VARCHAR(64) DEFAULT 'a'");
+        column.default = Some("'a'".to_string());
+        let mut live = live_column("name", "character varying(64)");
+        live.default = Some("'a'::character varying".to_string());
+        assert!(diff_column(&column, &live, true).is_empty());
+
+        let column = model_column("name", "VARCHAR(128)", "\"name\" VARCHAR(128)");
+        let live = live_column("name", "character varying(64)");
+        let changes = diff_column(&column, &live, true);
+        assert!(matches!(changes.as_slice(), [Difference::Length { .. }]));
+
+        // tinyint(1) 是布尔列，不能当作显示宽度忽略
+        let column = model_column("active", "TINYINT(1)", "`active` TINYINT(1)");
+        let live = live_column("active", "tinyint(4)");
+        let changes = diff_column(&column, &live, true);
+        assert!(matches!(
+            changes.as_slice(),
+            [Difference::Length { model, database, .. }] if model == "tinyint(1)" && database == "tinyint"
+        ));
+    }
+
+    #[test]
+    fn test_alter_column_sql() {
+        let mut column = model_column(
+            "name",
+            "VARCHAR(128)",
+            "`name` VARCHAR(128) NOT NULL DEFAULT 'guest' COMMENT 'user''s name'",
+        );
+        column.nullable = false;
+        column.default = Some("'guest'".to_string());
+        column.comment = Some("user's name".to_string());
+        let changes = vec![
+            Difference::Length {
+                column: "name".to_string(),
+                model: "varchar(128)".to_string(),
+                database: "varchar(64)".to_string(),
+            },
+            Difference::Nullable {
+                column: "name".to_string(),
+                model: false,
+                database: true,
+            },
+            Difference::Default {
+                column: "name".to_string(),
+                model: Some("'guest'".to_string()),
+                database: None,
+            },
+            Difference::Comment {
+                column: "name".to_string(),
+                model: Some("user's name".to_string()),
+                database: None,
+            },
+        ];
+
+        let cases: [(&str, Vec<&str>); 3] = [
+            (
+                "mysql",
+                vec!["ALTER TABLE `user` MODIFY COLUMN `name` VARCHAR(128) NOT NULL DEFAULT 'guest' COMMENT 'user''s name';"],
+            ),
+            (
+                "postgres",
+                vec![
+                    "ALTER TABLE \"user\" ALTER COLUMN \"name\" TYPE VARCHAR(128);",
+                    "ALTER TABLE \"user\" ALTER COLUMN \"name\" SET NOT NULL;",
+                    "ALTER TABLE \"user\" ALTER COLUMN \"name\" SET DEFAULT 'guest';",
+                    "COMMENT ON COLUMN \"user\".\"name\" IS 'user''s name';",
+                ],
+            ),
+            (
+                "sqlite",
+                vec!["-- SQLite does not support altering column \"name\" of \"user\"; rebuild the table to apply: `name` VARCHAR(128) NOT NULL DEFAULT 'guest' COMMENT 'user''s name'"],
+            ),
+        ];
+        for (database, expected) in cases {
+            assert_eq!(alter_column_sql("user", &column, &changes, database), expected, "{}", database);
+        }
+
+        // 模型中去掉默认值、改为可空
+        column.nullable = true;
+        column.default = None;
+        let changes = vec![
+            Difference::Nullable {
+                column: "name".to_string(),
+                model: true,
+                database: false,
+            },
+            Difference::Default {
+                column: "name".to_string(),
+                model: None,
+                database: Some("'guest'::character varying".to_string()),
+            },
+        ];
+        assert_eq!(
+            alter_column_sql("user", &column, &changes, "postgres"),
+            vec![
+                "ALTER TABLE \"user\" ALTER COLUMN \"name\" DROP NOT NULL;",
+                "ALTER TABLE \"user\" ALTER COLUMN \"name\" DROP DEFAULT;",
+            ]
+        );
+    }
+}
@@ -62,7 +62,7 @@ impl CodeGenerator {
 
         // 生成结构体文档注释
         code.push_str(&format!("/// {}\n", to_pascal_case(&table.name)));
-        code.push_str(&format!("/// \n"));
+        code.push_str("/// \n");
         code.push_str(&format!("/// 表名: `{}`\n", table.name));
 
         let pk = table
@@ -77,7 +77,7 @@ impl CodeGenerator {
             code.push_str(&format!("/// 逻辑删除字段: `{}`\n", soft_delete));
         }
         code.push_str(&format!("/// 字段数: {}\n", table.columns.len()));
-        code.push_str("\n");
+        code.push('\n');
 
         // 生成 derives
         let mut derives = vec![
@@ -145,7 +145,7 @@ impl CodeGenerator {
             }
 
             code.push_str(&desc_parts.join(" | "));
-            code.push_str("\n");
+            code.push('\n');
 
             // 如果有默认值，也加上（但 auto_increment 字段的 nextval 默认值应该忽略）
             if let Some(ref default) = col.default {
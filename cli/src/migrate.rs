@@ -0,0 +1,403 @@
+use crate::database::DbPool;
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 迁移历史表
+pub const HISTORY_TABLE: &str = "_sqlxplus_migrations";
+
+/// 本地迁移文件：`{version}_{name}.up.sql` / `{version}_{name}.down.sql`
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub version: i64,
+    pub name: String,
+    pub up_sql: String,
+    pub down_sql: Option<String>,
+    /// up SQL 的 SHA-256（十六进制）
+    pub checksum: String,
+}
+
+/// 已执行的迁移（历史表中的一行）
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub name: String,
+    pub checksum: String,
+    /// 执行时间（Unix 时间戳，秒）
+    pub applied_at: i64,
+}
+
+/// 计算 SQL 的校验和
+pub fn checksum(sql: &str) -> String {
+    format!("{:x}", Sha256::digest(sql.as_bytes()))
+}
+
+/// 读取目录下的所有迁移文件，按版本号排序（目录不存在时返回空列表）
+pub fn load_migrations(dir: &Path) -> Result<Vec<Migration>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut migrations: Vec<Migration> = Vec::new();
+    let mut down_files: Vec<(i64, PathBuf)> = Vec::new();
+    let entries = fs::read_dir(dir).with_context(|| format!("Failed to read directory: {:?}", dir))?;
+    for entry in entries {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let (stem, is_up) = if let Some(stem) = file_name.strip_suffix(".up.sql") {
+            (stem, true)
+        } else if let Some(stem) = file_name.strip_suffix(".down.sql") {
+            (stem, false)
+        } else {
+            continue;
+        };
+        let Some((version, name)) = stem
+            .split_once('_')
+            .and_then(|(version, name)| Some((version.parse::<i64>().ok()?, name)))
+        else {
+            eprintln!("⚠️  Warning: {:?} does not match {{version}}_{{name}}.up|down.sql, skipping", path);
+            continue;
+        };
+
+        if !is_up {
+            down_files.push((version, path));
+            continue;
+        }
+        if migrations.iter().any(|m| m.version == version) {
+            anyhow::bail!("Duplicate migration version {} in {:?}", version, dir);
+        }
+        let up_sql = fs::read_to_string(&path).with_context(|| format!("Failed to read file: {:?}", path))?;
+        migrations.push(Migration {
+            version,
+            name: name.to_string(),
+            checksum: checksum(&up_sql),
+            up_sql,
+            down_sql: None,
+        });
+    }
+
+    for (version, path) in down_files {
+        let Some(migration) = migrations.iter_mut().find(|m| m.version == version) else {
+            eprintln!("⚠️  Warning: {:?} has no matching .up.sql file, skipping", path);
+            continue;
+        };
+        migration.down_sql =
+            Some(fs::read_to_string(&path).with_context(|| format!("Failed to read file: {:?}", path))?);
+    }
+
+    migrations.sort_by_key(|m| m.version);
+    Ok(migrations)
+}
+
+/// 检查已执行的迁移文件是否被修改（校验和与历史记录不一致时返回错误）
+pub fn verify_checksums(migrations: &[Migration], applied: &[AppliedMigration]) -> Result<()> {
+    let modified: Vec<String> = applied
+        .iter()
+        .filter_map(|a| {
+            migrations
+                .iter()
+                .find(|m| m.version == a.version && m.checksum != a.checksum)
+                .map(|m| format!("{}_{}", m.version, m.name))
+        })
+        .collect();
+    if !modified.is_empty() {
+        anyhow::bail!(
+            "Applied migration(s) were modified (checksum mismatch): {}",
+            modified.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// 创建新的迁移文件，返回 (up 文件路径, down 文件路径)
+pub fn create_migration(dir: &Path, name: &str, up_sql: &str, down_sql: &str) -> Result<(PathBuf, PathBuf)> {
+    let name: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.is_empty() {
+        anyhow::bail!("Migration name must not be empty");
+    }
+
+    let version: i64 = chrono::Utc::now().format("%Y%m%d%H%M%S").to_string().parse()?;
+    if load_migrations(dir)?.iter().any(|m| m.version >= version) {
+        anyhow::bail!("A migration with version >= {} already exists in {:?}", version, dir);
+    }
+
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create directory: {:?}", dir))?;
+    let up_path = dir.join(format!("{}_{}.up.sql", version, name));
+    let down_path = dir.join(format!("{}_{}.down.sql", version, name));
+    fs::write(&up_path, up_sql).with_context(|| format!("Failed to write file {:?}", up_path))?;
+    fs::write(&down_path, down_sql).with_context(|| format!("Failed to write file {:?}", down_path))?;
+    Ok((up_path, down_path))
+}
+
+/// 迁移执行器
+///
+/// PostgreSQL 和 SQLite 支持事务性 DDL，每个迁移（包括历史记录）在同一个事务中执行；
+/// MySQL 的 DDL 会隐式提交，迁移直接执行，失败时需要手动处理已执行的部分。
+pub struct Migrator<'a> {
+    pool: &'a DbPool,
+}
+
+impl<'a> Migrator<'a> {
+    /// 创建执行器并确保历史表存在
+    pub async fn new(pool: &'a DbPool) -> Result<Self> {
+        let sql = format!(
+            r#"
+            CREATE TABLE IF NOT EXISTS {} (
+                version BIGINT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL,
+                checksum VARCHAR(64) NOT NULL,
+                applied_at BIGINT NOT NULL
+            )
+            "#,
+            HISTORY_TABLE
+        );
+        match pool {
+            DbPool::MySql(pool) => sqlx::raw_sql(&sql).execute(pool).await.map(|_| ()),
+            DbPool::Postgres(pool) => sqlx::raw_sql(&sql).execute(pool).await.map(|_| ()),
+            DbPool::Sqlite(pool) => sqlx::raw_sql(&sql).execute(pool).await.map(|_| ()),
+        }
+        .context("Failed to create migration history table")?;
+        Ok(Self { pool })
+    }
+
+    /// 已执行的迁移，按版本号排序
+    pub async fn applied(&self) -> Result<Vec<AppliedMigration>> {
+        let sql = format!(
+            "SELECT version, name, checksum, applied_at FROM {} ORDER BY version",
+            HISTORY_TABLE
+        );
+        match self.pool {
+            DbPool::MySql(pool) => sqlx::query_as(&sql).fetch_all(pool).await,
+            DbPool::Postgres(pool) => sqlx::query_as(&sql).fetch_all(pool).await,
+            DbPool::Sqlite(pool) => sqlx::query_as(&sql).fetch_all(pool).await,
+        }
+        .context("Failed to query migration history")
+    }
+
+    /// 执行迁移的 up SQL 并写入历史记录
+    pub async fn apply(&self, migration: &Migration) -> Result<()> {
+        let applied_at = chrono::Utc::now().timestamp();
+        let insert = format!(
+            "INSERT INTO {} (version, name, checksum, applied_at) VALUES ",
+            HISTORY_TABLE
+        );
+        match self.pool {
+            DbPool::MySql(pool) => {
+                sqlx::raw_sql(&migration.up_sql).execute(pool).await?;
+                sqlx::query(&format!("{}(?, ?, ?, ?)", insert))
+                    .bind(migration.version)
+                    .bind(&migration.name)
+                    .bind(&migration.checksum)
+                    .bind(applied_at)
+                    .execute(pool)
+                    .await?;
+            }
+            DbPool::Postgres(pool) => {
+                let mut tx = pool.begin().await?;
+                sqlx::raw_sql(&migration.up_sql).execute(&mut *tx).await?;
+                sqlx::query(&format!("{}($1, $2, $3, $4)", insert))
+                    .bind(migration.version)
+                    .bind(&migration.name)
+                    .bind(&migration.checksum)
+                    .bind(applied_at)
+                    .execute(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+            }
+            DbPool::Sqlite(pool) => {
+                let mut tx = pool.begin().await?;
+                sqlx::raw_sql(&migration.up_sql).execute(&mut *tx).await?;
+                sqlx::query(&format!("{}(?, ?, ?, ?)", insert))
+                    .bind(migration.version)
+                    .bind(&migration.name)
+                    .bind(&migration.checksum)
+                    .bind(applied_at)
+                    .execute(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 执行迁移的 down SQL 并删除历史记录
+    pub async fn revert(&self, migration: &Migration) -> Result<()> {
+        let down_sql = migration.down_sql.as_deref().with_context(|| {
+            format!(
+                "Migration {}_{} has no .down.sql file",
+                migration.version, migration.name
+            )
+        })?;
+        let delete = format!("DELETE FROM {} WHERE version = ", HISTORY_TABLE);
+        match self.pool {
+            DbPool::MySql(pool) => {
+                sqlx::raw_sql(down_sql).execute(pool).await?;
+                sqlx::query(&format!("{}?", delete))
+                    .bind(migration.version)
+                    .execute(pool)
+                    .await?;
+            }
+            DbPool::Postgres(pool) => {
+                let mut tx = pool.begin().await?;
+                sqlx::raw_sql(down_sql).execute(&mut *tx).await?;
+                sqlx::query(&format!("{}$1", delete))
+                    .bind(migration.version)
+                    .execute(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+            }
+            DbPool::Sqlite(pool) => {
+                let mut tx = pool.begin().await?;
+                sqlx::raw_sql(down_sql).execute(&mut *tx).await?;
+                sqlx::query(&format!("{}?", delete))
+                    .bind(migration.version)
+                    .execute(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在系统临时目录下创建空目录（每个测试使用不同的名称）
+    fn temp_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sqlxplus-migrate-{}-{}", std::process::id(), label));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write(dir: &Path, file_name: &str, content: &str) {
+        fs::write(dir.join(file_name), content).unwrap();
+    }
+
+    #[test]
+    fn test_load_migrations_sorted_by_version() {
+        let dir = temp_dir("sorted");
+        write(&dir, "20240301000000_add_posts.up.sql", "CREATE TABLE post (id INT);");
+        write(&dir, "20240301000000_add_posts.down.sql", "DROP TABLE post;");
+        write(&dir, "20240101000000_init.up.sql", "CREATE TABLE user (id INT);");
+        write(&dir, "20240201000000_add_email.up.sql", "ALTER TABLE user ADD email TEXT;");
+        write(&dir, "README.md", "not a migration");
+        write(&dir, "init.up.sql", "missing version");
+
+        let migrations = load_migrations(&dir).unwrap();
+        let versions: Vec<(i64, &str)> = migrations.iter().map(|m| (m.version, m.name.as_str())).collect();
+        assert_eq!(
+            versions,
+            vec![
+                (20240101000000, "init"),
+                (20240201000000, "add_email"),
+                (20240301000000, "add_posts"),
+            ]
+        );
+        assert_eq!(migrations[0].checksum, checksum("CREATE TABLE user (id INT);"));
+        assert_eq!(migrations[0].down_sql, None);
+        assert_eq!(migrations[2].down_sql.as_deref(), Some("DROP TABLE post;"));
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(load_migrations(&dir).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_migrations_duplicate_version() {
+        let dir = temp_dir("duplicate");
+        write(&dir, "20240101000000_init.up.sql", "SELECT 1;");
+        write(&dir, "20240101000000_other.up.sql", "SELECT 2;");
+
+        let err = load_migrations(&dir).unwrap_err();
+        assert!(err.to_string().contains("Duplicate migration version 20240101000000"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_migrations_orphan_down() {
+        let dir = temp_dir("orphan");
+        write(&dir, "20240101000000_init.up.sql", "SELECT 1;");
+        write(&dir, "20240201000000_gone.down.sql", "SELECT 2;");
+
+        let migrations = load_migrations(&dir).unwrap();
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].version, 20240101000000);
+        assert_eq!(migrations[0].down_sql, None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_create_migration_sanitizes_name() {
+        let dir = temp_dir("create");
+        let (up_path, down_path) =
+            create_migration(&dir, "  Add Users-Table! ", "CREATE TABLE users (id INT);\n", "DROP TABLE users;\n")
+                .unwrap();
+
+        let up_name = up_path.file_name().unwrap().to_str().unwrap();
+        let (version, rest) = up_name.split_once('_').unwrap();
+        assert_eq!(version.len(), 14);
+        assert!(version.parse::<i64>().is_ok());
+        assert_eq!(rest, "add_users_table_.up.sql");
+        assert_eq!(
+            down_path.file_name().unwrap().to_str().unwrap(),
+            format!("{}_add_users_table_.down.sql", version)
+        );
+
+        let migrations = load_migrations(&dir).unwrap();
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].name, "add_users_table_");
+        assert_eq!(migrations[0].down_sql.as_deref(), Some("DROP TABLE users;\n"));
+
+        assert!(create_migration(&dir, "   ", "", "").is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_create_migration_rejects_older_version() {
+        let dir = temp_dir("future");
+        write(&dir, "99990101000000_future.up.sql", "SELECT 1;");
+
+        let err = create_migration(&dir, "next", "", "").unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert_eq!(load_migrations(&dir).unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_checksums() {
+        let migration = |version: i64, up_sql: &str| Migration {
+            version,
+            name: format!("m{}", version),
+            up_sql: up_sql.to_string(),
+            down_sql: None,
+            checksum: checksum(up_sql),
+        };
+        let applied = |version: i64, up_sql: &str| AppliedMigration {
+            version,
+            name: format!("m{}", version),
+            checksum: checksum(up_sql),
+            applied_at: 0,
+        };
+        let migrations = vec![migration(1, "SELECT 1;"), migration(2, "SELECT 2;")];
+
+        assert!(verify_checksums(&migrations, &[applied(1, "SELECT 1;"), applied(2, "SELECT 2;")]).is_ok());
+        // 本地文件缺失的已执行迁移不在这里报错
+        assert!(verify_checksums(&migrations, &[applied(3, "SELECT 3;")]).is_ok());
+
+        let err = verify_checksums(&migrations, &[applied(1, "SELECT 1;"), applied(2, "SELECT 20;")]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Applied migration(s) were modified (checksum mismatch): 2_m2"
+        );
+    }
+}
@@ -13,6 +13,17 @@ impl SqlGenerator {
         model_file: &Path,
         database: &str,
     ) -> Result<String> {
+        let tables = Self::parse_models(model_file, database)?;
+        let create_statements: Vec<String> = tables
+            .iter()
+            .map(|table| Self::render_create_table(table, database))
+            .collect();
+
+        Ok(create_statements.join("\n\n"))
+    }
+
+    /// 解析模型文件中所有带 #[model(...)] 属性的结构体
+    pub fn parse_models(model_file: &Path, database: &str) -> Result<Vec<ModelTable>> {
         // 读取文件内容
         let content = fs::read_to_string(model_file)
             .with_context(|| format!("Failed to read file: {:?}", model_file))?;
@@ -22,7 +33,7 @@ impl SqlGenerator {
             .context("Failed to parse Rust file")?;
 
         // 查找带有 #[model(...)] 属性的结构体
-        let mut create_statements = Vec::new();
+        let mut tables = Vec::new();
         for item in ast.items {
             if let syn::Item::Struct(item_struct) = item {
                 // 检查是否有 #[model(...)] 属性
@@ -32,25 +43,24 @@ impl SqlGenerator {
                     .find(|attr| attr.path().is_ident("model"));
 
                 if let Some(attr) = model_attr {
-                    let sql = Self::generate_table_sql(&item_struct, attr, database)?;
-                    create_statements.push(sql);
+                    tables.push(Self::parse_table(&item_struct, attr, database)?);
                 }
             }
         }
 
-        if create_statements.is_empty() {
+        if tables.is_empty() {
             anyhow::bail!("No model struct found in the file");
         }
 
-        Ok(create_statements.join("\n\n"))
+        Ok(tables)
     }
 
-    /// 生成单个表的 CREATE TABLE 语句
-    fn generate_table_sql(
+    /// 解析单个模型结构体的表结构
+    fn parse_table(
         struct_item: &syn::ItemStruct,
         model_attr: &syn::Attribute,
         database: &str,
-    ) -> Result<String> {
+    ) -> Result<ModelTable> {
         // 解析 #[model(...)] 属性
         let (table_name, pk_field, _soft_delete_field, table_comment) = Self::parse_model_attr(model_attr)?;
 
@@ -60,15 +70,12 @@ impl SqlGenerator {
             _ => anyhow::bail!("Only named fields are supported"),
         };
 
-        // 生成列定义
-        let mut column_defs = Vec::new();
+        let mut columns = Vec::new();
         let mut indexes: Vec<(String, String)> = Vec::new(); // 单字段索引：(索引名, 字段名)
         let mut unique_indexes: Vec<(String, String)> = Vec::new(); // 单字段唯一索引：(索引名, 字段名)
-        // 联合索引：索引名 -> (字段名, 顺序) 列表
-        let mut composite_indexes: std::collections::HashMap<String, Vec<(String, i32)>> = std::collections::HashMap::new();
-        // PostgreSQL 的字段注释：(字段名, 注释)
-        let mut postgres_comments: Vec<(String, String)> = Vec::new();
-        
+        // 联合索引：索引名 -> (字段名, 顺序) 列表，按首次出现的顺序保存
+        let mut composite_indexes: Vec<(String, Vec<(String, i32)>)> = Vec::new();
+
         for (field_index, field) in fields.iter().enumerate() {
             let field_name = field.ident.as_ref().unwrap();
             let field_name_str = field_name.to_string();
@@ -97,102 +104,67 @@ impl SqlGenerator {
                 // 对于 String 类型，如果没有指定 length 且字段名包含 "text"，使用 TEXT 类型
                 if sql_type.starts_with("VARCHAR") {
                     let field_name_lower = field_name_str.to_lowercase();
-                    if field_name_lower.contains("text") || field_name_lower.ends_with("_text") {
-                        // 根据数据库类型选择 TEXT 类型
-                        sql_type = match database {
-                            "mysql" => "TEXT".to_string(),
-                            "postgres" => "TEXT".to_string(),
-                            "sqlite" => "TEXT".to_string(),
-                            _ => "TEXT".to_string(),
-                        };
+                    if field_name_lower.contains("text") {
+                        sql_type = "TEXT".to_string();
                     }
                 }
             }
             
-            // 判断是否可空（Option<T> 类型是可空的，但如果设置了 not_null，则不可空）
-            let nullable = Self::is_option_type(&field.ty) && !column_meta.not_null;
-            
             // 判断是否是主键
             let is_pk = field_name_str == pk_field;
 
+            // 判断是否可空（Option<T> 类型是可空的，但如果设置了 not_null，则不可空；主键不可空）
+            let nullable = Self::is_option_type(&field.ty) && !column_meta.not_null && !is_pk;
+
             // 生成列定义
-            let mut col_def = format!("    {}", Self::escape_identifier(database, &field_name_str));
+            let mut col_def = Self::escape_identifier(database, &field_name_str);
             col_def.push_str(&format!(" {}", sql_type));
 
             // 非空约束
             if !nullable && !is_pk {
                 col_def.push_str(" NOT NULL");
-            } else if column_meta.not_null && !is_pk {
-                col_def.push_str(" NOT NULL");
             }
 
             // 默认值处理
-            if let Some(ref default) = column_meta.default {
-                let default_sql = Self::format_default_value(
+            let default = match column_meta.default {
+                Some(ref default) => Some(Self::format_default_value(
                     default,
                     &field.ty,
                     &sql_type,
                     database,
-                )?;
+                )?),
+                None => None,
+            };
+            if let Some(ref default_sql) = default {
                 col_def.push_str(&format!(" DEFAULT {}", default_sql));
             }
 
-            // 主键字段如果是自增的，添加 AUTO_INCREMENT (MySQL) 或 SERIAL (PostgreSQL)
-            if is_pk {
+            // 单列整数主键（与 sqlxplus 的 PrimaryKey::AUTO_INCREMENT 一致）或 auto_increment 整数列自增：
+            // MySQL AUTO_INCREMENT，PostgreSQL SMALLSERIAL / SERIAL / BIGSERIAL，SQLite INTEGER 主键即 rowid
+            if Self::is_integer_type(&sql_type) && (is_pk || column_meta.auto_increment) {
                 match database {
-                    "mysql" => {
-                        // MySQL 的 BIGINT 主键通常是自增的，或者如果设置了 auto_increment
-                        if sql_type.contains("BIGINT") || column_meta.auto_increment {
-                            col_def.push_str(" AUTO_INCREMENT");
-                        }
-                    }
+                    "mysql" => col_def.push_str(" AUTO_INCREMENT"),
                     "postgres" => {
-                        // PostgreSQL 使用 SERIAL 或 BIGSERIAL
-                        if sql_type.contains("BIGINT") {
-                            col_def = format!("    {} BIGSERIAL", Self::escape_identifier(database, &field_name_str));
-                        } else if sql_type.contains("INT") {
-                            col_def = format!("    {} SERIAL", Self::escape_identifier(database, &field_name_str));
-                        }
+                        let serial = match sql_type.as_str() {
+                            "BIGINT" => "BIGSERIAL",
+                            "SMALLINT" => "SMALLSERIAL",
+                            _ => "SERIAL",
+                        };
+                        col_def = format!("{} {}", Self::escape_identifier(database, &field_name_str), serial);
                     }
                     _ => {}
                 }
-            } else if column_meta.auto_increment {
-                // 非主键字段也可以设置自增（MySQL）
-                if database == "mysql" {
-                    col_def.push_str(" AUTO_INCREMENT");
-                }
             }
 
-            // 添加字段注释
-            // MySQL 支持在 CREATE TABLE 中直接添加 COMMENT
-            // PostgreSQL 需要在 CREATE TABLE 之后单独执行 COMMENT ON COLUMN
-            // SQLite 不支持元数据 COMMENT，但可以使用 SQL 注释（--）
+            // MySQL 支持在列定义中直接添加 COMMENT（需要转义单引号）
+            // PostgreSQL 需要单独执行 COMMENT ON COLUMN，SQLite 只能使用 SQL 注释，均在渲染时处理
             if let Some(ref comment) = column_meta.comment {
-                match database {
-                    "mysql" => {
-                        // MySQL: COMMENT 'comment text'
-                        // 需要转义单引号
-                        let escaped_comment = comment.replace('\'', "''");
-                        col_def.push_str(&format!(" COMMENT '{}'", escaped_comment));
-                    }
-                    "postgres" => {
-                        // PostgreSQL 的 COMMENT 会在 CREATE TABLE 之后单独生成
-                        // 收集注释信息，稍后处理
-                        postgres_comments.push((field_name_str.clone(), comment.clone()));
-                    }
-                    "sqlite" => {
-                        // SQLite 不支持元数据 COMMENT，但可以使用 SQL 注释
-                        // 在列定义后添加注释
-                        col_def.push_str(&format!(" -- {}", comment));
-                    }
-                    _ => {
-                        // 其他数据库不支持 COMMENT，忽略
-                    }
+                if database == "mysql" {
+                    let escaped_comment = comment.replace('\'', "''");
+                    col_def.push_str(&format!(" COMMENT '{}'", escaped_comment));
                 }
             }
 
-            column_defs.push(col_def);
-
             // 收集索引信息
             // 1. 处理联合索引（combine_index）
             // 注意：unique 属性只影响单独索引，不影响联合索引
@@ -206,10 +178,16 @@ impl SqlGenerator {
                 };
                 
                 // 联合索引默认不是唯一的（unique 属性只影响单独索引）
-                composite_indexes
-                    .entry(combine_index_name.clone())
-                    .or_insert_with(Vec::new)
-                    .push((field_name_str.clone(), final_order));
+                match composite_indexes
+                    .iter_mut()
+                    .find(|(name, _)| name == combine_index_name)
+                {
+                    Some((_, fields)) => fields.push((field_name_str.clone(), final_order)),
+                    None => composite_indexes.push((
+                        combine_index_name.clone(),
+                        vec![(field_name_str.clone(), final_order)],
+                    )),
+                }
             }
             
             // 2. 处理单独索引
@@ -240,37 +218,82 @@ impl SqlGenerator {
                 };
                 indexes.push((final_index_name, field_name_str.clone()));
             }
+
+            columns.push(ModelColumn {
+                name: field_name_str,
+                sql_type,
+                nullable,
+                is_pk,
+                default,
+                comment: column_meta.comment,
+                definition: col_def,
+            });
         }
 
+        // 联合索引按顺序排序
+        let composite_indexes = composite_indexes
+            .into_iter()
+            .map(|(index_name, mut fields_with_order)| {
+                fields_with_order.sort_by_key(|(_, order)| *order);
+                (index_name, fields_with_order.into_iter().map(|(f, _)| f).collect())
+            })
+            .collect();
+
+        Ok(ModelTable {
+            name: table_name,
+            pk: pk_field,
+            table_comment,
+            columns,
+            unique_indexes,
+            indexes,
+            composite_indexes,
+        })
+    }
+
+    /// 生成单个表的 CREATE TABLE 语句
+    pub fn render_create_table(table: &ModelTable, database: &str) -> String {
+        let table_name = &table.name;
+
+        // 生成列定义
+        let column_defs: Vec<String> = table
+            .columns
+            .iter()
+            .map(|column| match (database, &column.comment) {
+                // SQLite 不支持元数据 COMMENT，在列定义后添加 SQL 注释
+                ("sqlite", Some(comment)) => format!("    {} -- {}", column.definition, comment),
+                _ => format!("    {}", column.definition),
+            })
+            .collect();
+
         // 生成 PRIMARY KEY 约束
         let mut constraints = Vec::new();
         constraints.push(format!(
             "    PRIMARY KEY ({})",
-            Self::escape_identifier(database, &pk_field)
+            Self::key_column_sql(database, table, &table.pk)
         ));
 
         // 生成单字段唯一索引约束
         // MySQL 使用 UNIQUE KEY，PostgreSQL 使用 CONSTRAINT ... UNIQUE，SQLite 使用 UNIQUE
-        for (index_name, field_name) in &unique_indexes {
+        for (index_name, field_name) in &table.unique_indexes {
             match database {
                 "mysql" => {
                     constraints.push(format!(
                         "    UNIQUE KEY {} ({})",
                         Self::escape_identifier(database, index_name),
-                        Self::escape_identifier(database, field_name)
+                        Self::key_column_sql(database, table, field_name)
                     ));
                 }
                 "postgres" => {
                     constraints.push(format!(
                         "    CONSTRAINT {} UNIQUE ({})",
                         Self::escape_identifier(database, index_name),
-                        Self::escape_identifier(database, field_name)
+                        Self::key_column_sql(database, table, field_name)
                     ));
                 }
                 "sqlite" => {
                     constraints.push(format!(
                         "    UNIQUE ({})",
-                        Self::escape_identifier(database, field_name)
+                        Self::key_column_sql(database, table, field_name)
                     ));
                 }
                 _ => {
@@ -278,7 +301,7 @@ impl SqlGenerator {
                     constraints.push(format!(
                         "    UNIQUE KEY {} ({})",
                         Self::escape_identifier(database, index_name),
-                        Self::escape_identifier(database, field_name)
+                        Self::key_column_sql(database, table, field_name)
                     ));
                 }
             }
@@ -287,7 +310,7 @@ impl SqlGenerator {
         // 构建 CREATE TABLE 语句
         let mut sql = format!(
             "CREATE TABLE {} (\n{}",
-            Self::escape_identifier(database, &table_name),
+            Self::escape_identifier(database, table_name),
             column_defs.join(",\n")
         );
 
@@ -297,7 +320,7 @@ impl SqlGenerator {
         }
 
         // 添加表注释（MySQL 在 CREATE TABLE 语句中，必须在 ); 之前）
-        if let Some(ref comment) = table_comment {
+        if let Some(ref comment) = table.table_comment {
             match database {
                 "mysql" => {
                     // MySQL: COMMENT 'comment text' 必须在 ); 之前
@@ -314,78 +337,50 @@ impl SqlGenerator {
         }
 
         // 生成单字段普通索引（在 CREATE TABLE 之后）
-        let has_single_indexes = !indexes.is_empty();
+        let has_single_indexes = !table.indexes.is_empty();
         if has_single_indexes {
             sql.push_str("\n\n");
-            for (index_name, field_name) in indexes {
-                sql.push_str(&format!(
-                    "CREATE INDEX {} ON {} ({});\n",
-                    Self::escape_identifier(database, &index_name),
-                    Self::escape_identifier(database, &table_name),
-                    Self::escape_identifier(database, &field_name)
-                ));
+            for (index_name, field_name) in &table.indexes {
+                sql.push_str(&Self::create_index_sql(database, table, index_name, std::slice::from_ref(field_name), false));
+                sql.push('\n');
             }
         }
 
-        // 生成联合索引（在 CREATE TABLE 之后，按顺序排序）
-        let has_composite_indexes = !composite_indexes.is_empty();
+        // 生成联合索引（在 CREATE TABLE 之后）
+        let has_composite_indexes = !table.composite_indexes.is_empty();
         if has_composite_indexes {
             if !has_single_indexes {
                 sql.push_str("\n\n");
             }
-            for (index_name, mut fields_with_order) in composite_indexes {
-                // 按顺序排序
-                fields_with_order.sort_by_key(|(_, order)| *order);
-                let fields_str = fields_with_order
-                    .iter()
-                    .map(|(f, _)| Self::escape_identifier(database, f))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                sql.push_str(&format!(
-                    "CREATE INDEX {} ON {} ({});\n",
-                    Self::escape_identifier(database, &index_name),
-                    Self::escape_identifier(database, &table_name),
-                    fields_str
-                ));
+            for (index_name, fields) in &table.composite_indexes {
+                sql.push_str(&Self::create_index_sql(database, table, index_name, fields, false));
+                sql.push('\n');
             }
         }
 
         // 生成 PostgreSQL 的字段注释（在 CREATE TABLE 之后）
-        let has_postgres_comments = !postgres_comments.is_empty();
-        if database == "postgres" && has_postgres_comments {
-            if !has_single_indexes && !has_composite_indexes {
-                sql.push_str("\n\n");
-            } else {
-                sql.push_str("\n\n");
-            }
+        let postgres_comments: Vec<_> = table
+            .columns
+            .iter()
+            .filter_map(|column| column.comment.as_ref().map(|comment| (&column.name, comment)))
+            .collect();
+        if database == "postgres" && !postgres_comments.is_empty() {
+            sql.push_str("\n\n");
             for (field_name, comment) in postgres_comments {
-                // PostgreSQL: COMMENT ON COLUMN table.column IS 'comment text';
-                // 需要转义单引号
-                let escaped_comment = comment.replace('\'', "''");
-                sql.push_str(&format!(
-                    "COMMENT ON COLUMN {}.{} IS '{}';\n",
-                    Self::escape_identifier(database, &table_name),
-                    Self::escape_identifier(database, &field_name),
-                    escaped_comment
-                ));
+                sql.push_str(&Self::column_comment_sql(database, table_name, field_name, Some(comment)));
+                sql.push('\n');
             }
         }
 
         // 生成 PostgreSQL 的表注释（在 CREATE TABLE 之后）
         if database == "postgres" {
-            if let Some(ref comment) = table_comment {
-                // 判断是否需要添加换行
-                let needs_newline = has_single_indexes || has_composite_indexes || has_postgres_comments;
-                if needs_newline {
-                    sql.push_str("\n\n");
-                } else {
-                    sql.push_str("\n\n");
-                }
+            if let Some(ref comment) = table.table_comment {
+                sql.push_str("\n\n");
                 // PostgreSQL: COMMENT ON TABLE table IS 'comment text';
                 let escaped_comment = comment.replace('\'', "''");
                 sql.push_str(&format!(
                     "COMMENT ON TABLE {} IS '{}';\n",
-                    Self::escape_identifier(database, &table_name),
+                    Self::escape_identifier(database, table_name),
                     escaped_comment
                 ));
             }
@@ -393,20 +388,79 @@ impl SqlGenerator {
 
         // 生成 SQLite 的表注释（在 CREATE TABLE 之后，使用 SQL 注释）
         if database == "sqlite" {
-            if let Some(ref comment) = table_comment {
-                // 判断是否需要添加换行
-                let needs_newline = has_single_indexes || has_composite_indexes;
-                if needs_newline {
-                    sql.push_str("\n\n");
-                } else {
-                    sql.push_str("\n\n");
-                }
+            if let Some(ref comment) = table.table_comment {
+                sql.push_str("\n\n");
                 // SQLite: 使用 SQL 注释 -- comment text
                 sql.push_str(&format!("-- 表注释: {}\n", comment));
             }
         }
 
-        Ok(sql)
+        sql
+    }
+
+    /// 生成 CREATE [UNIQUE] INDEX 语句
+    pub fn create_index_sql(
+        database: &str,
+        table: &ModelTable,
+        index_name: &str,
+        fields: &[String],
+        unique: bool,
+    ) -> String {
+        let fields_str = fields
+            .iter()
+            .map(|f| Self::key_column_sql(database, table, f))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "CREATE {}INDEX {} ON {} ({});",
+            if unique { "UNIQUE " } else { "" },
+            Self::escape_identifier(database, index_name),
+            Self::escape_identifier(database, &table.name),
+            fields_str
+        )
+    }
+
+    /// 索引 / 主键中的列：MySQL 的 TEXT / BLOB 列需要前缀长度（否则报错 1170），使用 255 个字符
+    fn key_column_sql(database: &str, table: &ModelTable, field: &str) -> String {
+        let escaped = Self::escape_identifier(database, field);
+        let sql_type = table
+            .columns
+            .iter()
+            .find(|column| column.name == field)
+            .map(|column| column.sql_type.as_str())
+            .unwrap_or_default();
+        if database == "mysql" && (sql_type.ends_with("TEXT") || sql_type.ends_with("BLOB")) {
+            format!("{}(255)", escaped)
+        } else {
+            escaped
+        }
+    }
+
+    /// 是否整数类型（不含 MySQL 用于布尔的 TINYINT(1)）
+    fn is_integer_type(sql_type: &str) -> bool {
+        let base = sql_type.split(['(', ' ']).next().unwrap_or_default();
+        matches!(base, "SMALLINT" | "INT" | "INTEGER" | "MEDIUMINT" | "BIGINT")
+    }
+
+    /// 生成 PostgreSQL 的 COMMENT ON COLUMN 语句（注释为 None 时清除注释）
+    pub fn column_comment_sql(
+        database: &str,
+        table_name: &str,
+        field_name: &str,
+        comment: Option<&str>,
+    ) -> String {
+        // PostgreSQL: COMMENT ON COLUMN table.column IS 'comment text';
+        // 需要转义单引号
+        let comment_sql = match comment {
+            Some(comment) => format!("'{}'", comment.replace('\'', "''")),
+            None => "NULL".to_string(),
+        };
+        format!(
+            "COMMENT ON COLUMN {}.{} IS {};",
+            Self::escape_identifier(database, table_name),
+            Self::escape_identifier(database, field_name),
+            comment_sql
+        )
     }
 
     /// 解析 #[model(...)] 属性
@@ -418,7 +472,7 @@ impl SqlGenerator {
 
         if let syn::Meta::List(list) = &attr.meta {
             let parser = syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated;
-            if let Ok(metas) = parser.parse2(list.tokens.clone().into()) {
+            if let Ok(metas) = parser.parse2(list.tokens.clone()) {
                 for meta in metas {
                     if let syn::Meta::NameValue(nv) = meta {
                         if nv.path.is_ident("table") {
@@ -612,12 +666,14 @@ impl SqlGenerator {
                 if let Some(segment) = path.segments.first() {
                     if segment.ident == "Option" {
                         if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                            if let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first() {
-                                if let Type::Path(TypePath { path: inner_path, .. }) = inner_ty {
-                                    if let Some(inner_segment) = inner_path.segments.last() {
-                                        if inner_segment.ident == "bool" {
-                                            return true;
-                                        }
+                            if let Some(syn::GenericArgument::Type(Type::Path(TypePath {
+                                path: inner_path,
+                                ..
+                            }))) = args.args.first()
+                            {
+                                if let Some(inner_segment) = inner_path.segments.last() {
+                                    if inner_segment.ident == "bool" {
+                                        return true;
                                     }
                                 }
                             }
@@ -677,7 +733,7 @@ impl SqlGenerator {
                 _ => "INT",
             },
             "i16" => match database {
-                "mysql" => "TINYINT",
+                "mysql" => "SMALLINT",
                 "postgres" => "SMALLINT",
                 "sqlite" => "INTEGER",
                 _ => "SMALLINT",
@@ -727,7 +783,7 @@ impl SqlGenerator {
                 "sqlite" => "INTEGER",
                 _ => "BOOLEAN",
             },
-            // 日期时间类型 - 统一使用带时区的
+            // 日期时间类型
             _ if type_str_clean.contains("chrono::DateTime<chrono::Utc>") 
                 || type_str_clean.contains("DateTime<Utc>") => {
                 match database {
@@ -739,12 +795,12 @@ impl SqlGenerator {
             }
             _ if type_str_clean.contains("chrono::NaiveDateTime") 
                 || type_str_clean.contains("NaiveDateTime") => {
-                // NaiveDateTime 也转换为带时区的（根据用户要求）
+                // 不带时区的类型在 PostgreSQL 中使用 TIMESTAMP（与 sqlxplus::schema 一致）
                 match database {
                     "mysql" => "TIMESTAMP(3)", // MySQL 的 TIMESTAMP 是带时区的，使用 (3) 以兼容更多版本
-                    "postgres" => "TIMESTAMP WITH TIME ZONE",
+                    "postgres" => "TIMESTAMP",
                     "sqlite" => "TEXT",
-                    _ => "TIMESTAMP WITH TIME ZONE",
+                    _ => "TIMESTAMP",
                 }
             }
             _ if type_str_clean.contains("chrono::NaiveDate") 
@@ -760,9 +816,9 @@ impl SqlGenerator {
                 || type_str_clean.contains("NaiveTime") => {
                 match database {
                     "mysql" => "TIME(3)", // 使用 (3) 以兼容更多 MySQL 版本
-                    "postgres" => "TIME WITH TIME ZONE",
+                    "postgres" => "TIME",
                     "sqlite" => "TEXT",
-                    _ => "TIME WITH TIME ZONE",
+                    _ => "TIME",
                 }
             }
             // JSON 类型
@@ -817,7 +873,7 @@ impl SqlGenerator {
             if attr.path().is_ident("column") {
                 if let syn::Meta::List(list) = &attr.meta {
                     let parser = syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated;
-                    if let Ok(metas) = parser.parse2(list.tokens.clone().into()) {
+                    if let Ok(metas) = parser.parse2(list.tokens.clone()) {
                         for meta_item in metas {
                             match meta_item {
                                 syn::Meta::Path(path) => {
@@ -910,7 +966,7 @@ impl SqlGenerator {
     }
 
     /// 转义标识符（根据数据库类型）
-    fn escape_identifier(database: &str, name: &str) -> String {
+    pub fn escape_identifier(database: &str, name: &str) -> String {
         match database {
             "mysql" => format!("`{}`", name),
             "postgres" => format!("\"{}\"", name),
@@ -920,6 +976,30 @@ impl SqlGenerator {
     }
 }
 
+/// 从模型解析出的表结构
+#[derive(Debug, Clone)]
+pub struct ModelTable {
+    pub name: String,
+    pub pk: String,
+    pub table_comment: Option<String>,
+    pub columns: Vec<ModelColumn>,
+    pub unique_indexes: Vec<(String, String)>, // 单字段唯一索引：(索引名, 字段名)
+    pub indexes: Vec<(String, String)>,        // 单字段普通索引：(索引名, 字段名)
+    pub composite_indexes: Vec<(String, Vec<String>)>, // 联合索引：(索引名, 按顺序排列的字段名)
+}
+
+/// 从模型解析出的列
+#[derive(Debug, Clone)]
+pub struct ModelColumn {
+    pub name: String,
+    pub sql_type: String, // 不含 SERIAL 替换的 SQL 类型
+    pub nullable: bool,   // 主键和 not_null 字段不可空
+    pub is_pk: bool,
+    pub default: Option<String>, // 格式化后的 SQL 默认值
+    pub comment: Option<String>,
+    pub definition: String, // 完整列定义（不含缩进和 SQLite 注释）
+}
+
 /// 字段的 column 属性元数据
 #[derive(Default)]
 struct ColumnMeta {
@@ -935,3 +1015,81 @@ struct ColumnMeta {
     comment: Option<String>, // 字段注释
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust_type_to_sql() {
+        // 与 sqlxplus::schema 共用的映射表，保证 `diff` 不会把运行时建的表报告为类型漂移
+        for line in include_str!("../../core/testdata/type_mapping.txt").lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = line.split('|').map(str::trim).collect();
+            let [rust_type, mysql, postgres, sqlite] = parts[..] else {
+                panic!("invalid mapping line: {}", line);
+            };
+            let ty: Type = syn::parse_str(rust_type).unwrap();
+            let optional: Type = syn::parse_str(&format!("Option<{}>", rust_type)).unwrap();
+            for (database, expected) in [("mysql", mysql), ("postgres", postgres), ("sqlite", sqlite)] {
+                assert_eq!(SqlGenerator::rust_type_to_sql(&ty, database).unwrap(), expected, "{} on {}", rust_type, database);
+                assert_eq!(SqlGenerator::rust_type_to_sql(&optional, database).unwrap(), expected, "Option<{}> on {}", rust_type, database);
+            }
+        }
+    }
+
+    fn parse(source: &str, database: &str) -> ModelTable {
+        let item: syn::ItemStruct = syn::parse_str(source).unwrap();
+        let attr = item.attrs.iter().find(|attr| attr.path().is_ident("model")).unwrap();
+        SqlGenerator::parse_table(&item, attr, database).unwrap()
+    }
+
+    #[test]
+    fn test_auto_increment_consistent_across_drivers() {
+        let cases = [
+            ("i32", "mysql", "`id` INT AUTO_INCREMENT"),
+            ("i32", "postgres", "\"id\" SERIAL"),
+            ("i16", "postgres", "\"id\" SMALLSERIAL"),
+            ("u64", "mysql", "`id` BIGINT UNSIGNED AUTO_INCREMENT"),
+            ("i64", "sqlite", "\"id\" INTEGER"),
+            ("String", "mysql", "`id` VARCHAR(255)"),
+            ("String", "postgres", "\"id\" VARCHAR(255)"),
+        ];
+        for (rust_type, database, expected) in cases {
+            let source = format!(
+                "#[model(table = \"t\", pk = \"id\")] struct T {{ id: Option<{}>, name: Option<String> }}",
+                rust_type
+            );
+            let table = parse(&source, database);
+            assert_eq!(table.columns[0].definition, expected, "{} on {}", rust_type, database);
+        }
+
+        let source = "#[model(table = \"t\", pk = \"id\")] struct T { id: String, #[column(auto_increment)] seq: i32 }";
+        assert_eq!(parse(source, "postgres").columns[1].definition, "\"seq\" SERIAL");
+        assert_eq!(parse(source, "mysql").columns[1].definition, "`seq` INT NOT NULL AUTO_INCREMENT");
+    }
+
+    #[test]
+    fn test_mysql_text_key_prefix() {
+        let source = r#"
+            #[model(table = "doc", pk = "id")]
+            struct Doc {
+                id: Option<i64>,
+                #[column(unique)]
+                body_text: Option<String>,
+                #[column(index)]
+                data: Option<Vec<u8>>,
+                #[column(index)]
+                title: Option<String>,
+            }
+        "#;
+        let sql = SqlGenerator::render_create_table(&parse(source, "mysql"), "mysql");
+        assert!(sql.contains("UNIQUE KEY `uk_doc_body_text` (`body_text`(255))"), "{}", sql);
+        assert!(sql.contains("CREATE INDEX `idx_doc_data` ON `doc` (`data`(255));"), "{}", sql);
+        assert!(sql.contains("CREATE INDEX `idx_doc_title` ON `doc` (`title`);"), "{}", sql);
+
+        let sql = SqlGenerator::render_create_table(&parse(source, "postgres"), "postgres");
+        assert!(sql.contains("CREATE INDEX \"idx_doc_data\" ON \"doc\" (\"data\");"), "{}", sql);
+    }
+}
@@ -1,14 +1,26 @@
 //! Delete Builder - 支持指定 WHERE 条件进行删除
+//!
+//! 模型定义了逻辑删除字段时默认执行逻辑删除（`UPDATE ... SET`，与 `crud::delete_by_id` 一致），
+//! 调用 [`DeleteBuilder::force`] 执行物理删除
 
-use super::query_builder::QueryBuilder;
+use super::query_builder::{BindValue, QueryBuilder};
 use crate::database_info::DatabaseInfo;
 use crate::error::{Result, SqlxPlusError};
+use crate::soft_delete::TrashedScope;
 use crate::traits::Model;
 
 /// Delete Builder - 支持指定 WHERE 条件
 pub struct DeleteBuilder<M: Model> {
     _phantom: std::marker::PhantomData<M>,
     where_builder: Option<QueryBuilder>,
+    force: bool,
+    allow_delete_all: bool,
+}
+
+impl<M: Model> Default for DeleteBuilder<M> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<M: Model> DeleteBuilder<M> {
@@ -17,10 +29,14 @@ impl<M: Model> DeleteBuilder<M> {
         Self {
             _phantom: std::marker::PhantomData,
             where_builder: None,
+            force: false,
+            allow_delete_all: false,
         }
     }
 
     /// 添加 WHERE 条件（复用 QueryBuilder）
+    ///
+    /// 逻辑删除时默认只处理未删除的记录，可以通过 `with_trashed()` / `only_trashed()` 调整范围
     pub fn condition<F>(mut self, f: F) -> Self
     where
         F: FnOnce(QueryBuilder) -> QueryBuilder,
@@ -31,7 +47,96 @@ impl<M: Model> DeleteBuilder<M> {
         self
     }
 
-    /// 执行删除
+    /// 强制物理删除（即使模型定义了逻辑删除字段）
+    ///
+    /// 物理删除不会自动排除已删除的记录；配合 `only_trashed()` 可以清理已逻辑删除的记录
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// DeleteBuilder::<User>::new()
+    ///     .condition(|q| q.only_trashed())
+    ///     .force()
+    ///     .execute(pool)
+    ///     .await?;
+    /// ```
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+
+    /// 构建 DELETE（或逻辑删除的 UPDATE）语句和绑定值
+    fn build_sql<DB>(&self) -> Result<(String, Vec<BindValue>)>
+    where
+        DB: DatabaseInfo,
+    {
+        let driver = DB::get_driver();
+        let escaped_table = DB::escape_identifier(M::TABLE);
+
+        // 没有 WHERE 条件时返回错误（防止误删所有数据）：只调用 with_trashed() 等不产生条件的方法
+        // 不算作条件，only_trashed() 会限定为已逻辑删除的记录
+        let empty;
+        let where_builder = match &self.where_builder {
+            Some(where_builder) => where_builder,
+            None => {
+                empty = QueryBuilder::new("");
+                &empty
+            }
+        };
+        let only_trashed =
+            M::SOFT_DELETE_FIELD.is_some() && where_builder.trashed_scope() == TrashedScope::Only;
+        if !where_builder.has_conditions() && !only_trashed && !self.allow_delete_all {
+            return Err(SqlxPlusError::InvalidField(
+                "Delete operation requires WHERE condition. Use allow_delete_all() to explicitly allow deleting all records.".to_string(),
+            ));
+        }
+
+        // 逻辑删除：SET 子句在前，WHERE 占位符从 SET 之后开始编号
+        let mut placeholder_index = 0;
+        let mut binds = Vec::new();
+        let soft_delete = if self.force {
+            None
+        } else {
+            crate::soft_delete::deleted_set_clause::<DB, M>(&mut placeholder_index)
+        };
+        if let Some((_, timestamps)) = &soft_delete {
+            let now = crate::timestamp::now();
+            binds.extend(timestamps.iter().filter_map(|kind| kind.value_at(now)));
+        }
+
+        // 逻辑删除默认只处理未删除的记录；物理删除仅在 only_trashed() 时限定范围
+        let scope = match (where_builder.trashed_scope(), soft_delete.is_some()) {
+            (TrashedScope::Exclude, false) => TrashedScope::With,
+            (scope, _) => scope,
+        };
+        let scope_sql = crate::soft_delete::scope_condition::<DB, M>(scope);
+
+        // 构建 WHERE 子句
+        let where_clause = if where_builder.has_conditions() {
+            let (where_sql, _) = where_builder.build_where_sql(driver, placeholder_index);
+            binds.extend(where_builder.where_binds().iter().cloned());
+            match scope_sql {
+                // 自定义条件可能包含顶层 OR，需要加括号
+                Some(scope_sql) => format!("({}) AND {}", where_sql, scope_sql),
+                None => where_sql,
+            }
+        } else {
+            scope_sql.unwrap_or_default()
+        };
+
+        let statement = match soft_delete {
+            Some((set_clause, _)) => format!("UPDATE {} SET {}", escaped_table, set_clause),
+            None => format!("DELETE FROM {}", escaped_table),
+        };
+        let sql = if where_clause.is_empty() {
+            statement
+        } else {
+            format!("{} WHERE {}", statement, where_clause)
+        };
+        Ok((sql, binds))
+    }
+
+    /// 执行删除，返回受影响的行数
     pub async fn execute<'e, 'c: 'e, DB, E>(self, executor: E) -> Result<u64>
     where
         DB: sqlx::Database + DatabaseInfo,
@@ -47,74 +152,128 @@ impl<M: Model> DeleteBuilder<M> {
         bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
         Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
         Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     {
-        let driver = DB::get_driver();
-        let table = M::TABLE;
-        let escaped_table = DB::escape_identifier(table);
-
-        // 构建 WHERE 子句
-        let (where_clause, where_binds) = if let Some(where_builder) = &self.where_builder {
-            // 检查是否是 allow_delete_all 的情况（空的 QueryBuilder）
-            if !where_builder.has_conditions() {
-                // 允许删除所有记录
-                (String::new(), Vec::new())
-            } else {
-                let (where_sql, _) = where_builder.build_where_sql(driver, 0);
-                let where_binds = where_builder.where_binds().to_vec();
-                (where_sql, where_binds)
-            }
-        } else {
-            // 如果没有 WHERE 条件，返回错误（防止误删所有数据）
-            return Err(SqlxPlusError::InvalidField(
-                "Delete operation requires WHERE condition. Use allow_delete_all() to explicitly allow deleting all records.".to_string(),
-            ));
-        };
-
-        // 构建 SQL
-        let sql = if where_clause.is_empty() {
-            format!("DELETE FROM {}", escaped_table)
-        } else {
-            format!("DELETE FROM {} WHERE {}", escaped_table, where_clause)
-        };
+        let (sql, binds) = self.build_sql::<DB>()?;
 
         // 执行删除
         let mut query = sqlx::query(&sql);
 
-        // 绑定 WHERE 子句的值
-        for bind_value in where_binds {
+        // 绑定 SET 子句（删除时间 / 更新时间）和 WHERE 子句的值
+        for bind_value in binds {
             crate::apply_bind_value!(query, bind_value);
         }
 
         let result = query.execute(executor).await?;
-        // 在泛型上下文中，使用 match 根据数据库类型获取 rows_affected
-        let rows_affected = match DB::get_driver() {
-            crate::db_pool::DbDriver::MySql => unsafe {
-                use sqlx::mysql::MySqlQueryResult;
-                let ptr: *const DB::QueryResult = &result;
-                let mysql_ptr = ptr as *const MySqlQueryResult;
-                (*mysql_ptr).rows_affected()
-            },
-            crate::db_pool::DbDriver::Postgres => unsafe {
-                use sqlx::postgres::PgQueryResult;
-                let ptr: *const DB::QueryResult = &result;
-                let pg_ptr = ptr as *const PgQueryResult;
-                (*pg_ptr).rows_affected()
-            },
-            crate::db_pool::DbDriver::Sqlite => unsafe {
-                use sqlx::sqlite::SqliteQueryResult;
-                let ptr: *const DB::QueryResult = &result;
-                let sqlite_ptr = ptr as *const SqliteQueryResult;
-                (*sqlite_ptr).rows_affected()
-            },
-        };
-        Ok(rows_affected)
+        Ok(DB::rows_affected(&result))
     }
 
     /// 允许删除所有记录（危险操作，需要明确调用）
+    ///
+    /// 仅在 `condition` 没有产生任何条件时生效，可以与 `condition(|q| q.with_trashed())` 组合使用
     pub fn allow_delete_all(mut self) -> Self {
-        // 创建一个空的 WHERE builder，表示允许删除所有
-        // 在 execute 中会特殊处理
-        self.where_builder = Some(QueryBuilder::new(""));
+        self.allow_delete_all = true;
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soft_delete::SoftDeleteKind;
+    use crate::timestamp::TimestampKind;
+
+    struct Note;
+
+    impl Model for Note {
+        const TABLE: &'static str = "note";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const SOFT_DELETE_FIELD: Option<&'static str> = Some("deleted_at");
+        const SOFT_DELETE_KIND: SoftDeleteKind =
+            SoftDeleteKind::Timestamp(TimestampKind::NaiveDateTime);
+        const UPDATED_AT_FIELD: Option<&'static str> = Some("updated_at");
+    }
+
+    struct Log;
+
+    impl Model for Log {
+        const TABLE: &'static str = "log";
+        const PK: &'static str = "id";
+        type Id = i64;
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_soft_delete_sql() {
+        let (sql, binds) = DeleteBuilder::<Note>::new()
+            .condition(|q| q.and_eq("owner", 1i64).or_eq("owner", 2i64))
+            .build_sql::<sqlx::Postgres>()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "note" SET "deleted_at" = $1, "updated_at" = $2 WHERE ("owner" = $3 OR "owner" = $4) AND "deleted_at" IS NULL"#
+        );
+        assert_eq!(binds.len(), 4);
+        assert!(matches!(binds[0], BindValue::NaiveDateTime(_)));
+        assert!(matches!(binds[1], BindValue::Int64(_)));
+        assert_eq!(binds[2..], [BindValue::Int64(1), BindValue::Int64(2)]);
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_force_delete_sql() {
+        let (sql, binds) = DeleteBuilder::<Note>::new()
+            .condition(|q| q.and_eq("owner", 1i64))
+            .force()
+            .build_sql::<sqlx::MySql>()
+            .unwrap();
+        assert_eq!(sql, "DELETE FROM `note` WHERE `owner` = ?");
+        assert_eq!(binds, vec![BindValue::Int64(1)]);
+
+        // 清理已逻辑删除的记录
+        let (sql, _) = DeleteBuilder::<Note>::new()
+            .condition(|q| q.only_trashed())
+            .force()
+            .build_sql::<sqlx::MySql>()
+            .unwrap();
+        assert_eq!(sql, "DELETE FROM `note` WHERE `deleted_at` IS NOT NULL");
+
+        // 没有逻辑删除字段的模型始终物理删除
+        let (sql, _) = DeleteBuilder::<Log>::new()
+            .allow_delete_all()
+            .build_sql::<sqlx::MySql>()
+            .unwrap();
+        assert_eq!(sql, "DELETE FROM `log`");
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_delete_requires_condition() {
+        assert!(DeleteBuilder::<Log>::new().build_sql::<sqlx::Sqlite>().is_err());
+        // 只调整范围、不产生条件时同样需要 allow_delete_all
+        assert!(DeleteBuilder::<Note>::new()
+            .condition(|q| q.with_trashed())
+            .build_sql::<sqlx::Sqlite>()
+            .is_err());
+        assert!(DeleteBuilder::<Note>::new()
+            .condition(|q| q.with_trashed())
+            .force()
+            .build_sql::<sqlx::Sqlite>()
+            .is_err());
+        // 没有逻辑删除字段时 only_trashed() 不限定范围
+        assert!(DeleteBuilder::<Log>::new()
+            .condition(|q| q.only_trashed())
+            .build_sql::<sqlx::Sqlite>()
+            .is_err());
+
+        let (sql, _) = DeleteBuilder::<Note>::new()
+            .condition(|q| q.with_trashed())
+            .allow_delete_all()
+            .force()
+            .build_sql::<sqlx::Sqlite>()
+            .unwrap();
+        assert_eq!(sql, r#"DELETE FROM "note""#);
+    }
+}
@@ -2,26 +2,80 @@
 
 use crate::database_info::DatabaseInfo;
 use crate::error::{Result, SqlxPlusError};
+use crate::primary_key::{self, BindPrimaryKey};
 use crate::traits::Model;
 
 /// Insert Builder - 支持指定插入字段
 ///
 /// 注意：此 Builder 需要 Model 实现 `UpdateFields` trait（由 CRUD derive 宏自动生成）
+///
+/// 支持两种模式：
+/// - 单条插入：`InsertBuilder::new(model)`，使用 `execute` 执行
+/// - 批量插入：`InsertBuilder::many(models)`，使用 `execute_many` 执行，生成多行 VALUES 语句
+///
+/// 通过 `on_conflict` / `do_update` / `do_nothing` 可生成 upsert 语句
 pub struct InsertBuilder<M: Model> {
-    model: M,
+    models: Vec<M>,
     fields: Vec<String>,        // 要插入的字段列表（空表示插入所有非主键字段）
     ignore_fields: Vec<String>, // 忽略的字段（如主键、自动递增字段等）
+    on_conflict: Option<OnConflict>,
+}
+
+/// 冲突时的处理方式
+#[derive(Debug, Clone, PartialEq)]
+enum ConflictAction {
+    /// 更新指定字段（空表示更新除冲突列外的所有插入字段）
+    DoUpdate(Vec<String>),
+    /// 忽略冲突的记录
+    DoNothing,
 }
 
+/// upsert 配置
+#[derive(Debug, Clone, Default)]
+struct OnConflict {
+    target: Vec<String>, // 冲突列（空表示使用 Model::UNIQUE_FIELDS 的第一个字段）
+    action: Option<ConflictAction>,
+}
+
+use super::query_builder::BindValue;
 use super::update_builder::UpdateFields;
 
+/// 单条记录要插入的列和值（值为 `None` 表示由数据库写入当前时间）
+type RowValues = Vec<(String, Option<BindValue>)>;
+
+/// 单条 INSERT 语句：SQL 以及每行的主键（已赋值时）和其余绑定值
+struct InsertStatement<Id> {
+    sql: String,
+    rows: Vec<(Option<Id>, Vec<BindValue>)>,
+}
+
 impl<M: Model> InsertBuilder<M> {
     /// 创建 InsertBuilder
     pub fn new(model: M) -> Self {
         Self {
-            model,
+            models: vec![model],
+            fields: Vec::new(),
+            ignore_fields: Vec::new(),
+            on_conflict: None,
+        }
+    }
+
+    /// 创建批量插入的 InsertBuilder
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let ids = InsertBuilder::many(users)
+    ///     .fields(&["username", "email"])
+    ///     .execute_many(pool.mysql_pool())
+    ///     .await?;
+    /// ```
+    pub fn many(models: Vec<M>) -> Self {
+        Self {
+            models,
             fields: Vec::new(),
             ignore_fields: Vec::new(),
+            on_conflict: None,
         }
     }
 
@@ -44,35 +98,146 @@ impl<M: Model> InsertBuilder<M> {
         self
     }
 
-    /// 执行插入
-    pub async fn execute<'e, 'c: 'e, DB, E>(self, executor: E) -> Result<crate::crud::Id>
+    /// 指定冲突列（唯一约束），需配合 `do_update` 或 `do_nothing` 使用
+    ///
+    /// 未调用时默认使用 `#[column(unique)]` 标记的第一个字段。
+    /// MySQL 的 `ON DUPLICATE KEY UPDATE` 不支持指定冲突列，此设置仅对 PostgreSQL / SQLite 生效。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let id = InsertBuilder::new(user)
+    ///     .on_conflict(&["email"])
+    ///     .do_update(&["nick_name", "updated_at"])
+    ///     .execute(pool.mysql_pool())
+    ///     .await?;
+    /// ```
+    pub fn on_conflict(mut self, columns: &[&str]) -> Self {
+        self.on_conflict.get_or_insert_with(OnConflict::default).target =
+            columns.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// 冲突时更新指定字段，值取自本次插入的数据
+    ///
+    /// - MySQL：`ON DUPLICATE KEY UPDATE col = VALUES(col)`
+    /// - PostgreSQL / SQLite：`ON CONFLICT (...) DO UPDATE SET col = EXCLUDED.col`
+    ///
+    /// 传入空列表时更新除冲突列外的所有插入字段。
+    ///
+    /// 注意：MySQL 8.0.20 起 `VALUES(col)` 已被标记为废弃（仍可使用），
+    /// 为兼容 MySQL 5.7 / MariaDB 暂未改用 `INSERT ... AS new ... UPDATE col = new.col` 的行别名写法。
+    pub fn do_update(mut self, columns: &[&str]) -> Self {
+        self.on_conflict.get_or_insert_with(OnConflict::default).action = Some(
+            ConflictAction::DoUpdate(columns.iter().map(|s| s.to_string()).collect()),
+        );
+        self
+    }
+
+    /// 冲突时忽略该记录
+    ///
+    /// - MySQL：`INSERT IGNORE`
+    /// - PostgreSQL / SQLite：`ON CONFLICT (...) DO NOTHING`
+    ///
+    /// 被忽略的记录没有 ID，需使用 [`execute_optional`](Self::execute_optional) 执行（被忽略时返回 `None`）；
+    /// `execute_many` 无法将返回的 ID 与传入记录对应，不支持 `do_nothing`
+    ///
+    /// 注意：MySQL 的 `INSERT IGNORE` 不只忽略唯一键冲突，还会把其他错误降级为警告，
+    /// 如数据截断、`NOT NULL` 列写入 NULL（写入隐式默认值）、分区不匹配等，这些记录会被静默写入或跳过；
+    /// 依赖严格模式校验数据时应改用 `do_update`，或在插入前自行校验。
+    pub fn do_nothing(mut self) -> Self {
+        self.on_conflict.get_or_insert_with(OnConflict::default).action =
+            Some(ConflictAction::DoNothing);
+        self
+    }
+
+    /// 是否配置了 `do_nothing`（冲突记录会被忽略）
+    fn skips_conflicts(&self) -> bool {
+        matches!(
+            self.on_conflict,
+            Some(OnConflict { action: Some(ConflictAction::DoNothing), .. })
+        )
+    }
+
+    /// 确定冲突列，未指定时使用 Model::UNIQUE_FIELDS 的第一个字段
+    fn conflict_target(&self, conflict: &OnConflict) -> Result<Vec<String>>
+    where
+        M: UpdateFields,
+    {
+        let target: Vec<String> = if conflict.target.is_empty() {
+            M::UNIQUE_FIELDS
+                .first()
+                .map(|s| vec![s.to_string()])
+                .unwrap_or_default()
+        } else {
+            conflict.target.clone()
+        };
+        for column in &target {
+            if !primary_key::pk_columns::<M>().any(|pk| pk == column) && !M::has_field(column) {
+                return Err(SqlxPlusError::InvalidField(format!(
+                    "Field '{}' does not exist in model '{}'",
+                    column,
+                    M::TABLE
+                )));
+            }
+        }
+        Ok(target)
+    }
+
+    /// 确定冲突时要更新的字段
+    fn conflict_update_fields(
+        &self,
+        action: &ConflictAction,
+        columns: &[&str],
+        target: &[String],
+    ) -> Result<Vec<String>>
+    where
+        M: UpdateFields,
+    {
+        let ConflictAction::DoUpdate(fields) = action else {
+            return Ok(Vec::new());
+        };
+        let fields: Vec<String> = if fields.is_empty() {
+            columns
+                .iter()
+                .filter(|&&c| {
+                    !primary_key::pk_columns::<M>().any(|pk| pk == c) && !target.iter().any(|t| t == c)
+                })
+                .map(|s| s.to_string())
+                .collect()
+        } else {
+            for field_name in fields {
+                if !M::has_field(field_name) {
+                    return Err(SqlxPlusError::InvalidField(format!(
+                        "Field '{}' does not exist in model '{}'",
+                        field_name,
+                        M::TABLE
+                    )));
+                }
+            }
+            fields.clone()
+        };
+        if fields.is_empty() {
+            return Err(SqlxPlusError::InvalidField(
+                "No fields to update on conflict".to_string(),
+            ));
+        }
+        Ok(fields)
+    }
+
+    /// 确定要插入的字段列表
+    fn fields_to_insert(&self) -> Result<Vec<String>>
     where
-        DB: sqlx::Database + DatabaseInfo,
-        for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
-        E: sqlx::Executor<'c, Database = DB> + Send,
         M: UpdateFields,
-        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
-        String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
-        i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        usize: sqlx::ColumnIndex<DB::Row>,
     {
         let table = M::TABLE;
-        let pk = M::PK;
-        let escaped_table = DB::escape_identifier(table);
+        let is_pk = |name: &str| primary_key::pk_columns::<M>().any(|pk| pk == name);
 
-        // 确定要插入的字段列表
         let fields_to_insert = if self.fields.is_empty() {
-            // 如果没有指定字段，插入所有非主键字段
+            // 如果没有指定字段，插入所有非主键字段（已赋值的主键在构建语句时写入）
             M::get_all_field_names()
                 .iter()
-                .filter(|&&name| name != pk && !self.ignore_fields.contains(&name.to_string()))
+                .filter(|&&name| !is_pk(name) && !self.ignore_fields.contains(&name.to_string()))
                 .map(|s| s.to_string())
                 .collect::<Vec<_>>()
         } else {
@@ -84,10 +249,10 @@ impl<M: Model> InsertBuilder<M> {
                         field_name, table
                     )));
                 }
-                if field_name == pk {
+                if is_pk(field_name) {
                     return Err(SqlxPlusError::InvalidField(format!(
-                        "Cannot insert primary key field '{}'",
-                        pk
+                        "Primary key field '{}' is inserted automatically when set on the model",
+                        field_name
                     )));
                 }
                 if self.ignore_fields.contains(field_name) {
@@ -97,7 +262,7 @@ impl<M: Model> InsertBuilder<M> {
                     )));
                 }
             }
-            self.fields
+            self.fields.clone()
         };
 
         if fields_to_insert.is_empty() {
@@ -106,104 +271,633 @@ impl<M: Model> InsertBuilder<M> {
             ));
         }
 
-        // 构建 INSERT 语句
-        let mut field_names = Vec::new();
-        let mut values = Vec::new();
-        let mut placeholder_index = 0;
-        let now_ms = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64;
-
-        // 辅助闭包：判断字段名是否为更新时间字段
-        let is_updated_at = |name: &str| -> bool {
-            M::UPDATED_AT_FIELD.map_or(name == "updated_at", |f| f == name)
-        };
+        Ok(fields_to_insert)
+    }
 
-        for field_name in &fields_to_insert {
-            if field_name == "created_at" || is_updated_at(field_name) {
-                if let Some(bind_value) = self.model.get_field_value(field_name) {
-                    let escaped_field = DB::escape_identifier(field_name);
-                    field_names.push(escaped_field);
-                    values.push((bind_value, placeholder_index));
-                    placeholder_index += 1;
-                } else {
-                    let escaped_field = DB::escape_identifier(field_name);
-                    field_names.push(escaped_field);
-                    values.push((super::query_builder::BindValue::Int64(now_ms), placeholder_index));
-                    placeholder_index += 1;
-                }
-            } else if let Some(bind_value) = self.model.get_field_value(field_name) {
-                let escaped_field = DB::escape_identifier(field_name);
-                field_names.push(escaped_field);
-                values.push((bind_value, placeholder_index));
-                placeholder_index += 1;
+    /// 收集单条记录要插入的列和值
+    ///
+    /// 值为 `None` 表示由数据库生成的时间戳列（写入 `CURRENT_TIMESTAMP`）
+    fn row_values(
+        model: &M,
+        fields_to_insert: &[String],
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> RowValues
+    where
+        M: UpdateFields,
+    {
+        let mut values = Vec::new();
+        for field_name in fields_to_insert {
+            if let Some(bind_value) = model.get_field_value(field_name) {
+                values.push((field_name.clone(), Some(bind_value)));
+            } else if let Some(kind) = crate::timestamp::insert_timestamp_kind::<M>(field_name) {
+                // 创建 / 更新时间字段为空时按时间戳类型填充当前时间
+                values.push((field_name.clone(), kind.value_at(now)));
             }
             // 如果字段值为 None（对于 Option 类型），跳过该字段
         }
+        values
+    }
 
-        if field_names.is_empty() {
-            return Err(SqlxPlusError::InvalidField(
-                "No valid field values to insert".to_string(),
-            ));
+    /// 构建 INSERT 语句列表
+    ///
+    /// 相邻且插入列一致的记录合并为一条多行 VALUES 语句，并按绑定参数上限拆分。
+    /// 主键已赋值的记录写入主键列，未赋值的记录由数据库生成主键（仅整数主键）
+    fn build_statements<DB>(&self) -> Result<Vec<InsertStatement<M::Id>>>
+    where
+        DB: sqlx::Database + DatabaseInfo,
+        M: UpdateFields,
+    {
+        let fields_to_insert = self.fields_to_insert()?;
+        let now = crate::timestamp::now();
+        let driver = DB::get_driver();
+        let pk_columns: Vec<&str> = primary_key::pk_columns::<M>().collect();
+
+        let rows: Vec<(Option<M::Id>, RowValues)> = self
+            .models
+            .iter()
+            .map(|model| (model.insert_pk_value(), Self::row_values(model, &fields_to_insert, now)))
+            .collect();
+        if rows.iter().any(|(id, _)| id.is_none()) {
+            primary_key::ensure_generated::<M>()?;
         }
 
-        // 构建 SQL
-        let fields_str = field_names.join(", ");
-        let placeholders: Vec<String> = (0..values.len()).map(|i| DB::placeholder(i)).collect();
-        let placeholders_str = placeholders.join(", ");
-
-        // 根据数据库类型构建 SQL
-        let (sql, use_returning) = match DB::get_driver() {
-            crate::db_pool::DbDriver::Postgres => {
-                // PostgreSQL 使用 RETURNING 子句
-                let sql = format!(
-                    "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
-                    escaped_table, fields_str, placeholders_str, pk
-                );
-                (sql, true)
-            }
-            _ => {
-                // MySQL 和 SQLite 不使用 RETURNING
-                let sql = format!(
-                    "INSERT INTO {} ({}) VALUES ({})",
-                    escaped_table, fields_str, placeholders_str
-                );
-                (sql, false)
+        let mut statements = Vec::new();
+        let mut start = 0;
+        while start < rows.len() {
+            let (first_id, first_values) = &rows[start];
+            // 主键未赋值时由数据库生成，PostgreSQL / SQLite 通过 RETURNING 取回
+            let generated_pk = if first_id.is_none() { Some(pk_columns[0]) } else { None };
+            let returning = match driver {
+                crate::db_pool::DbDriver::Postgres | crate::db_pool::DbDriver::Sqlite => generated_pk,
+                crate::db_pool::DbDriver::MySql => None,
+            };
+            let mut columns: Vec<&str> = Vec::new();
+            if first_id.is_some() {
+                columns.extend(&pk_columns);
+            }
+            columns.extend(first_values.iter().map(|(name, _)| name.as_str()));
+            let db_now_columns: Vec<&str> = first_values
+                .iter()
+                .filter(|(_, value)| value.is_none())
+                .map(|(name, _)| name.as_str())
+                .collect();
+            if columns.is_empty() {
+                return Err(SqlxPlusError::InvalidField(
+                    "No valid field values to insert".to_string(),
+                ));
+            }
+            // MySQL 的 upsert 只能通过 LAST_INSERT_ID() 取得单行 ID，每条记录单独执行
+            let chunk_size = match (driver, &self.on_conflict) {
+                (crate::db_pool::DbDriver::MySql, Some(_)) => 1,
+                _ => crate::crud::insert_chunk_size(driver, columns.len()),
+            };
+            let signature = |(id, values): &(Option<M::Id>, RowValues)| {
+                (
+                    id.is_some(),
+                    values
+                        .iter()
+                        .map(|(name, value)| (name.clone(), value.is_none()))
+                        .collect::<Vec<_>>(),
+                )
+            };
+            let mut end = start + 1;
+            while end < rows.len()
+                && end - start < chunk_size
+                && signature(&rows[end]) == signature(&rows[start])
+            {
+                end += 1;
             }
-        };
+
+            let sql = match &self.on_conflict {
+                None => crate::crud::build_insert_sql_with_db_now::<DB>(
+                    M::TABLE,
+                    &columns,
+                    &db_now_columns,
+                    end - start,
+                    returning,
+                ),
+                Some(conflict) => {
+                    let action = conflict.action.as_ref().ok_or_else(|| {
+                        SqlxPlusError::InvalidField(
+                            "on_conflict() requires do_update() or do_nothing()".to_string(),
+                        )
+                    })?;
+                    let target = self.conflict_target(conflict)?;
+                    let update_fields = self.conflict_update_fields(action, &columns, &target)?;
+                    let insert_sql = crate::crud::build_insert_sql_with_db_now::<DB>(
+                        M::TABLE,
+                        &columns,
+                        &db_now_columns,
+                        end - start,
+                        None,
+                    );
+                    build_upsert_sql::<DB>(insert_sql, generated_pk, &target, action, &update_fields, returning)?
+                }
+            };
+            let rows = rows[start..end]
+                .iter()
+                .map(|(id, values)| {
+                    (
+                        id.clone(),
+                        values.iter().filter_map(|(_, value)| value.clone()).collect(),
+                    )
+                })
+                .collect();
+            statements.push(InsertStatement { sql, rows });
+            start = end;
+        }
+
+        Ok(statements)
+    }
+
+    /// 执行插入
+    ///
+    /// 主键已赋值时写入主键列并原样返回，否则返回数据库生成的自增 ID（与 `Crud::insert` 一致）。
+    /// 只能用于单条记录（`InsertBuilder::new`），批量插入请使用 [`execute_many`](Self::execute_many)。
+    /// 记录被 `do_nothing` 忽略时返回错误，需要区分这种情况请使用 [`execute_optional`](Self::execute_optional)
+    pub async fn execute<'e, 'c: 'e, DB, E>(self, executor: E) -> Result<M::Id>
+    where
+        DB: sqlx::Database + DatabaseInfo,
+        for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+        E: sqlx::Executor<'c, Database = DB> + Send,
+        M: UpdateFields,
+        M::Id: BindPrimaryKey<DB>,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+        i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        usize: sqlx::ColumnIndex<DB::Row>,
+    {
+        self.execute_optional(executor).await?.ok_or_else(|| {
+            SqlxPlusError::Other(format!(
+                "Insert into '{}' was skipped by do_nothing(); use execute_optional() to handle skipped rows",
+                M::TABLE
+            ))
+        })
+    }
+
+    /// 执行插入，记录被 `do_nothing` 忽略时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let id = InsertBuilder::new(user)
+    ///     .do_nothing()
+    ///     .execute_optional(pool.mysql_pool())
+    ///     .await?;
+    /// if id.is_none() {
+    ///     println!("email already exists, skipped");
+    /// }
+    /// ```
+    pub async fn execute_optional<'e, 'c: 'e, DB, E>(self, executor: E) -> Result<Option<M::Id>>
+    where
+        DB: sqlx::Database + DatabaseInfo,
+        for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+        E: sqlx::Executor<'c, Database = DB> + Send,
+        M: UpdateFields,
+        M::Id: BindPrimaryKey<DB>,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+        i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        usize: sqlx::ColumnIndex<DB::Row>,
+    {
+        if self.models.len() != 1 {
+            return Err(SqlxPlusError::InvalidField(format!(
+                "execute() expects exactly one model, got {}. Use execute_many() for batch insert",
+                self.models.len()
+            )));
+        }
+
+        let skippable = self.skips_conflicts();
+        let InsertStatement { sql, rows } = self
+            .build_statements::<DB>()?
+            .pop()
+            .ok_or_else(|| SqlxPlusError::InvalidField("No valid field values to insert".to_string()))?;
+        let assigned_id = rows[0].0.clone();
 
         // 执行插入
         let mut query = sqlx::query(&sql);
 
-        // 绑定值（按顺序）
-        for (bind_value, _) in values {
-            crate::apply_bind_value!(query, bind_value);
+        // 绑定值（按顺序，已赋值的主键在前）
+        for (id, binds) in rows {
+            if let Some(id) = id {
+                query = id.bind_to(query);
+            }
+            for bind_value in binds {
+                crate::apply_bind_value!(query, bind_value);
+            }
         }
 
-        // 获取插入的 ID
-        let id = if use_returning {
-            // PostgreSQL: 使用 RETURNING 子句
-            use sqlx::Row as _;
-            let row = query.fetch_one(executor).await?;
-            row.get::<i64, _>(0usize)
-        } else {
-            // MySQL 和 SQLite: 从 execute 结果中获取
+        // 主键已赋值：原样返回，DO NOTHING / INSERT IGNORE 跳过冲突记录时影响行数为 0
+        if let Some(id) = assigned_id {
             let result = query.execute(executor).await?;
-            match DB::get_driver() {
-                crate::db_pool::DbDriver::MySql => unsafe {
+            if skippable && DB::rows_affected(&result) == 0 {
+                return Ok(None);
+            }
+            return Ok(Some(id));
+        }
+
+        // 获取数据库生成的 ID
+        let generated_id = match DB::get_driver() {
+            crate::db_pool::DbDriver::Postgres | crate::db_pool::DbDriver::Sqlite => {
+                // PostgreSQL / SQLite: 使用 RETURNING 子句
+                // DO NOTHING 跳过冲突记录时不返回任何行
+                use sqlx::Row as _;
+                match query.fetch_optional(executor).await? {
+                    Some(row) => row.try_get::<i64, _>(0usize)?,
+                    None => return Ok(None),
+                }
+            }
+            crate::db_pool::DbDriver::MySql => {
+                // MySQL: 从 execute 结果中获取，INSERT IGNORE 跳过冲突记录时影响行数为 0
+                let result = query.execute(executor).await?;
+                if skippable && DB::rows_affected(&result) == 0 {
+                    return Ok(None);
+                }
+                unsafe {
                     use sqlx::mysql::MySqlQueryResult;
                     let ptr: *const DB::QueryResult = &result;
                     let mysql_ptr = ptr as *const MySqlQueryResult;
                     (*mysql_ptr).last_insert_id() as i64
-                },
-                crate::db_pool::DbDriver::Sqlite => unsafe {
-                    use sqlx::sqlite::SqliteQueryResult;
-                    let ptr: *const DB::QueryResult = &result;
-                    let sqlite_ptr = ptr as *const SqliteQueryResult;
-                    (*sqlite_ptr).last_insert_rowid() as i64
-                },
-                _ => unreachable!(),
+                }
             }
         };
 
-        Ok(id)
+        primary_key::generated_id::<M>(generated_id).map(Some)
+    }
+
+    /// 执行批量插入
+    ///
+    /// 返回与传入记录一一对应的主键（已赋值的主键原样返回，其余为数据库生成的 ID，
+    /// MySQL 通过 `LAST_INSERT_ID()` 推算连续区间）。
+    /// 拆分为多条语句时不会自动开启事务，需要原子性请传入事务。
+    ///
+    /// 使用 `do_update` 时：MySQL 每条记录单独执行一条语句以取得正确的 ID；
+    /// 不支持 `do_nothing`（被忽略的记录没有 ID，返回结果无法与传入记录对应），
+    /// 需要时请逐条使用 [`execute_optional`](Self::execute_optional)。
+    pub async fn execute_many<'c, DB, E>(self, executor: E) -> Result<Vec<M::Id>>
+    where
+        DB: sqlx::Database + DatabaseInfo,
+        for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+        E: sqlx::Acquire<'c, Database = DB> + Send,
+        for<'x> &'x mut DB::Connection: sqlx::Executor<'x, Database = DB>,
+        M: UpdateFields,
+        M::Id: BindPrimaryKey<DB>,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+        i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        usize: sqlx::ColumnIndex<DB::Row>,
+    {
+        if self.models.is_empty() {
+            return Ok(Vec::new());
+        }
+        if self.skips_conflicts() {
+            return Err(SqlxPlusError::InvalidField(
+                "execute_many() does not support do_nothing(); use execute_optional() for each row".to_string(),
+            ));
+        }
+
+        let statements = self.build_statements::<DB>()?;
+        let mut ids = Vec::with_capacity(self.models.len());
+        let mut conn = executor.acquire().await?;
+
+        for InsertStatement { sql, rows } in statements {
+            let count = rows.len();
+            let generated = rows[0].0.is_none();
+            let mut query = sqlx::query(&sql);
+            for (id, binds) in rows {
+                if let Some(id) = id {
+                    query = id.clone().bind_to(query);
+                    ids.push(id);
+                }
+                for bind_value in binds {
+                    crate::apply_bind_value!(query, bind_value);
+                }
+            }
+
+            // 同一语句中的记录要么都已赋值主键，要么都由数据库生成
+            if !generated {
+                query.execute(&mut *conn).await?;
+                continue;
+            }
+
+            match DB::get_driver() {
+                crate::db_pool::DbDriver::Postgres | crate::db_pool::DbDriver::Sqlite => {
+                    use sqlx::Row as _;
+                    for row in query.fetch_all(&mut *conn).await? {
+                        ids.push(primary_key::generated_id::<M>(row.try_get::<i64, _>(0usize)?)?);
+                    }
+                }
+                crate::db_pool::DbDriver::MySql => {
+                    let result = query.execute(&mut *conn).await?;
+                    // 多行 INSERT 时 LAST_INSERT_ID() 返回本语句第一行的 ID
+                    let first_id = unsafe {
+                        use sqlx::mysql::MySqlQueryResult;
+                        let ptr: *const DB::QueryResult = &result;
+                        let mysql_ptr = ptr as *const MySqlQueryResult;
+                        (*mysql_ptr).last_insert_id() as i64
+                    };
+                    for offset in 0..count as i64 {
+                        ids.push(primary_key::generated_id::<M>(first_id + offset)?);
+                    }
+                }
+            }
+        }
+
+        Ok(ids)
+    }
+}
+
+/// 在 INSERT 语句上附加冲突处理子句
+///
+/// `insert_sql` 为不带 RETURNING 的 INSERT 语句，`generated_pk` 为由数据库生成的主键列（主键未赋值时），
+/// `returning` 为 Some 时在末尾追加 RETURNING 子句
+fn build_upsert_sql<DB>(
+    insert_sql: String,
+    generated_pk: Option<&str>,
+    target: &[String],
+    action: &ConflictAction,
+    update_fields: &[String],
+    returning: Option<&str>,
+) -> Result<String>
+where
+    DB: DatabaseInfo,
+{
+    let mut sql = match DB::get_driver() {
+        crate::db_pool::DbDriver::MySql => match action {
+            ConflictAction::DoNothing => insert_sql.replacen("INSERT INTO", "INSERT IGNORE INTO", 1),
+            ConflictAction::DoUpdate(_) => {
+                let mut assignments: Vec<String> = update_fields
+                    .iter()
+                    .map(|f| {
+                        let col = DB::escape_identifier(f);
+                        // VALUES(col) 自 MySQL 8.0.20 起废弃；行别名写法（AS new ... new.col）
+                        // 不被 MySQL 5.7 / MariaDB 支持，故仍使用 VALUES(col)
+                        format!("{} = VALUES({})", col, col)
+                    })
+                    .collect();
+                // 使 LAST_INSERT_ID() 在更新已有记录时返回该记录的主键
+                if let Some(pk) = generated_pk {
+                    let escaped_pk = DB::escape_identifier(pk);
+                    assignments.push(format!("{} = LAST_INSERT_ID({})", escaped_pk, escaped_pk));
+                }
+                format!("{} ON DUPLICATE KEY UPDATE {}", insert_sql, assignments.join(", "))
+            }
+        },
+        crate::db_pool::DbDriver::Postgres | crate::db_pool::DbDriver::Sqlite => {
+            let target_sql = if target.is_empty() {
+                String::new()
+            } else {
+                let escaped: Vec<String> = target.iter().map(|c| DB::escape_identifier(c)).collect();
+                format!(" ({})", escaped.join(", "))
+            };
+            match action {
+                ConflictAction::DoNothing => {
+                    format!("{} ON CONFLICT{} DO NOTHING", insert_sql, target_sql)
+                }
+                ConflictAction::DoUpdate(_) => {
+                    if target.is_empty() {
+                        return Err(SqlxPlusError::InvalidField(
+                            "do_update() requires a conflict target: call on_conflict() or mark a field with #[column(unique)]"
+                                .to_string(),
+                        ));
+                    }
+                    let assignments: Vec<String> = update_fields
+                        .iter()
+                        .map(|f| {
+                            let col = DB::escape_identifier(f);
+                            format!("{} = EXCLUDED.{}", col, col)
+                        })
+                        .collect();
+                    format!(
+                        "{} ON CONFLICT{} DO UPDATE SET {}",
+                        insert_sql,
+                        target_sql,
+                        assignments.join(", ")
+                    )
+                }
+            }
+        }
+    };
+    if let Some(pk) = returning {
+        sql.push_str(" RETURNING ");
+        sql.push_str(&DB::escape_identifier(pk));
+    }
+    Ok(sql)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Account {
+        email: Option<String>,
+        nick_name: Option<String>,
+    }
+
+    impl Model for Account {
+        const TABLE: &'static str = "account";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const UNIQUE_FIELDS: &'static [&'static str] = &["email"];
+    }
+
+    impl UpdateFields for Account {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "email" => self.email.clone().map(BindValue::from),
+                "nick_name" => self.nick_name.clone().map(BindValue::from),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &["email", "nick_name"]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            matches!(field_name, "id" | "email" | "nick_name")
+        }
+    }
+
+    fn accounts(count: usize) -> Vec<Account> {
+        (0..count)
+            .map(|i| Account {
+                email: Some(format!("u{}@example.com", i)),
+                nick_name: Some(format!("u{}", i)),
+            })
+            .collect()
+    }
+
+    fn update(fields: &[&str]) -> (ConflictAction, Vec<String>) {
+        let fields: Vec<String> = fields.iter().map(|s| s.to_string()).collect();
+        (ConflictAction::DoUpdate(fields.clone()), fields)
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_mysql_upsert_sql() {
+        let insert = crate::crud::build_insert_sql::<sqlx::MySql>("user", &["email", "nick_name"], 1, None);
+        let (action, fields) = update(&["nick_name"]);
+        let sql = build_upsert_sql::<sqlx::MySql>(insert.clone(), Some("id"), &["email".to_string()], &action, &fields, None).unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO `user` (`email`, `nick_name`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `nick_name` = VALUES(`nick_name`), `id` = LAST_INSERT_ID(`id`)"
+        );
+
+        let sql = build_upsert_sql::<sqlx::MySql>(insert, Some("id"), &[], &ConflictAction::DoNothing, &[], None).unwrap();
+        assert_eq!(sql, "INSERT IGNORE INTO `user` (`email`, `nick_name`) VALUES (?, ?)");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres_upsert_sql() {
+        let insert = crate::crud::build_insert_sql::<sqlx::Postgres>("user", &["email", "nick_name", "updated_at"], 1, None);
+        let (action, fields) = update(&["nick_name", "updated_at"]);
+        let sql = build_upsert_sql::<sqlx::Postgres>(insert.clone(), Some("id"), &["email".to_string()], &action, &fields, Some("id")).unwrap();
+        assert_eq!(
+            sql,
+            r#"INSERT INTO "user" ("email", "nick_name", "updated_at") VALUES ($1, $2, $3) ON CONFLICT ("email") DO UPDATE SET "nick_name" = EXCLUDED."nick_name", "updated_at" = EXCLUDED."updated_at" RETURNING "id""#
+        );
+
+        // DO UPDATE 必须指定冲突列
+        assert!(build_upsert_sql::<sqlx::Postgres>(insert, Some("id"), &[], &action, &fields, Some("id")).is_err());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_upsert_do_nothing_sql() {
+        let insert = crate::crud::build_insert_sql::<sqlx::Sqlite>("user", &["email"], 2, None);
+        let sql = build_upsert_sql::<sqlx::Sqlite>(insert, Some("id"), &["email".to_string()], &ConflictAction::DoNothing, &[], Some("id")).unwrap();
+        assert_eq!(
+            sql,
+            r#"INSERT INTO "user" ("email") VALUES (?), (?) ON CONFLICT ("email") DO NOTHING RETURNING "id""#
+        );
+    }
+
+    #[cfg(all(feature = "mysql", feature = "postgres"))]
+    #[test]
+    fn test_many_upsert_statements() {
+        // MySQL 的 upsert 逐条执行，才能通过 LAST_INSERT_ID() 取得每条记录的 ID
+        let statements = InsertBuilder::many(accounts(3))
+            .do_update(&["nick_name"])
+            .build_statements::<sqlx::MySql>()
+            .unwrap();
+        assert_eq!(statements.len(), 3);
+        assert!(statements
+            .iter()
+            .all(|statement| statement.rows.len() == 1 && statement.rows[0].1.len() == 2));
+
+        let statements = InsertBuilder::many(accounts(3))
+            .do_update(&["nick_name"])
+            .build_statements::<sqlx::Postgres>()
+            .unwrap();
+        assert_eq!(statements.len(), 1);
+        assert_eq!(
+            statements[0].sql,
+            r#"INSERT INTO "account" ("email", "nick_name") VALUES ($1, $2), ($3, $4), ($5, $6) ON CONFLICT ("email") DO UPDATE SET "nick_name" = EXCLUDED."nick_name" RETURNING "id""#
+        );
+    }
+
+    struct Membership {
+        tenant_id: i64,
+        user_id: Option<String>,
+        role: Option<String>,
+    }
+
+    impl Model for Membership {
+        const TABLE: &'static str = "membership";
+        const PK: &'static str = "tenant_id, user_id";
+        type Id = (i64, String);
+    }
+
+    impl UpdateFields for Membership {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "role" => self.role.clone().map(BindValue::from),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &["role"]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            field_name == "role"
+        }
+
+        fn insert_pk_value(&self) -> Option<(i64, String)> {
+            Some((self.tenant_id, self.user_id.clone()?))
+        }
+    }
+
+    fn membership(user_id: Option<&str>) -> Membership {
+        Membership {
+            tenant_id: 1,
+            user_id: user_id.map(str::to_string),
+            role: Some("admin".to_string()),
+        }
+    }
+
+    #[cfg(all(feature = "mysql", feature = "postgres"))]
+    #[test]
+    fn test_assigned_composite_pk_statements() {
+        // 已赋值的复合主键逐列写入，不使用 RETURNING
+        let statements = InsertBuilder::many(vec![membership(Some("a")), membership(Some("b"))])
+            .build_statements::<sqlx::Postgres>()
+            .unwrap();
+        assert_eq!(statements.len(), 1);
+        assert_eq!(
+            statements[0].sql,
+            r#"INSERT INTO "membership" ("tenant_id", "user_id", "role") VALUES ($1, $2, $3), ($4, $5, $6)"#
+        );
+        assert_eq!(statements[0].rows[1].0, Some((1, "b".to_string())));
+
+        // upsert 不更新主键列，也不使用 LAST_INSERT_ID()
+        let statements = InsertBuilder::new(membership(Some("a")))
+            .on_conflict(&["tenant_id", "user_id"])
+            .do_update(&[])
+            .build_statements::<sqlx::MySql>()
+            .unwrap();
+        assert_eq!(
+            statements[0].sql,
+            "INSERT INTO `membership` (`tenant_id`, `user_id`, `role`) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE `role` = VALUES(`role`)"
+        );
+
+        // 非整数主键必须在插入前赋值，且不能作为普通字段指定
+        assert!(InsertBuilder::new(membership(None))
+            .build_statements::<sqlx::Postgres>()
+            .is_err());
+        assert!(InsertBuilder::new(membership(Some("a")))
+            .field("user_id")
+            .build_statements::<sqlx::Postgres>()
+            .is_err());
     }
 }
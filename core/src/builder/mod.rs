@@ -9,5 +9,5 @@ pub mod update_builder;
 
 pub use delete_builder::DeleteBuilder;
 pub use insert_builder::InsertBuilder;
-pub use query_builder::{BindValue, QueryBuilder};
+pub use query_builder::{BindValue, JoinType, QueryBuilder};
 pub use update_builder::{UpdateBuilder, UpdateFields};
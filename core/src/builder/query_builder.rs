@@ -1,4 +1,6 @@
 use crate::db_pool::DbDriver;
+use crate::error::{Result, SqlxPlusError};
+use crate::soft_delete::TrashedScope;
 use crate::utils::escape_identifier;
 use std::fmt::Write;
 
@@ -18,6 +20,8 @@ pub enum BindValue {
     Float32(f32),
     Bool(bool),
     Bytes(Vec<u8>),
+    DateTime(chrono::DateTime<chrono::Utc>),
+    NaiveDateTime(chrono::NaiveDateTime),
     Null,
 }
 
@@ -37,6 +41,8 @@ impl BindValue {
             BindValue::Float32(f) => f.to_string(),
             BindValue::Bool(b) => b.to_string(),
             BindValue::Bytes(_) => "BLOB".to_string(), // 二进制数据不能直接转换为 SQL 字符串
+            BindValue::DateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
+            BindValue::NaiveDateTime(dt) => format!("'{}'", dt.format("%Y-%m-%d %H:%M:%S%.f")),
             BindValue::Null => "NULL".to_string(),
         }
     }
@@ -81,6 +87,8 @@ enum Operator {
     Between(usize, usize),
     /// 正则表达式匹配（MySQL: REGEXP, PostgreSQL: ~）
     RegExp,
+    /// 列与列比较: = other_column（用于 JOIN ON 条件，不占用绑定值）
+    EqColumn(String),
 }
 
 impl Operator {
@@ -175,10 +183,42 @@ impl Operator {
                 *bind_index += 1;
                 sql
             }
+            Operator::EqColumn(other) => format!("= {}", escape_identifier(driver, other)),
         }
     }
 }
 
+/// JOIN 类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    /// INNER JOIN
+    Inner,
+    /// LEFT JOIN
+    Left,
+    /// RIGHT JOIN（SQLite 3.39.0 及以上版本支持）
+    Right,
+}
+
+impl JoinType {
+    fn as_sql(&self) -> &'static str {
+        match self {
+            JoinType::Inner => "INNER JOIN",
+            JoinType::Left => "LEFT JOIN",
+            JoinType::Right => "RIGHT JOIN",
+        }
+    }
+}
+
+/// JOIN 子句：(类型, 表名, 别名, ON 条件)
+/// ON 条件的绑定值存储在嵌套 QueryBuilder 的 binds 中
+#[derive(Debug, Clone)]
+struct Join {
+    join_type: JoinType,
+    table: String,
+    alias: Option<String>,
+    on: QueryBuilder,
+}
+
 /// 条件项：可以是单个条件或条件组
 #[derive(Debug, Clone)]
 enum ConditionItem {
@@ -187,12 +227,35 @@ enum ConditionItem {
     Single(String, Operator, ConditionType),
     /// 条件组：(嵌套的 QueryBuilder, condition_type)
     Group(Box<QueryBuilder>, ConditionType),
+    /// 子查询条件：(field, 子查询类型, 子查询 QueryBuilder, condition_type)
+    /// EXISTS / NOT EXISTS 没有 field；子查询的绑定值同样合并到外层 binds 中
+    Subquery(Option<String>, SubqueryKind, Box<QueryBuilder>, ConditionType),
+    /// 行值比较：(fields, 比较符, condition_type)，如 `(a, b) > (?, ?)`，每个字段占用一个绑定值
+    Row(Vec<String>, &'static str, ConditionType),
+}
+
+/// 子查询条件类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SubqueryKind {
+    /// field IN (SELECT ...)
+    In,
+    /// field NOT IN (SELECT ...)
+    NotIn,
+    /// EXISTS (SELECT ...)
+    Exists,
+    /// NOT EXISTS (SELECT ...)
+    NotExists,
 }
 
 /// 安全的查询构建器，使用绑定参数而非字符串拼接
 #[derive(Debug, Clone)]
 pub struct QueryBuilder {
     base_sql: String,
+    // 主表别名（仅用于由 CRUD 生成的 FROM 子句）
+    alias: Option<String>,
+    // 显式选择的列（仅用于由 CRUD 生成的 SELECT 子句，空表示使用模型的列）
+    select: Vec<String>,
+    joins: Vec<Join>,
     conditions: Vec<ConditionItem>,
     order_by: Vec<(String, bool)>, // (field, ascending)
     binds: Vec<BindValue>,
@@ -203,12 +266,17 @@ pub struct QueryBuilder {
     group_by: Vec<String>,
     having_conditions: Vec<ConditionItem>,
     having_binds: Vec<BindValue>,
+    // 逻辑删除记录的查询范围（仅作用于 CRUD 方法自动添加的逻辑删除条件）
+    trashed: TrashedScope,
 }
 
 impl QueryBuilder {
     pub fn new(base_sql: impl Into<String>) -> Self {
         Self {
             base_sql: base_sql.into(),
+            alias: None,
+            select: Vec::new(),
+            joins: Vec::new(),
             conditions: Vec::new(),
             order_by: Vec::new(),
             binds: Vec::new(),
@@ -217,6 +285,7 @@ impl QueryBuilder {
             group_by: Vec::new(),
             having_conditions: Vec::new(),
             having_binds: Vec::new(),
+            trashed: TrashedScope::default(),
         }
     }
 
@@ -226,6 +295,159 @@ impl QueryBuilder {
         self
     }
 
+    /// 设置主表别名
+    ///
+    /// 仅在由 CRUD 方法生成 `SELECT ... FROM table` 时生效（生成 `FROM table AS alias`），
+    /// 自行编写 base_sql 时请直接在 SQL 中写明别名
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    /// 查询时包含已逻辑删除的记录
+    ///
+    /// 仅对定义了逻辑删除字段的模型生效，CRUD 方法不再自动添加"未删除"条件
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let all = User::find_all(pool, Some(QueryBuilder::new("").with_trashed())).await?;
+    /// ```
+    pub fn with_trashed(mut self) -> Self {
+        self.trashed = TrashedScope::With;
+        self
+    }
+
+    /// 只查询已逻辑删除的记录
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let deleted = User::count(pool, QueryBuilder::new("").only_trashed()).await?;
+    /// ```
+    pub fn only_trashed(mut self) -> Self {
+        self.trashed = TrashedScope::Only;
+        self
+    }
+
+    /// 当前的逻辑删除查询范围
+    pub fn trashed_scope(&self) -> TrashedScope {
+        self.trashed
+    }
+
+    /// 添加 JOIN 子句
+    ///
+    /// `table` 可以带别名：`"orders"`、`"orders o"` 或 `"orders AS o"`；
+    /// ON 条件通过闭包构建，列与列比较使用 `and_eq_column`，也可以使用绑定值的普通条件。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let builder = QueryBuilder::new("")
+    ///     .alias("u")
+    ///     .left_join("orders o", |on| on.and_eq_column("o.user_id", "u.id").and_eq("o.state", 1))
+    ///     .and_gt("o.amount", 100);
+    /// // ... FROM `user` AS `u` LEFT JOIN `orders` AS `o` ON `o`.`user_id` = `u`.`id` AND `o`.`state` = ? WHERE `o`.`amount` > ?
+    /// ```
+    pub fn join<F>(mut self, join_type: JoinType, table: &str, on: F) -> Self
+    where
+        F: FnOnce(QueryBuilder) -> QueryBuilder,
+    {
+        let mut parts = table.split_whitespace();
+        let table_name = parts.next().unwrap_or_default().to_string();
+        let alias = match (parts.next(), parts.next()) {
+            (Some(as_kw), Some(alias)) if as_kw.eq_ignore_ascii_case("as") => Some(alias.to_string()),
+            (Some(alias), None) => Some(alias.to_string()),
+            _ => None,
+        };
+        self.joins.push(Join {
+            join_type,
+            table: table_name,
+            alias,
+            on: on(QueryBuilder::new("")),
+        });
+        self
+    }
+
+    /// INNER JOIN，参见 [`join`](Self::join)
+    pub fn inner_join<F>(self, table: &str, on: F) -> Self
+    where
+        F: FnOnce(QueryBuilder) -> QueryBuilder,
+    {
+        self.join(JoinType::Inner, table, on)
+    }
+
+    /// LEFT JOIN，参见 [`join`](Self::join)
+    pub fn left_join<F>(self, table: &str, on: F) -> Self
+    where
+        F: FnOnce(QueryBuilder) -> QueryBuilder,
+    {
+        self.join(JoinType::Left, table, on)
+    }
+
+    /// RIGHT JOIN，参见 [`join`](Self::join)
+    pub fn right_join<F>(self, table: &str, on: F) -> Self
+    where
+        F: FnOnce(QueryBuilder) -> QueryBuilder,
+    {
+        self.join(JoinType::Right, table, on)
+    }
+
+    /// 是否包含 JOIN 子句
+    pub fn has_joins(&self) -> bool {
+        !self.joins.is_empty()
+    }
+
+    /// 指定要查询的列（可链式调用多次）
+    ///
+    /// 仅在由 CRUD 方法生成 SELECT 子句时生效，常与 `find_all_as` 配合查询到更小的 DTO。
+    /// 列名可以带表名限定（如 `o.amount`），未限定的列在有 JOIN 时会自动加上主表限定。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let builder = QueryBuilder::new("").select(&[User::ID, User::USERNAME]);
+    /// let briefs: Vec<UserBrief> = User::find_all_as::<UserBrief, _>(pool.mysql_pool(), Some(builder)).await?;
+    /// ```
+    pub fn select<C: AsRef<str>>(mut self, columns: &[C]) -> Self {
+        self.select
+            .extend(columns.iter().map(|c| c.as_ref().to_string()));
+        self
+    }
+
+    /// 生成主表查询的 `SELECT ... FROM table [AS alias]`（供 CRUD 方法使用）
+    ///
+    /// 选择的列依次取 `select` 指定的列、`default_columns`（通常为 `Model::COLUMNS`），都为空时使用 `*`；
+    /// 有 JOIN 时未限定的列会加上主表限定（`alias.col` / `alias.*`），避免同名列覆盖模型字段
+    pub fn select_from_sql(&self, driver: DbDriver, table: &str, default_columns: &[&str]) -> String {
+        let columns: Vec<&str> = if !self.select.is_empty() {
+            self.select.iter().map(|c| c.as_str()).collect()
+        } else if !default_columns.is_empty() {
+            default_columns.to_vec()
+        } else {
+            vec!["*"]
+        };
+        let escaped_columns: Vec<String> = columns
+            .iter()
+            .map(|c| escape_identifier(driver, &self.qualify_column(table, c)))
+            .collect();
+        let mut sql = format!("SELECT {}", escaped_columns.join(", "));
+        write!(sql, " FROM {}", escape_identifier(driver, table)).unwrap();
+        if let Some(alias) = &self.alias {
+            write!(sql, " AS {}", escape_identifier(driver, alias)).unwrap();
+        }
+        sql
+    }
+
+    /// 有 JOIN 时为主表列加上表名（或别名）限定，避免列名歧义
+    pub fn qualify_column(&self, table: &str, field: &str) -> String {
+        if self.joins.is_empty() || field.contains('.') {
+            field.to_string()
+        } else {
+            format!("{}.{}", self.alias.as_deref().unwrap_or(table), field)
+        }
+    }
+
     /// 设置 LIMIT（链式调用），仅作用于 into_sql 生成的 SQL
     pub fn limit(mut self, n: u64) -> Self {
         self.limit = Some(n);
@@ -239,16 +461,16 @@ impl QueryBuilder {
     }
 
     /// 添加 GROUP BY 字段（链式调用，可多次调用添加多个字段）
-    pub fn group_by(mut self, field: &str) -> Self {
-        self.group_by.push(field.to_string());
+    pub fn group_by(mut self, field: impl AsRef<str>) -> Self {
+        self.group_by.push(field.as_ref().to_string());
         self
     }
 
     /// HAVING 条件：等于
-    pub fn having_eq(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn having_eq(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.having_conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Eq,
             ConditionType::And,
         ));
@@ -257,10 +479,10 @@ impl QueryBuilder {
     }
 
     /// HAVING 条件：不等于
-    pub fn having_ne(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn having_ne(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.having_conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Ne,
             ConditionType::And,
         ));
@@ -269,10 +491,10 @@ impl QueryBuilder {
     }
 
     /// HAVING 条件：大于
-    pub fn having_gt(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn having_gt(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.having_conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Gt,
             ConditionType::And,
         ));
@@ -281,10 +503,10 @@ impl QueryBuilder {
     }
 
     /// HAVING 条件：大于等于
-    pub fn having_ge(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn having_ge(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.having_conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Ge,
             ConditionType::And,
         ));
@@ -293,10 +515,10 @@ impl QueryBuilder {
     }
 
     /// HAVING 条件：小于
-    pub fn having_lt(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn having_lt(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.having_conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Lt,
             ConditionType::And,
         ));
@@ -305,10 +527,10 @@ impl QueryBuilder {
     }
 
     /// HAVING 条件：小于等于
-    pub fn having_le(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn having_le(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.having_conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Le,
             ConditionType::And,
         ));
@@ -316,10 +538,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_eq(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn and_eq(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Eq,
             ConditionType::And,
         ));
@@ -327,10 +549,30 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_ne(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    /// 列与列相等（不绑定值），常用于 JOIN ON 条件：`o.user_id = u.id`
+    pub fn and_eq_column(mut self, field: impl AsRef<str>, other: impl AsRef<str>) -> Self {
+        self.conditions.push(ConditionItem::Single(
+            field.as_ref().to_string(),
+            Operator::EqColumn(other.as_ref().to_string()),
+            ConditionType::And,
+        ));
+        self
+    }
+
+    /// 列与列相等（OR 连接）
+    pub fn or_eq_column(mut self, field: impl AsRef<str>, other: impl AsRef<str>) -> Self {
+        self.conditions.push(ConditionItem::Single(
+            field.as_ref().to_string(),
+            Operator::EqColumn(other.as_ref().to_string()),
+            ConditionType::Or,
+        ));
+        self
+    }
+
+    pub fn and_ne(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Ne,
             ConditionType::And,
         ));
@@ -338,10 +580,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_gt(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn and_gt(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Gt,
             ConditionType::And,
         ));
@@ -349,10 +591,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_ge(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn and_ge(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Ge,
             ConditionType::And,
         ));
@@ -360,10 +602,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_lt(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn and_lt(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Lt,
             ConditionType::And,
         ));
@@ -371,10 +613,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_le(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn and_le(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Le,
             ConditionType::And,
         ));
@@ -383,10 +625,10 @@ impl QueryBuilder {
     }
 
     // ========== OR 条件方法 ==========
-    pub fn or_eq(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn or_eq(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Eq,
             ConditionType::Or,
         ));
@@ -394,10 +636,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn or_ne(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn or_ne(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Ne,
             ConditionType::Or,
         ));
@@ -405,10 +647,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn or_gt(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn or_gt(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Gt,
             ConditionType::Or,
         ));
@@ -416,10 +658,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn or_ge(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn or_ge(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Ge,
             ConditionType::Or,
         ));
@@ -427,10 +669,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn or_lt(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn or_lt(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Lt,
             ConditionType::Or,
         ));
@@ -438,10 +680,10 @@ impl QueryBuilder {
         self
     }
 
-    pub fn or_le(mut self, field: &str, value: impl Into<BindValue>) -> Self {
+    pub fn or_le(mut self, field: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
         let bind_value = value.into();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Le,
             ConditionType::Or,
         ));
@@ -449,11 +691,11 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_like(mut self, field: &str, value: impl Into<String>) -> Self {
+    pub fn and_like(mut self, field: impl AsRef<str>, value: impl Into<String>) -> Self {
         let s = value.into();
         let bind_value = BindValue::String(format!("%{}%", s));
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Like,
             ConditionType::And,
         ));
@@ -462,11 +704,11 @@ impl QueryBuilder {
     }
 
     /// LIKE 前缀匹配（value%）
-    pub fn and_like_prefix(mut self, field: &str, value: impl Into<String>) -> Self {
+    pub fn and_like_prefix(mut self, field: impl AsRef<str>, value: impl Into<String>) -> Self {
         let s = value.into();
         let bind_value = BindValue::String(format!("{}%", s));
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Like,
             ConditionType::And,
         ));
@@ -475,11 +717,11 @@ impl QueryBuilder {
     }
 
     /// LIKE 后缀匹配（%value）
-    pub fn and_like_suffix(mut self, field: &str, value: impl Into<String>) -> Self {
+    pub fn and_like_suffix(mut self, field: impl AsRef<str>, value: impl Into<String>) -> Self {
         let s = value.into();
         let bind_value = BindValue::String(format!("%{}", s));
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Like,
             ConditionType::And,
         ));
@@ -488,11 +730,11 @@ impl QueryBuilder {
     }
 
     /// LIKE 精确匹配（不添加 %）
-    pub fn and_like_exact(mut self, field: &str, value: impl Into<String>) -> Self {
+    pub fn and_like_exact(mut self, field: impl AsRef<str>, value: impl Into<String>) -> Self {
         let s = value.into();
         let bind_value = BindValue::String(s);
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Like,
             ConditionType::And,
         ));
@@ -501,11 +743,11 @@ impl QueryBuilder {
     }
 
     /// LIKE 自定义模式匹配
-    pub fn and_like_custom(mut self, field: &str, pattern: impl Into<String>) -> Self {
+    pub fn and_like_custom(mut self, field: impl AsRef<str>, pattern: impl Into<String>) -> Self {
         let s = pattern.into();
         let bind_value = BindValue::String(s);
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Like,
             ConditionType::And,
         ));
@@ -513,11 +755,11 @@ impl QueryBuilder {
         self
     }
 
-    pub fn or_like(mut self, field: &str, value: impl Into<String>) -> Self {
+    pub fn or_like(mut self, field: impl AsRef<str>, value: impl Into<String>) -> Self {
         let s = value.into();
         let bind_value = BindValue::String(format!("%{}%", s));
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Like,
             ConditionType::Or,
         ));
@@ -529,11 +771,11 @@ impl QueryBuilder {
     /// MySQL: field REGEXP pattern
     /// PostgreSQL: field ~ pattern
     /// SQLite: 不支持原生正则表达式（会抛出错误）
-    pub fn and_regexp(mut self, field: &str, pattern: impl Into<String>) -> Self {
+    pub fn and_regexp(mut self, field: impl AsRef<str>, pattern: impl Into<String>) -> Self {
         let s = pattern.into();
         let bind_value = BindValue::String(s);
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::RegExp,
             ConditionType::And,
         ));
@@ -545,11 +787,11 @@ impl QueryBuilder {
     /// MySQL: field REGEXP pattern
     /// PostgreSQL: field ~ pattern
     /// SQLite: 不支持原生正则表达式（会抛出错误）
-    pub fn or_regexp(mut self, field: &str, pattern: impl Into<String>) -> Self {
+    pub fn or_regexp(mut self, field: impl AsRef<str>, pattern: impl Into<String>) -> Self {
         let s = pattern.into();
         let bind_value = BindValue::String(s);
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::RegExp,
             ConditionType::Or,
         ));
@@ -557,7 +799,7 @@ impl QueryBuilder {
         self
     }
 
-    pub fn and_in(mut self, field: &str, values: Vec<impl Into<BindValue>>) -> Self {
+    pub fn and_in(mut self, field: impl AsRef<str>, values: Vec<impl Into<BindValue>>) -> Self {
         let bind_values: Vec<BindValue> = values.into_iter().map(|v| v.into()).collect();
         let start_index = self.binds.len();
         for bv in &bind_values {
@@ -566,7 +808,7 @@ impl QueryBuilder {
         let end_index = self.binds.len();
         // 存储 IN 子句的起始和结束索引
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::In(start_index, end_index),
             ConditionType::And,
         ));
@@ -574,7 +816,7 @@ impl QueryBuilder {
     }
 
     /// NOT IN 查询
-    pub fn and_not_in(mut self, field: &str, values: Vec<impl Into<BindValue>>) -> Self {
+    pub fn and_not_in(mut self, field: impl AsRef<str>, values: Vec<impl Into<BindValue>>) -> Self {
         let bind_values: Vec<BindValue> = values.into_iter().map(|v| v.into()).collect();
         let start_index = self.binds.len();
         for bv in &bind_values {
@@ -583,14 +825,14 @@ impl QueryBuilder {
         let end_index = self.binds.len();
         // 存储 NOT IN 子句的起始和结束索引
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::NotIn(start_index, end_index),
             ConditionType::And,
         ));
         self
     }
 
-    pub fn or_in(mut self, field: &str, values: Vec<impl Into<BindValue>>) -> Self {
+    pub fn or_in(mut self, field: impl AsRef<str>, values: Vec<impl Into<BindValue>>) -> Self {
         let bind_values: Vec<BindValue> = values.into_iter().map(|v| v.into()).collect();
         let start_index = self.binds.len();
         for bv in &bind_values {
@@ -598,7 +840,7 @@ impl QueryBuilder {
         }
         let end_index = self.binds.len();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::In(start_index, end_index),
             ConditionType::Or,
         ));
@@ -606,9 +848,9 @@ impl QueryBuilder {
     }
 
     /// IS NULL 查询
-    pub fn and_is_null(mut self, field: &str) -> Self {
+    pub fn and_is_null(mut self, field: impl AsRef<str>) -> Self {
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::IsNull,
             ConditionType::And,
         ));
@@ -616,27 +858,27 @@ impl QueryBuilder {
     }
 
     /// IS NOT NULL 查询
-    pub fn and_is_not_null(mut self, field: &str) -> Self {
+    pub fn and_is_not_null(mut self, field: impl AsRef<str>) -> Self {
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::IsNotNull,
             ConditionType::And,
         ));
         self
     }
 
-    pub fn or_is_null(mut self, field: &str) -> Self {
+    pub fn or_is_null(mut self, field: impl AsRef<str>) -> Self {
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::IsNull,
             ConditionType::Or,
         ));
         self
     }
 
-    pub fn or_is_not_null(mut self, field: &str) -> Self {
+    pub fn or_is_not_null(mut self, field: impl AsRef<str>) -> Self {
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::IsNotNull,
             ConditionType::Or,
         ));
@@ -646,7 +888,7 @@ impl QueryBuilder {
     /// BETWEEN 范围查询
     pub fn and_between(
         mut self,
-        field: &str,
+        field: impl AsRef<str>,
         min: impl Into<BindValue>,
         max: impl Into<BindValue>,
     ) -> Self {
@@ -657,7 +899,7 @@ impl QueryBuilder {
         self.binds.push(max_value.clone());
         let end_index = self.binds.len();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Between(start_index, end_index),
             ConditionType::And,
         ));
@@ -666,7 +908,7 @@ impl QueryBuilder {
 
     pub fn or_between(
         mut self,
-        field: &str,
+        field: impl AsRef<str>,
         min: impl Into<BindValue>,
         max: impl Into<BindValue>,
     ) -> Self {
@@ -677,13 +919,142 @@ impl QueryBuilder {
         self.binds.push(max_value.clone());
         let end_index = self.binds.len();
         self.conditions.push(ConditionItem::Single(
-            field.to_string(),
+            field.as_ref().to_string(),
             Operator::Between(start_index, end_index),
             ConditionType::Or,
         ));
         self
     }
 
+    /// 添加子查询条件，并将子查询的全部绑定值合并到当前 builder
+    fn push_subquery(
+        mut self,
+        field: Option<String>,
+        kind: SubqueryKind,
+        subquery: QueryBuilder,
+        condition_type: ConditionType,
+    ) -> Self {
+        self.binds.extend(subquery.binds());
+        self.conditions.push(ConditionItem::Subquery(
+            field,
+            kind,
+            Box::new(subquery),
+            condition_type,
+        ));
+        self
+    }
+
+    /// IN 子查询：`field IN (SELECT ...)`
+    ///
+    /// 子查询使用完整的 base_sql，其中的绑定值会按顺序合并，PostgreSQL 的 `$n` 会自动续接编号
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let orders = QueryBuilder::new("SELECT user_id FROM orders").and_gt("amount", 100);
+    /// let builder = QueryBuilder::new("SELECT * FROM users")
+    ///     .and_eq("state", 1)
+    ///     .and_in_subquery("id", orders);
+    /// // SELECT * FROM users WHERE "state" = $1 AND "id" IN (SELECT user_id FROM orders WHERE "amount" > $2)
+    /// ```
+    pub fn and_in_subquery(self, field: impl AsRef<str>, subquery: QueryBuilder) -> Self {
+        self.push_subquery(
+            Some(field.as_ref().to_string()),
+            SubqueryKind::In,
+            subquery,
+            ConditionType::And,
+        )
+    }
+
+    /// NOT IN 子查询：`field NOT IN (SELECT ...)`
+    pub fn and_not_in_subquery(self, field: impl AsRef<str>, subquery: QueryBuilder) -> Self {
+        self.push_subquery(
+            Some(field.as_ref().to_string()),
+            SubqueryKind::NotIn,
+            subquery,
+            ConditionType::And,
+        )
+    }
+
+    /// IN 子查询（OR 连接）
+    pub fn or_in_subquery(self, field: impl AsRef<str>, subquery: QueryBuilder) -> Self {
+        self.push_subquery(
+            Some(field.as_ref().to_string()),
+            SubqueryKind::In,
+            subquery,
+            ConditionType::Or,
+        )
+    }
+
+    /// EXISTS 子查询：`EXISTS (SELECT ...)`
+    ///
+    /// 关联外层表的条件可以直接写在子查询的 base_sql 中，或使用 `and_eq_column`
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let orders = QueryBuilder::new("SELECT 1 FROM orders o")
+    ///     .and_eq_column("o.user_id", "u.id")
+    ///     .and_eq("o.state", 1);
+    /// let builder = QueryBuilder::new("SELECT * FROM users u").and_exists(orders);
+    /// ```
+    pub fn and_exists(self, subquery: QueryBuilder) -> Self {
+        self.push_subquery(None, SubqueryKind::Exists, subquery, ConditionType::And)
+    }
+
+    /// NOT EXISTS 子查询：`NOT EXISTS (SELECT ...)`
+    pub fn and_not_exists(self, subquery: QueryBuilder) -> Self {
+        self.push_subquery(None, SubqueryKind::NotExists, subquery, ConditionType::And)
+    }
+
+    /// EXISTS 子查询（OR 连接）
+    pub fn or_exists(self, subquery: QueryBuilder) -> Self {
+        self.push_subquery(None, SubqueryKind::Exists, subquery, ConditionType::Or)
+    }
+
+    /// 添加行值比较条件，字段数与值数不一致时返回错误
+    fn push_row(
+        mut self,
+        fields: &[impl AsRef<str>],
+        op: &'static str,
+        values: Vec<impl Into<BindValue>>,
+    ) -> Result<Self> {
+        if fields.is_empty() || fields.len() != values.len() {
+            return Err(SqlxPlusError::InvalidField(format!(
+                "row comparison requires one value per field: {} fields, {} values",
+                fields.len(),
+                values.len()
+            )));
+        }
+        self.binds.extend(values.into_iter().map(Into::into));
+        self.conditions.push(ConditionItem::Row(
+            fields.iter().map(|f| f.as_ref().to_string()).collect(),
+            op,
+            ConditionType::And,
+        ));
+        Ok(self)
+    }
+
+    /// 行值比较（大于）：`(a, b) > (?, ?)`，按字段顺序逐个比较
+    ///
+    /// 字段为空或字段数与值数不一致时返回 `SqlxPlusError::InvalidField`。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let builder = QueryBuilder::new("SELECT * FROM users")
+    ///     .and_row_gt(&["created_at", "id"], vec![BindValue::from(created_at), BindValue::from(42i64)])?;
+    /// // SELECT * FROM users WHERE ("created_at", "id") > ($1, $2)
+    /// ```
+    pub fn and_row_gt(self, fields: &[impl AsRef<str>], values: Vec<impl Into<BindValue>>) -> Result<Self> {
+        self.push_row(fields, ">", values)
+    }
+
+    /// 行值比较（小于）：`(a, b) < (?, ?)`，校验规则同 [`QueryBuilder::and_row_gt`]
+    pub fn and_row_lt(self, fields: &[impl AsRef<str>], values: Vec<impl Into<BindValue>>) -> Result<Self> {
+        self.push_row(fields, "<", values)
+    }
+
     /// AND 条件分组：创建一个用 AND 连接的条件组
     /// 示例：`builder.and_group(|b| b.and_eq("a", 1).and_eq("b", 2))`
     /// 生成：`(a = ? AND b = ?)`
@@ -722,8 +1093,19 @@ impl QueryBuilder {
         self
     }
 
-    pub fn order_by(mut self, field: &str, ascending: bool) -> Self {
-        self.order_by.push((field.to_string(), ascending));
+    pub fn order_by(mut self, field: impl AsRef<str>, ascending: bool) -> Self {
+        self.order_by.push((field.as_ref().to_string(), ascending));
+        self
+    }
+
+    /// 已添加的排序字段：(field, ascending)
+    pub fn order_by_fields(&self) -> &[(String, bool)] {
+        &self.order_by
+    }
+
+    /// 替换全部排序字段
+    pub(crate) fn with_order_by(mut self, order_by: Vec<(String, bool)>) -> Self {
+        self.order_by = order_by;
         self
     }
 
@@ -743,6 +1125,8 @@ impl QueryBuilder {
             let condition_type = match item {
                 ConditionItem::Single(_, _, ct) => *ct,
                 ConditionItem::Group(_, ct) => *ct,
+                ConditionItem::Subquery(_, _, _, ct) => *ct,
+                ConditionItem::Row(_, _, ct) => *ct,
             };
 
             // 处理条件连接符（AND 或 OR）
@@ -774,13 +1158,45 @@ impl QueryBuilder {
                 }
                 ConditionItem::Group(group_builder, _) => {
                     // 递归处理分组条件
-                    sql.push_str("(");
+                    sql.push('(');
                     let (group_sql, new_bind_index) =
                         group_builder.build_conditions_sql(driver, bind_index);
                     sql.push_str(&group_sql);
-                    sql.push_str(")");
+                    sql.push(')');
                     bind_index = new_bind_index;
                 }
+                ConditionItem::Subquery(field, kind, sub_builder, _) => {
+                    // 子查询的占位符从当前绑定索引继续编号（PostgreSQL 的 $n）
+                    let (sub_sql, new_bind_index) = sub_builder.build_sql(driver, bind_index);
+                    let field = field
+                        .as_deref()
+                        .map(|f| escape_identifier(driver, f))
+                        .unwrap_or_default();
+                    match kind {
+                        SubqueryKind::In => write!(sql, "{} IN ({})", field, sub_sql),
+                        SubqueryKind::NotIn => write!(sql, "{} NOT IN ({})", field, sub_sql),
+                        SubqueryKind::Exists => write!(sql, "EXISTS ({})", sub_sql),
+                        SubqueryKind::NotExists => write!(sql, "NOT EXISTS ({})", sub_sql),
+                    }
+                    .unwrap();
+                    bind_index = new_bind_index;
+                }
+                ConditionItem::Row(fields, op, _) => {
+                    let columns: Vec<String> =
+                        fields.iter().map(|f| escape_identifier(driver, f)).collect();
+                    let placeholders: Vec<String> = (0..fields.len())
+                        .map(|i| driver.placeholder(bind_index + i))
+                        .collect();
+                    bind_index += fields.len();
+                    write!(
+                        sql,
+                        "({}) {} ({})",
+                        columns.join(", "),
+                        op,
+                        placeholders.join(", ")
+                    )
+                    .unwrap();
+                }
             }
         }
 
@@ -803,9 +1219,44 @@ impl QueryBuilder {
         temp_builder.build_conditions_sql(driver, start_bind_index)
     }
 
+    /// 生成 JOIN 子句的 SQL（以空格开头），返回 (sql, next_bind_index)
+    fn build_joins_sql(&self, driver: DbDriver, start_bind_index: usize) -> (String, usize) {
+        let mut sql = String::new();
+        let mut bind_index = start_bind_index;
+        for join in &self.joins {
+            write!(
+                sql,
+                " {} {}",
+                join.join_type.as_sql(),
+                escape_identifier(driver, &join.table)
+            )
+            .unwrap();
+            if let Some(alias) = &join.alias {
+                write!(sql, " AS {}", escape_identifier(driver, alias)).unwrap();
+            }
+            if join.on.has_conditions() {
+                let (on_sql, next_index) = join.on.build_conditions_sql(driver, bind_index);
+                write!(sql, " ON {}", on_sql).unwrap();
+                bind_index = next_index;
+            }
+        }
+        (sql, bind_index)
+    }
+
     pub fn into_sql(&self, driver: DbDriver) -> String {
+        let (sql, _) = self.build_sql(driver, 0);
+        driver.convert_placeholders(&sql)
+    }
+
+    /// 生成完整 SQL（不转换 base_sql 中的 `?`），占位符从 `start_bind_index` 开始编号
+    /// 返回 (sql, next_bind_index)，供子查询嵌套使用
+    fn build_sql(&self, driver: DbDriver, start_bind_index: usize) -> (String, usize) {
         let mut sql = self.base_sql.clone();
 
+        // 添加 JOIN 子句（ON 条件的绑定值排在最前面）
+        let (joins_sql, mut bind_index) = self.build_joins_sql(driver, start_bind_index);
+        sql.push_str(&joins_sql);
+
         // 添加 WHERE 条件
         if !self.conditions.is_empty() {
             // 检查 base_sql 是否已经包含 WHERE
@@ -818,8 +1269,9 @@ impl QueryBuilder {
                 sql.push_str(" AND ");
             }
 
-            let (conditions_sql, _) = self.build_conditions_sql(driver, 0);
+            let (conditions_sql, next_index) = self.build_conditions_sql(driver, bind_index);
             sql.push_str(&conditions_sql);
+            bind_index = next_index;
         }
 
         // 添加 GROUP BY
@@ -838,9 +1290,9 @@ impl QueryBuilder {
         if !self.having_conditions.is_empty() {
             sql.push_str(" HAVING ");
             // 构建 HAVING 条件 SQL，需要从 WHERE 条件的绑定索引之后开始
-            let where_bind_count = self.binds.len();
-            let (having_sql, _) = self.build_having_sql(driver, where_bind_count);
+            let (having_sql, next_index) = self.build_having_sql(driver, bind_index);
             sql.push_str(&having_sql);
+            bind_index = next_index;
         }
 
         // 添加 ORDER BY
@@ -866,22 +1318,33 @@ impl QueryBuilder {
             }
         }
 
-        driver.convert_placeholders(&sql)
+        (sql, bind_index)
     }
 
+    /// 生成 COUNT 查询
+    ///
+    /// 将 base_sql 顶层的 `SELECT ... FROM` 替换为 `SELECT COUNT(*) FROM`，保留 JOIN 和 WHERE 条件；
+    /// 包含 GROUP BY 时统计分组数量：`SELECT COUNT(*) FROM (SELECT 1 FROM ... GROUP BY ...) AS count_query`。
+    /// 绑定值与 [`binds`](Self::binds) 一致。
     pub fn into_count_sql(&self, driver: DbDriver) -> String {
-        // 将 SELECT ... FROM 转换为 SELECT COUNT(*) FROM
-        let base = self.base_sql.clone();
-        let count_sql = if let Some(from_pos) = base.to_uppercase().find(" FROM ") {
-            format!("SELECT COUNT(*){}", &base[from_pos..])
-        } else {
-            format!("SELECT COUNT(*) FROM ({}) AS count_query", base)
-        };
+        let mut builder = self.clone();
+        builder.order_by.clear();
+        builder.limit = None;
+        builder.offset = None;
 
-        let mut builder = QueryBuilder::new(count_sql);
-        builder.conditions = self.conditions.clone();
-        builder.binds = self.binds.clone();
-        builder.into_sql(driver)
+        let from_pos = find_top_level_from(&self.base_sql);
+        match from_pos {
+            Some(from_pos) if self.group_by.is_empty() => {
+                // 将 SELECT ... FROM 转换为 SELECT COUNT(*) FROM
+                builder.base_sql = format!("SELECT COUNT(*){}", &self.base_sql[from_pos..]);
+                builder.into_sql(driver)
+            }
+            Some(from_pos) => {
+                builder.base_sql = format!("SELECT 1{}", &self.base_sql[from_pos..]);
+                format!("SELECT COUNT(*) FROM ({}) AS count_query", builder.into_sql(driver))
+            }
+            None => format!("SELECT COUNT(*) FROM ({}) AS count_query", builder.into_sql(driver)),
+        }
     }
 
     pub fn into_paginated_sql(&self, driver: DbDriver, limit: u32, offset: u32) -> String {
@@ -903,10 +1366,15 @@ impl QueryBuilder {
         sql
     }
 
-    /// 返回所有绑定值（WHERE 条件 + HAVING 条件）
+    /// 返回所有绑定值（JOIN ON 条件 + WHERE 条件 + HAVING 条件）
     /// 获取绑定值列表
     pub fn binds(&self) -> Vec<BindValue> {
-        let mut all_binds = self.binds.clone();
+        let mut all_binds: Vec<BindValue> = self
+            .joins
+            .iter()
+            .flat_map(|join| join.on.binds.iter().cloned())
+            .collect();
+        all_binds.extend_from_slice(&self.binds);
         all_binds.extend_from_slice(&self.having_binds);
         all_binds
     }
@@ -926,6 +1394,63 @@ impl QueryBuilder {
     pub fn has_conditions(&self) -> bool {
         !self.conditions.is_empty()
     }
+
+    /// 将已有的 WHERE 条件包装为一个分组，使之后追加的 AND 条件作用于整体
+    ///
+    /// 用户条件可能包含顶层 OR（如 `a = ? OR a = ?`），直接追加 `AND x` 会因优先级只约束最后一个分支；
+    /// 没有顶层 OR 时原样返回
+    pub(crate) fn group_conditions(mut self) -> Self {
+        let has_top_level_or = self.conditions.iter().skip(1).any(|item| {
+            matches!(
+                item,
+                ConditionItem::Single(_, _, ConditionType::Or)
+                    | ConditionItem::Group(_, ConditionType::Or)
+                    | ConditionItem::Subquery(_, _, _, ConditionType::Or)
+                    | ConditionItem::Row(_, _, ConditionType::Or)
+            )
+        });
+        if !has_top_level_or {
+            return self;
+        }
+        let mut group = QueryBuilder::new("");
+        group.conditions = std::mem::take(&mut self.conditions);
+        group.binds = self.binds.clone();
+        self.conditions
+            .push(ConditionItem::Group(Box::new(group), ConditionType::And));
+        self
+    }
+}
+
+/// 查找 SQL 中顶层（不在括号或引号内）的 ` FROM ` 位置
+fn find_top_level_from(sql: &str) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let mut depth = 0i32;
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match quote {
+            Some(q) => {
+                if b == q {
+                    quote = None;
+                }
+            }
+            None => match b {
+                b'\'' | b'"' | b'`' => quote = Some(b),
+                b'(' => depth += 1,
+                b')' => depth -= 1,
+                _ if depth == 0
+                    && bytes.len() - i >= 6
+                    && bytes[i..i + 6].eq_ignore_ascii_case(b" FROM ") =>
+                {
+                    return Some(i);
+                }
+                _ => {}
+            },
+        }
+        i += 1;
+    }
+    None
 }
 
 impl From<String> for BindValue {
@@ -1018,12 +1543,24 @@ impl From<&[u8]> for BindValue {
     }
 }
 
+impl From<chrono::DateTime<chrono::Utc>> for BindValue {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
+        BindValue::DateTime(dt)
+    }
+}
+
+impl From<chrono::NaiveDateTime> for BindValue {
+    fn from(dt: chrono::NaiveDateTime) -> Self {
+        BindValue::NaiveDateTime(dt)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn normalize(sql: &str) -> String {
-        sql.replace('`', "").replace('\"', "")
+        sql.replace(['`', '\"'], "")
     }
 
     fn mysql_driver() -> DbDriver {
@@ -1349,6 +1886,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_row_comparison() {
+        let builder = QueryBuilder::new("SELECT * FROM users")
+            .and_eq("is_del", 0)
+            .and_row_lt(&["created_at", "id"], vec![BindValue::from("2024-01-01"), BindValue::from(42i64)])
+            .unwrap();
+        assert_eq!(
+            builder.into_sql(DbDriver::Postgres),
+            r#"SELECT * FROM users WHERE "is_del" = $1 AND ("created_at", "id") < ($2, $3)"#
+        );
+        assert_eq!(builder.binds().len(), 3);
+    }
+
+    #[test]
+    fn test_row_comparison_length_mismatch() {
+        let result = QueryBuilder::new("SELECT * FROM users")
+            .and_row_gt(&["created_at", "id"], vec![BindValue::from(42i64)]);
+        assert!(matches!(result, Err(SqlxPlusError::InvalidField(_))));
+
+        let result = QueryBuilder::new("SELECT * FROM users").and_row_lt(&[] as &[&str], Vec::<BindValue>::new());
+        assert!(matches!(result, Err(SqlxPlusError::InvalidField(_))));
+    }
+
     // ========== COUNT SQL 测试 ==========
     #[test]
     fn test_into_count_sql() {
@@ -1569,6 +2129,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_bind_value_from_f64() {
         let bv: BindValue = 3.14f64.into();
         assert!(matches!(bv, BindValue::Float64(_)));
@@ -1816,4 +2377,173 @@ mod tests {
         assert!(sql.contains("OR `username` REGEXP ?"));
         assert_eq!(builder.binds().len(), 2);
     }
+
+    // ========== 类型化列测试 ==========
+    #[test]
+    fn test_typed_columns() {
+        struct User;
+        const USERNAME: crate::Column<User> = crate::Column::new("user_name");
+        const ID: crate::Column<User> = crate::Column::new("id");
+
+        let builder = QueryBuilder::new("SELECT * FROM users")
+            .and_eq(USERNAME, "alice")
+            .and_in(ID, vec![1, 2])
+            .order_by(ID, false);
+        let sql = builder.into_sql(postgres_driver());
+        assert_eq!(
+            sql,
+            "SELECT * FROM users WHERE \"user_name\" = $1 AND \"id\" IN ($2, $3) ORDER BY \"id\" DESC"
+        );
+        // 字符串列名仍然可用
+        let builder = QueryBuilder::new("SELECT * FROM users").and_eq(String::from("id"), 1);
+        assert_eq!(normalize(&builder.into_sql(sqlite_driver())), "SELECT * FROM users WHERE id = ?");
+    }
+
+    // ========== JOIN 测试 ==========
+    #[test]
+    fn test_join_with_bound_on_condition() {
+        let builder = QueryBuilder::new("SELECT u.* FROM users AS u")
+            .inner_join("orders o", |on| on.and_eq_column("o.user_id", "u.id").and_eq("o.state", 1))
+            .left_join("address AS a", |on| on.and_eq_column("a.user_id", "u.id"))
+            .and_gt("o.amount", 100)
+            .order_by("u.id", false);
+        let sql = builder.into_sql(postgres_driver());
+        assert_eq!(
+            sql,
+            "SELECT u.* FROM users AS u INNER JOIN \"orders\" AS \"o\" ON \"o\".\"user_id\" = \"u\".\"id\" AND \"o\".\"state\" = $1 LEFT JOIN \"address\" AS \"a\" ON \"a\".\"user_id\" = \"u\".\"id\" WHERE \"o\".\"amount\" > $2 ORDER BY \"u\".\"id\" DESC"
+        );
+        // ON 条件的绑定值在 WHERE 之前
+        assert_eq!(builder.binds(), vec![BindValue::Int32(1), BindValue::Int32(100)]);
+    }
+
+    #[test]
+    fn test_join_select_from_and_count() {
+        let builder = QueryBuilder::new("")
+            .alias("u")
+            .right_join("orders", |on| on.and_eq_column("orders.user_id", "u.id"));
+        let base_sql = builder.select_from_sql(mysql_driver(), "user", &[]);
+        assert_eq!(base_sql, "SELECT `u`.* FROM `user` AS `u`");
+        assert_eq!(builder.qualify_column("user", "is_del"), "u.is_del");
+
+        let builder = builder.with_base_sql(base_sql).and_eq("u.is_del", 0);
+        assert_eq!(
+            builder.into_count_sql(mysql_driver()),
+            "SELECT COUNT(*) FROM `user` AS `u` RIGHT JOIN `orders` ON `orders`.`user_id` = `u`.`id` WHERE `u`.`is_del` = ?"
+        );
+
+        // 无 JOIN 时不加限定
+        let plain = QueryBuilder::new("");
+        assert_eq!(plain.select_from_sql(sqlite_driver(), "user", &[]), "SELECT * FROM \"user\"");
+        assert_eq!(plain.qualify_column("user", "is_del"), "is_del");
+    }
+
+    #[test]
+    fn test_count_sql_with_subquery_and_group_by() {
+        // 子查询中的 FROM 不应被当作主查询的 FROM
+        let builder = QueryBuilder::new(
+            "SELECT u.id, (SELECT COUNT(*) FROM orders o WHERE o.user_id = u.id) AS cnt FROM users u",
+        )
+        .and_eq("u.state", 1);
+        assert_eq!(
+            builder.into_count_sql(mysql_driver()),
+            "SELECT COUNT(*) FROM users u WHERE `u`.`state` = ?"
+        );
+
+        let builder = QueryBuilder::new("SELECT user_id, COUNT(*) FROM orders")
+            .and_eq("state", 1)
+            .group_by("user_id")
+            .having_gt("COUNT(*)", 2)
+            .order_by("user_id", true);
+        assert_eq!(
+            normalize(&builder.into_count_sql(postgres_driver())),
+            "SELECT COUNT(*) FROM (SELECT 1 FROM orders WHERE state = $1 GROUP BY user_id HAVING COUNT(*) > $2) AS count_query"
+        );
+    }
+
+    // ========== SELECT 列测试 ==========
+    #[test]
+    fn test_select_columns() {
+        let columns = ["id", "user_name"];
+        let builder = QueryBuilder::new("");
+        assert_eq!(
+            builder.select_from_sql(mysql_driver(), "user", &columns),
+            "SELECT `id`, `user_name` FROM `user`"
+        );
+
+        // select 优先于模型列；有 JOIN 时未限定的列加上主表限定
+        let builder = QueryBuilder::new("")
+            .alias("u")
+            .select(&["id", "o.amount"])
+            .inner_join("orders o", |on| on.and_eq_column("o.user_id", "u.id"));
+        assert_eq!(
+            builder.select_from_sql(postgres_driver(), "user", &columns),
+            "SELECT \"u\".\"id\", \"o\".\"amount\" FROM \"user\" AS \"u\""
+        );
+    }
+
+    // ========== 子查询条件测试 ==========
+    #[test]
+    fn test_in_subquery_renumbers_postgres_placeholders() {
+        let orders = QueryBuilder::new("SELECT user_id FROM orders")
+            .and_gt("amount", 100)
+            .and_eq("state", 2);
+        let builder = QueryBuilder::new("SELECT * FROM users")
+            .and_eq("state", 1)
+            .and_in_subquery("id", orders)
+            .and_like("name", "al");
+
+        assert_eq!(
+            builder.into_sql(postgres_driver()),
+            "SELECT * FROM users WHERE \"state\" = $1 AND \"id\" IN (SELECT user_id FROM orders WHERE \"amount\" > $2 AND \"state\" = $3) AND \"name\" LIKE $4"
+        );
+        assert_eq!(
+            builder.into_sql(mysql_driver()),
+            "SELECT * FROM users WHERE `state` = ? AND `id` IN (SELECT user_id FROM orders WHERE `amount` > ? AND `state` = ?) AND `name` LIKE ?"
+        );
+
+        let binds = builder.binds();
+        assert_eq!(binds.len(), 4);
+        assert!(matches!(binds[0], BindValue::Int32(1)));
+        assert!(matches!(binds[1], BindValue::Int32(100)));
+        assert!(matches!(binds[2], BindValue::Int32(2)));
+        assert!(matches!(binds[3], BindValue::String(ref v) if v == "%al%"));
+    }
+
+    #[test]
+    fn test_exists_and_not_exists() {
+        let orders = QueryBuilder::new("SELECT 1 FROM orders o")
+            .and_eq_column("o.user_id", "u.id")
+            .and_eq("o.state", 1);
+        let refunds = QueryBuilder::new("SELECT 1 FROM refunds r")
+            .and_eq_column("r.user_id", "u.id")
+            .and_gt("r.amount", 0);
+        let builder = QueryBuilder::new("SELECT * FROM users u")
+            .and_exists(orders)
+            .and_not_exists(refunds)
+            .or_in_subquery("u.id", QueryBuilder::new("SELECT user_id FROM vip").limit(10));
+
+        assert_eq!(
+            builder.into_sql(postgres_driver()),
+            "SELECT * FROM users u WHERE EXISTS (SELECT 1 FROM orders o WHERE \"o\".\"user_id\" = \"u\".\"id\" AND \"o\".\"state\" = $1) AND NOT EXISTS (SELECT 1 FROM refunds r WHERE \"r\".\"user_id\" = \"u\".\"id\" AND \"r\".\"amount\" > $2) OR \"u\".\"id\" IN (SELECT user_id FROM vip LIMIT 10)"
+        );
+        assert_eq!(builder.binds().len(), 2);
+    }
+
+    #[test]
+    fn test_subquery_inside_group_and_after_join() {
+        let sub = QueryBuilder::new("SELECT user_id FROM orders")
+            .and_not_in("state", vec![3, 4]);
+        let builder = QueryBuilder::new("SELECT * FROM users u")
+            .left_join("profiles p", |on| on.and_eq_column("p.user_id", "u.id").and_eq("p.kind", 1))
+            .and_group(|g| g.and_not_in_subquery("u.id", sub).or_eq("u.state", 9))
+            .having_gt("COUNT(*)", 1);
+
+        let sql = builder.into_sql(postgres_driver());
+        assert!(sql.contains("\"p\".\"kind\" = $1"));
+        assert!(sql.contains(
+            "(\"u\".\"id\" NOT IN (SELECT user_id FROM orders WHERE \"state\" NOT IN ($2, $3)) OR \"u\".\"state\" = $4)"
+        ));
+        assert!(sql.ends_with("> $5"));
+        assert_eq!(builder.binds().len(), 5);
+    }
 }
@@ -3,15 +3,43 @@
 use crate::database_info::DatabaseInfo;
 use crate::error::{Result, SqlxPlusError};
 use super::query_builder::{BindValue, QueryBuilder};
+use crate::soft_delete::TrashedScope;
 use crate::traits::Model;
 
 /// Update Builder - 支持指定字段和 WHERE 条件
 ///
 /// 注意：此 Builder 需要 Model 实现 `UpdateFields` trait（由 CRUD derive 宏自动生成）
+///
+/// 支持两种模式：
+/// - 模型模式：`UpdateBuilder::new(model)`，SET 的值取自 model 当前字段
+/// - 批量模式：`UpdateBuilder::<M>::bulk()`，无需加载记录，通过 `set` / `set_expr` / `set_null` 指定 SET 子句
+///
+/// 模型定义了逻辑删除字段时，两种模式都默认排除已删除的记录
 pub struct UpdateBuilder<M: Model> {
-    model: M,
+    model: Option<M>,                    // 模型模式下的 model（批量模式为 None）
     fields: Vec<String>,                 // 要更新的字段列表（空表示更新所有字段）
+    sets: Vec<SetItem>,                  // 显式指定的 SET 子句
     where_builder: Option<QueryBuilder>, // WHERE 条件构建器
+    allow_update_all: bool,              // 没有 WHERE 条件时是否允许更新所有记录
+}
+
+/// 显式指定的 SET 子句
+#[derive(Debug, Clone)]
+enum SetItem {
+    /// col = ?
+    Value(String, BindValue),
+    /// col = <表达式>，表达式中的 `?` 依次绑定给定的值
+    Expr(String, String, Vec<BindValue>),
+    /// col = NULL
+    Null(String),
+}
+
+impl SetItem {
+    fn column(&self) -> &str {
+        match self {
+            SetItem::Value(col, _) | SetItem::Expr(col, _, _) | SetItem::Null(col) => col,
+        }
+    }
 }
 
 /// Trait 用于从 Model 中提取字段值
@@ -26,15 +54,50 @@ pub trait UpdateFields: Model {
 
     /// 检查字段是否存在
     fn has_field(field_name: &str) -> bool;
+
+    /// 插入时写入的主键值（复合主键为元组）
+    ///
+    /// 返回 `None` 表示主键由数据库生成（自增整数主键），默认实现始终返回 `None`
+    fn insert_pk_value(&self) -> Option<Self::Id> {
+        None
+    }
 }
 
 impl<M: Model> UpdateBuilder<M> {
     /// 创建 UpdateBuilder
     pub fn new(model: M) -> Self {
         Self {
-            model,
+            model: Some(model),
             fields: Vec::new(),
+            sets: Vec::new(),
             where_builder: None,
+            allow_update_all: false,
+        }
+    }
+
+    /// 创建不依赖 model 实例的批量更新 UpdateBuilder
+    ///
+    /// 批量模式必须通过 `condition` 指定 WHERE 条件（或显式调用 `allow_update_all`）。
+    /// 如果模型有更新时间字段且未显式设置，会自动设置为当前毫秒时间戳。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let affected = UpdateBuilder::<User>::bulk()
+    ///     .set("state", 2)
+    ///     .set_expr("num", "num + ?", 1)
+    ///     .set_null("last_login_time")
+    ///     .condition(|q| q.and_in("id", ids))
+    ///     .execute(pool.mysql_pool())
+    ///     .await?;
+    /// ```
+    pub fn bulk() -> Self {
+        Self {
+            model: None,
+            fields: Vec::new(),
+            sets: Vec::new(),
+            where_builder: None,
+            allow_update_all: false,
         }
     }
 
@@ -51,7 +114,57 @@ impl<M: Model> UpdateBuilder<M> {
         self
     }
 
+    /// 设置字段为指定值：`col = ?`
+    ///
+    /// 模型模式下会覆盖 model 中同名字段的值
+    pub fn set(mut self, field_name: impl AsRef<str>, value: impl Into<BindValue>) -> Self {
+        let item = match value.into() {
+            BindValue::Null => SetItem::Null(field_name.as_ref().to_string()),
+            value => SetItem::Value(field_name.as_ref().to_string(), value),
+        };
+        self.sets.push(item);
+        self
+    }
+
+    /// 设置字段为 SQL 表达式：`col = <expr>`，表达式中的 `?` 绑定给定的值
+    ///
+    /// 表达式会原样拼接到 SQL 中，不要传入不可信的输入
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// builder.set_expr("num", "num + ?", 1)
+    /// ```
+    pub fn set_expr(mut self, field_name: impl AsRef<str>, expr: &str, value: impl Into<BindValue>) -> Self {
+        self.sets.push(SetItem::Expr(
+            field_name.as_ref().to_string(),
+            expr.to_string(),
+            vec![value.into()],
+        ));
+        self
+    }
+
+    /// 设置字段为不含绑定参数的 SQL 表达式：`col = <expr>`（如 `num + 1`、`CURRENT_TIMESTAMP`）
+    ///
+    /// 表达式会原样拼接到 SQL 中，不要传入不可信的输入
+    pub fn set_raw(mut self, field_name: impl AsRef<str>, expr: &str) -> Self {
+        self.sets.push(SetItem::Expr(
+            field_name.as_ref().to_string(),
+            expr.to_string(),
+            Vec::new(),
+        ));
+        self
+    }
+
+    /// 设置字段为 NULL：`col = NULL`
+    pub fn set_null(mut self, field_name: impl AsRef<str>) -> Self {
+        self.sets.push(SetItem::Null(field_name.as_ref().to_string()));
+        self
+    }
+
     /// 添加 WHERE 条件（复用 QueryBuilder）
+    ///
+    /// 模型定义了逻辑删除字段时默认只更新未删除的记录，可以通过 `with_trashed()` / `only_trashed()` 调整范围
     pub fn condition<F>(mut self, f: F) -> Self
     where
         F: FnOnce(QueryBuilder) -> QueryBuilder,
@@ -62,172 +175,713 @@ impl<M: Model> UpdateBuilder<M> {
         self
     }
 
-    /// 执行更新
-    pub async fn execute<'e, 'c: 'e, DB, E>(self, executor: E) -> Result<u64>
+    /// 允许更新所有记录（危险操作，需要明确调用）
+    ///
+    /// 仅在 `condition` 没有产生任何条件时生效；`condition` 中只调用 `with_trashed()` 等
+    /// 不产生条件的方法时，不会被视为允许更新所有记录（`only_trashed()` 会限定为已删除的记录，无需此调用）
+    pub fn allow_update_all(mut self) -> Self {
+        self.allow_update_all = true;
+        self
+    }
+
+    /// `condition` 中调用了 `only_trashed()` 且模型定义了逻辑删除字段
+    fn only_trashed(&self) -> bool {
+        M::SOFT_DELETE_FIELD.is_some()
+            && self
+                .where_builder
+                .as_ref()
+                .is_some_and(|b| b.trashed_scope() == TrashedScope::Only)
+    }
+
+    /// 乐观锁检查：model 模式下 `VERSION_FIELD` 有值且未被显式 `set` 时返回 (版本字段, 当前版本值)
+    fn version_check(&self) -> Option<(&'static str, BindValue)>
     where
-        DB: sqlx::Database + DatabaseInfo,
-        for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
-        E: sqlx::Executor<'c, Database = DB> + Send,
         M: UpdateFields,
-        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
-        String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-        Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    {
+        let version_field = M::VERSION_FIELD?;
+        if self.sets.iter().any(|item| item.column() == version_field) {
+            return None;
+        }
+        let version_value = self.model.as_ref()?.get_field_value(version_field)?;
+        Some((version_field, version_value))
+    }
+
+    /// 构建 UPDATE 语句和绑定值，没有需要更新的字段时返回 None
+    fn build_sql<DB>(&self) -> Result<Option<(String, Vec<BindValue>)>>
+    where
+        DB: DatabaseInfo,
+        M: UpdateFields,
     {
         let driver = DB::get_driver();
         let table = M::TABLE;
-        let pk = M::PK;
         let escaped_table = DB::escape_identifier(table);
-        let escaped_pk = DB::escape_identifier(pk);
-
-        // 确定要更新的字段列表
-        let fields_to_update = if self.fields.is_empty() {
-            // 如果没有指定字段，更新所有非主键字段
-            M::get_all_field_names()
-                .iter()
-                .filter(|&&name| name != pk)
-                .map(|s| s.to_string())
-                .collect::<Vec<_>>()
-        } else {
-            // 验证字段是否存在
-            for field_name in &self.fields {
-                if !M::has_field(field_name) {
-                    return Err(SqlxPlusError::InvalidField(format!(
-                        "Field '{}' does not exist in model '{}'",
-                        field_name, table
-                    )));
+        // 主键字段（复合主键包含多个）不允许更新
+        let is_pk = |name: &str| crate::primary_key::pk_columns::<M>().any(|pk| pk == name);
+
+        // 校验显式 SET 的字段
+        for item in &self.sets {
+            let field_name = item.column();
+            if !M::has_field(field_name) {
+                return Err(SqlxPlusError::InvalidField(format!(
+                    "Field '{}' does not exist in model '{}'",
+                    field_name, table
+                )));
+            }
+            if is_pk(field_name) {
+                return Err(SqlxPlusError::InvalidField(format!(
+                    "Cannot update primary key field '{}'",
+                    field_name
+                )));
+            }
+        }
+        let is_explicit = |name: &str| self.sets.iter().any(|item| item.column() == name);
+        // 版本字段由乐观锁自动维护，不从 model 中取值
+        let is_version = |name: &str| M::VERSION_FIELD == Some(name);
+        let version_check = self.version_check();
+
+        // 确定要从 model 中取值更新的字段列表
+        let fields_to_update = match &self.model {
+            None => {
+                if !self.fields.is_empty() {
+                    return Err(SqlxPlusError::InvalidField(
+                        "field()/fields() require a model, use set() in bulk mode".to_string(),
+                    ));
                 }
-                if field_name == pk {
-                    return Err(SqlxPlusError::InvalidField(format!(
-                        "Cannot update primary key field '{}'",
-                        pk
-                    )));
+                Vec::new()
+            }
+            Some(_) if self.fields.is_empty() => {
+                // 如果没有指定字段，更新所有非主键字段
+                M::get_all_field_names()
+                    .iter()
+                    .filter(|&&name| !is_pk(name) && !is_explicit(name) && !is_version(name))
+                    .map(|s| s.to_string())
+                    .collect::<Vec<_>>()
+            }
+            Some(_) => {
+                // 验证字段是否存在
+                for field_name in &self.fields {
+                    if !M::has_field(field_name) {
+                        return Err(SqlxPlusError::InvalidField(format!(
+                            "Field '{}' does not exist in model '{}'",
+                            field_name, table
+                        )));
+                    }
+                    if is_pk(field_name) {
+                        return Err(SqlxPlusError::InvalidField(format!(
+                            "Cannot update primary key field '{}'",
+                            field_name
+                        )));
+                    }
                 }
+                self.fields
+                    .iter()
+                    .filter(|name| !is_explicit(name) && !is_version(name))
+                    .cloned()
+                    .collect()
             }
-            self.fields
         };
 
-        if fields_to_update.is_empty() {
-            return Ok(0);
-        }
-
         // 构建 SET 子句
         let mut set_parts = Vec::new();
         let mut set_values = Vec::new();
         let mut placeholder_index = 0;
-        let now_ms = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64;
+        let now = crate::timestamp::now();
 
-        // 辅助闭包：判断字段名是否为更新时间字段
-        let is_updated_at = |name: &str| -> bool {
-            M::UPDATED_AT_FIELD.map_or(name == "updated_at", |f| f == name)
-        };
-
-        for field_name in &fields_to_update {
-            if is_updated_at(field_name) {
-                if let Some(bind_value) = self.model.get_field_value(field_name) {
-                    let escaped_field = DB::escape_identifier(field_name);
-                    set_parts.push(format!(
-                        "{} = {}",
-                        escaped_field,
-                        DB::placeholder(placeholder_index)
-                    ));
+        if let Some(model) = &self.model {
+            for field_name in &fields_to_update {
+                let escaped_field = DB::escape_identifier(field_name);
+                if let Some(bind_value) = model.get_field_value(field_name) {
+                    set_parts.push(format!("{} = {}", escaped_field, DB::placeholder(placeholder_index)));
                     set_values.push(bind_value);
                     placeholder_index += 1;
-                } else {
-                    let escaped_field = DB::escape_identifier(field_name);
+                } else if let Some(kind) = crate::timestamp::update_timestamp_kind::<M>(field_name) {
+                    // 更新时间字段为空时按时间戳类型填充当前时间
+                    match kind.value_at(now) {
+                        Some(bind_value) => {
+                            set_parts.push(format!("{} = {}", escaped_field, DB::placeholder(placeholder_index)));
+                            set_values.push(bind_value);
+                            placeholder_index += 1;
+                        }
+                        None => set_parts.push(format!(
+                            "{} = {}",
+                            escaped_field,
+                            crate::timestamp::CURRENT_TIMESTAMP
+                        )),
+                    }
+                }
+                // 如果字段值为 None（对于 Option 类型），跳过该字段
+            }
+        }
+
+        for item in &self.sets {
+            let escaped_field = DB::escape_identifier(item.column());
+            match item {
+                SetItem::Value(_, value) => {
                     set_parts.push(format!(
                         "{} = {}",
                         escaped_field,
                         DB::placeholder(placeholder_index)
                     ));
-                    set_values.push(super::query_builder::BindValue::Int64(now_ms));
+                    set_values.push(value.clone());
                     placeholder_index += 1;
                 }
-            } else if let Some(bind_value) = self.model.get_field_value(field_name) {
-                let escaped_field = DB::escape_identifier(field_name);
-                set_parts.push(format!(
-                    "{} = {}",
-                    escaped_field,
-                    DB::placeholder(placeholder_index)
-                ));
-                set_values.push(bind_value);
-                placeholder_index += 1;
+                SetItem::Expr(_, expr, values) => {
+                    let placeholder_count = expr.matches('?').count();
+                    if placeholder_count != values.len() {
+                        return Err(SqlxPlusError::InvalidField(format!(
+                            "Expression '{}' for field '{}' has {} placeholder(s) but {} value(s)",
+                            expr,
+                            item.column(),
+                            placeholder_count,
+                            values.len()
+                        )));
+                    }
+                    let mut rendered = String::with_capacity(expr.len());
+                    for ch in expr.chars() {
+                        if ch == '?' {
+                            rendered.push_str(&DB::placeholder(placeholder_index));
+                            placeholder_index += 1;
+                        } else {
+                            rendered.push(ch);
+                        }
+                    }
+                    set_parts.push(format!("{} = {}", escaped_field, rendered));
+                    set_values.extend(values.iter().cloned());
+                }
+                SetItem::Null(_) => {
+                    set_parts.push(format!("{} = NULL", escaped_field));
+                }
             }
-            // 如果字段值为 None（对于 Option 类型），跳过该字段
         }
 
         if set_parts.is_empty() {
-            return Ok(0);
+            return Ok(None);
+        }
+
+        // 版本字段自增：model 模式仅在携带版本值时，批量模式在未显式设置时
+        if let Some(version_field) = M::VERSION_FIELD {
+            let bump = if self.model.is_some() {
+                version_check.is_some()
+            } else {
+                !is_explicit(version_field)
+            };
+            if bump {
+                let escaped_version = DB::escape_identifier(version_field);
+                set_parts.push(format!("{} = {} + 1", escaped_version, escaped_version));
+            }
+        }
+
+        // 批量模式下自动设置更新时间字段
+        if self.model.is_none() {
+            if let Some(updated_at) = M::UPDATED_AT_FIELD {
+                if !is_explicit(updated_at) {
+                    let escaped_updated = DB::escape_identifier(updated_at);
+                    match M::UPDATED_AT_KIND.value_at(now) {
+                        Some(bind_value) => {
+                            set_parts.push(format!(
+                                "{} = {}",
+                                escaped_updated,
+                                DB::placeholder(placeholder_index)
+                            ));
+                            set_values.push(bind_value);
+                            placeholder_index += 1;
+                        }
+                        None => set_parts.push(format!(
+                            "{} = {}",
+                            escaped_updated,
+                            crate::timestamp::CURRENT_TIMESTAMP
+                        )),
+                    }
+                }
+            }
         }
 
         // 构建 WHERE 子句
-        let (where_clause, where_binds) = if let Some(where_builder) = &self.where_builder {
+        let custom_where = self.where_builder.as_ref().filter(|b| b.has_conditions());
+        let (where_clause, mut where_binds) = if let Some(where_builder) = custom_where {
             let (where_sql, _) = where_builder.build_where_sql(driver, placeholder_index);
             let where_binds = where_builder.where_binds().to_vec();
             (where_sql, where_binds)
-        } else {
-            // 如果没有 WHERE 条件，使用主键条件
-            // 需要从 model 中获取主键值
-            if let Some(pk_value) = self.model.get_field_value(pk) {
-                let where_sql = format!("{} = {}", escaped_pk, DB::placeholder(placeholder_index));
-                (where_sql, vec![pk_value])
-            } else {
-                return Err(SqlxPlusError::InvalidField(format!(
-                    "Primary key field '{}' is required for update but value is None",
-                    pk
-                )));
+        } else if self.allow_update_all {
+            // 明确允许更新所有记录
+            (String::new(), Vec::new())
+        } else if let Some(model) = &self.model {
+            // 如果没有 WHERE 条件，使用主键条件（复合主键的每个字段都必须有值）
+            let mut pk_values = Vec::new();
+            for pk in crate::primary_key::pk_columns::<M>() {
+                let pk_value = model.get_field_value(pk).ok_or_else(|| {
+                    SqlxPlusError::InvalidField(format!(
+                        "Primary key field '{}' is required for update but value is None",
+                        pk
+                    ))
+                })?;
+                pk_values.push(pk_value);
             }
+            let mut where_index = placeholder_index;
+            let where_sql = crate::primary_key::pk_condition::<DB, M>(&mut where_index);
+            (where_sql, pk_values)
+        } else if self.only_trashed() {
+            // 只处理已逻辑删除的记录（如批量恢复），范围条件在下方追加
+            (String::new(), Vec::new())
+        } else {
+            // 批量模式没有 WHERE 条件，返回错误（防止误更新所有数据）
+            return Err(SqlxPlusError::InvalidField(
+                "Bulk update requires WHERE condition. Use allow_update_all() to explicitly allow updating all records.".to_string(),
+            ));
         };
 
-        // 构建完整的 SQL
-        let sql = format!(
-            "UPDATE {} SET {} WHERE {}",
-            escaped_table,
-            set_parts.join(", "),
+        // 追加的条件：乐观锁版本条件、逻辑删除范围条件（默认排除已删除的记录）
+        let mut extra_conditions = Vec::new();
+        if let Some((version_field, version_value)) = &version_check {
+            extra_conditions.push(format!(
+                "{} = {}",
+                DB::escape_identifier(version_field),
+                DB::placeholder(placeholder_index + where_binds.len())
+            ));
+            where_binds.push(version_value.clone());
+        }
+        let scope = self
+            .where_builder
+            .as_ref()
+            .map_or(TrashedScope::Exclude, |b| b.trashed_scope());
+        extra_conditions.extend(crate::soft_delete::scope_condition::<DB, M>(scope));
+
+        let where_clause = if extra_conditions.is_empty() {
             where_clause
-        );
+        } else if where_clause.is_empty() {
+            extra_conditions.join(" AND ")
+        } else if custom_where.is_some() {
+            // 自定义条件可能包含顶层 OR，需要加括号
+            format!("({}) AND {}", where_clause, extra_conditions.join(" AND "))
+        } else {
+            format!("{} AND {}", where_clause, extra_conditions.join(" AND "))
+        };
+
+        // 构建完整的 SQL
+        let sql = if where_clause.is_empty() {
+            format!("UPDATE {} SET {}", escaped_table, set_parts.join(", "))
+        } else {
+            format!(
+                "UPDATE {} SET {} WHERE {}",
+                escaped_table,
+                set_parts.join(", "),
+                where_clause
+            )
+        };
+
+        set_values.extend(where_binds);
+        Ok(Some((sql, set_values)))
+    }
+
+    /// 执行更新
+    pub async fn execute<'e, 'c: 'e, DB, E>(self, executor: E) -> Result<u64>
+    where
+        DB: sqlx::Database + DatabaseInfo,
+        for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+        E: sqlx::Executor<'c, Database = DB> + Send,
+        M: UpdateFields,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    {
+        let Some((sql, binds)) = self.build_sql::<DB>()? else {
+            return Ok(0);
+        };
+        let version_check = self.version_check();
 
         // 执行更新
         let mut query = sqlx::query(&sql);
 
-        // 绑定 SET 子句的值
-        for bind_value in set_values {
-            crate::apply_bind_value!(query, bind_value);
-        }
-
-        // 绑定 WHERE 子句的值
-        for bind_value in where_binds {
+        // 绑定 SET 子句和 WHERE 子句的值（按顺序）
+        for bind_value in binds {
             crate::apply_bind_value!(query, bind_value);
         }
 
         let result = query.execute(executor).await?;
-        // 在泛型上下文中，使用 match 根据数据库类型获取 rows_affected
-        let rows_affected = match DB::get_driver() {
-            crate::db_pool::DbDriver::MySql => unsafe {
-                use sqlx::mysql::MySqlQueryResult;
-                let ptr: *const DB::QueryResult = &result;
-                let mysql_ptr = ptr as *const MySqlQueryResult;
-                (*mysql_ptr).rows_affected()
-            },
-            crate::db_pool::DbDriver::Postgres => unsafe {
-                use sqlx::postgres::PgQueryResult;
-                let ptr: *const DB::QueryResult = &result;
-                let pg_ptr = ptr as *const PgQueryResult;
-                (*pg_ptr).rows_affected()
-            },
-            crate::db_pool::DbDriver::Sqlite => unsafe {
-                use sqlx::sqlite::SqliteQueryResult;
-                let ptr: *const DB::QueryResult = &result;
-                let sqlite_ptr = ptr as *const SqliteQueryResult;
-                (*sqlite_ptr).rows_affected()
-            },
-        };
+        let rows_affected = DB::rows_affected(&result);
+
+        // 带版本条件却没有更新到任何行：记录已被其他事务修改（或已不存在）
+        if rows_affected == 0 {
+            if let Some((version_field, version_value)) = version_check {
+                return Err(SqlxPlusError::StaleVersion(format!(
+                    "{} row was modified concurrently or no longer exists ({} = {} mismatch)",
+                    M::TABLE,
+                    version_field,
+                    version_value.to_sql_value()
+                )));
+            }
+        }
         Ok(rows_affected)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter {
+        id: Option<i64>,
+        state: Option<i16>,
+        num: Option<i64>,
+    }
+
+    impl Model for Counter {
+        const TABLE: &'static str = "counter";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const UPDATED_AT_FIELD: Option<&'static str> = Some("updated_at");
+    }
+
+    impl UpdateFields for Counter {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "id" => self.id.map(BindValue::from),
+                "state" => self.state.map(BindValue::from),
+                "num" => self.num.map(BindValue::from),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &["state", "num", "remark", "updated_at"]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            matches!(field_name, "id" | "state" | "num" | "remark" | "updated_at")
+        }
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_mysql_bulk_update_sql() {
+        let (sql, binds) = UpdateBuilder::<Counter>::bulk()
+            .set("state", 2i16)
+            .set_expr("num", "num + ?", 1i64)
+            .set_null("remark")
+            .condition(|q| q.and_in("id", vec![1i64, 2]))
+            .build_sql::<sqlx::MySql>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "UPDATE `counter` SET `state` = ?, `num` = num + ?, `remark` = NULL, `updated_at` = ? WHERE `id` IN (?, ?)"
+        );
+        assert_eq!(binds.len(), 5);
+        assert_eq!(binds[0], BindValue::Int16(2));
+        assert_eq!(binds[1], BindValue::Int64(1));
+        assert_eq!(binds[3], BindValue::Int64(1));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres_bulk_update_placeholders() {
+        let (sql, binds) = UpdateBuilder::<Counter>::bulk()
+            .set_expr("num", "num + ?", 5i64)
+            .set("updated_at", 0i64)
+            .condition(|q| q.and_eq("state", 1i16))
+            .build_sql::<sqlx::Postgres>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "counter" SET "num" = num + $1, "updated_at" = $2 WHERE "state" = $3"#
+        );
+        assert_eq!(binds, vec![BindValue::Int64(5), BindValue::Int64(0), BindValue::Int16(1)]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_model_update_with_explicit_set() {
+        let model = Counter { id: Some(7), state: Some(1), num: Some(3) };
+        let (sql, binds) = UpdateBuilder::new(model)
+            .fields(&["state", "num"])
+            .set_raw("num", "num + 1")
+            .build_sql::<sqlx::Sqlite>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "counter" SET "state" = ?, "num" = num + 1 WHERE "id" = ?"#
+        );
+        assert_eq!(binds, vec![BindValue::Int16(1), BindValue::Int64(7)]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_bulk_update_validation() {
+        // 批量模式必须指定 WHERE 条件
+        assert!(UpdateBuilder::<Counter>::bulk()
+            .set("state", 2i16)
+            .build_sql::<sqlx::Sqlite>()
+            .is_err());
+        // condition 没有产生任何条件时同样视为缺少 WHERE 条件
+        assert!(UpdateBuilder::<Counter>::bulk()
+            .set("state", 2i16)
+            .condition(|q| q.with_trashed())
+            .build_sql::<sqlx::Sqlite>()
+            .is_err());
+        // 不允许更新主键
+        assert!(UpdateBuilder::<Counter>::bulk()
+            .set("id", 2i64)
+            .allow_update_all()
+            .build_sql::<sqlx::Sqlite>()
+            .is_err());
+        // 占位符数量与绑定值不一致
+        assert!(UpdateBuilder::<Counter>::bulk()
+            .set_expr("num", "num + 1", 1i64)
+            .allow_update_all()
+            .build_sql::<sqlx::Sqlite>()
+            .is_err());
+        let (sql, _) = UpdateBuilder::<Counter>::bulk()
+            .set("state", 0i16)
+            .allow_update_all()
+            .build_sql::<sqlx::Sqlite>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, r#"UPDATE "counter" SET "state" = ?, "updated_at" = ?"#);
+    }
+
+    struct Article {
+        id: Option<i64>,
+        title: Option<String>,
+    }
+
+    impl Model for Article {
+        const TABLE: &'static str = "article";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const UPDATED_AT_FIELD: Option<&'static str> = Some("modified");
+        const UPDATED_AT_KIND: crate::timestamp::TimestampKind =
+            crate::timestamp::TimestampKind::CurrentTimestamp;
+    }
+
+    impl UpdateFields for Article {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "id" => self.id.map(BindValue::from),
+                "title" => self.title.clone().map(BindValue::from),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &["title", "modified"]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            matches!(field_name, "id" | "title" | "modified")
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_db_side_updated_at() {
+        // 数据库生成的更新时间不占用占位符
+        let model = Article { id: Some(3), title: Some("t".to_string()) };
+        let (sql, binds) = UpdateBuilder::new(model)
+            .build_sql::<sqlx::Postgres>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "article" SET "title" = $1, "modified" = CURRENT_TIMESTAMP WHERE "id" = $2"#
+        );
+        assert_eq!(binds, vec![BindValue::String("t".to_string()), BindValue::Int64(3)]);
+
+        let (sql, _) = UpdateBuilder::<Article>::bulk()
+            .set("title", "x")
+            .condition(|q| q.and_eq("id", 1i64))
+            .build_sql::<sqlx::Postgres>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "article" SET "title" = $1, "modified" = CURRENT_TIMESTAMP WHERE "id" = $2"#
+        );
+    }
+
+    struct Memo {
+        id: Option<i64>,
+        body: Option<String>,
+    }
+
+    impl Model for Memo {
+        const TABLE: &'static str = "memo";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const SOFT_DELETE_FIELD: Option<&'static str> = Some("is_del");
+    }
+
+    impl UpdateFields for Memo {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "id" => self.id.map(BindValue::from),
+                "body" => self.body.clone().map(BindValue::from),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &["body", "is_del"]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            matches!(field_name, "id" | "body" | "is_del")
+        }
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_update_excludes_trashed() {
+        let model = Memo { id: Some(4), body: Some("b".to_string()) };
+        let (sql, _) = UpdateBuilder::new(model)
+            .build_sql::<sqlx::MySql>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, "UPDATE `memo` SET `body` = ? WHERE `id` = ? AND `is_del` = 0");
+
+        let (sql, _) = UpdateBuilder::<Memo>::bulk()
+            .set("body", "x")
+            .condition(|q| q.and_eq("id", 1i64).or_eq("id", 2i64))
+            .build_sql::<sqlx::MySql>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "UPDATE `memo` SET `body` = ? WHERE (`id` = ? OR `id` = ?) AND `is_del` = 0"
+        );
+
+        // with_trashed 时可以更新已删除的记录（如批量恢复）
+        let (sql, _) = UpdateBuilder::<Memo>::bulk()
+            .set("is_del", 0i16)
+            .condition(|q| q.and_eq("id", 1i64).with_trashed())
+            .build_sql::<sqlx::MySql>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, "UPDATE `memo` SET `is_del` = ? WHERE `id` = ?");
+
+        // 模型模式下只调整范围时仍按主键更新
+        let model = Memo { id: Some(4), body: Some("b".to_string()) };
+        let (sql, _) = UpdateBuilder::new(model)
+            .condition(|q| q.with_trashed())
+            .build_sql::<sqlx::MySql>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, "UPDATE `memo` SET `body` = ? WHERE `id` = ?");
+
+        // 只处理已删除的记录时不需要其他条件
+        let (sql, _) = UpdateBuilder::<Memo>::bulk()
+            .set("is_del", 0i16)
+            .condition(|q| q.only_trashed())
+            .build_sql::<sqlx::MySql>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, "UPDATE `memo` SET `is_del` = ? WHERE `is_del` <> 0");
+
+        // 显式允许更新所有记录时保留 with_trashed 范围
+        let (sql, _) = UpdateBuilder::<Memo>::bulk()
+            .set("body", "x")
+            .condition(|q| q.with_trashed())
+            .allow_update_all()
+            .build_sql::<sqlx::MySql>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, "UPDATE `memo` SET `body` = ?");
+    }
+
+    struct Doc {
+        id: Option<i64>,
+        title: Option<String>,
+        version: Option<i32>,
+    }
+
+    impl Model for Doc {
+        const TABLE: &'static str = "doc";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const VERSION_FIELD: Option<&'static str> = Some("version");
+    }
+
+    impl UpdateFields for Doc {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "id" => self.id.map(BindValue::from),
+                "title" => self.title.clone().map(BindValue::from),
+                "version" => self.version.map(BindValue::from),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &["title", "version"]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            matches!(field_name, "id" | "title" | "version")
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_version_check_and_increment() {
+        let doc = Doc { id: Some(1), title: Some("t".to_string()), version: Some(3) };
+        let (sql, binds) = UpdateBuilder::new(doc)
+            .build_sql::<sqlx::Postgres>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "doc" SET "title" = $1, "version" = "version" + 1 WHERE "id" = $2 AND "version" = $3"#
+        );
+        assert_eq!(
+            binds,
+            vec![BindValue::String("t".to_string()), BindValue::Int64(1), BindValue::Int32(3)]
+        );
+
+        // 自定义条件与版本条件组合
+        let doc = Doc { id: Some(1), title: Some("t".to_string()), version: Some(3) };
+        let (sql, _) = UpdateBuilder::new(doc)
+            .field("title")
+            .condition(|q| q.and_eq("id", 1i64).or_eq("id", 2i64))
+            .build_sql::<sqlx::Postgres>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "doc" SET "title" = $1, "version" = "version" + 1 WHERE ("id" = $2 OR "id" = $3) AND "version" = $4"#
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_version_skipped_without_value_and_bumped_in_bulk() {
+        // 没有版本值时不做乐观锁检查
+        let doc = Doc { id: Some(1), title: Some("t".to_string()), version: None };
+        let (sql, _) = UpdateBuilder::new(doc)
+            .build_sql::<sqlx::Sqlite>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, r#"UPDATE "doc" SET "title" = ? WHERE "id" = ?"#);
+
+        // 批量模式自增版本，使持有旧版本的更新失败
+        let (sql, _) = UpdateBuilder::<Doc>::bulk()
+            .set("title", "x")
+            .condition(|q| q.and_gt("id", 0i64))
+            .build_sql::<sqlx::Sqlite>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "doc" SET "title" = ?, "version" = "version" + 1 WHERE "id" > ?"#
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! 类型化的列句柄
+//!
+//! 由 `derive(ModelMeta)` 为每个字段生成关联常量（如 `User::USERNAME`），
+//! 可直接传给 `QueryBuilder` 的条件方法，列名拼写错误会在编译期暴露。
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// 模型 `M` 的一个数据库列
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// let builder = QueryBuilder::new("")
+///     .and_eq(User::USERNAME, "alice")
+///     .order_by(User::ID, false);
+/// ```
+pub struct Column<M> {
+    name: &'static str,
+    _model: PhantomData<fn() -> M>,
+}
+
+impl<M> Column<M> {
+    /// 创建列句柄（一般由 derive 宏调用）
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _model: PhantomData,
+        }
+    }
+
+    /// 数据库列名（已应用 `#[column(name = "...")]`）
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+// 手动实现，避免要求 M 实现 Clone / Copy / Debug 等 trait
+impl<M> Clone for Column<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for Column<M> {}
+
+impl<M> PartialEq for Column<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<M> Eq for Column<M> {}
+
+impl<M> fmt::Debug for Column<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Column").field(&self.name).finish()
+    }
+}
+
+impl<M> fmt::Display for Column<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl<M> AsRef<str> for Column<M> {
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
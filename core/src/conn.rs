@@ -0,0 +1,342 @@
+//! 运行时分派的 CRUD 执行器
+//!
+//! `Crud` 的方法在编译期根据执行器推断数据库类型，因此需要写 `pool.mysql_pool()` 这样的驱动相关代码。
+//! [`DbConn`] 包装 `&DbPool` 或 `&mut Transaction`，在运行时根据 `DbDriver` 分派到对应驱动的执行器，
+//! 同一段代码可以运行在由 URL 选择的 MySQL / PostgreSQL / SQLite 上。
+//!
+//! 连接池使用读写分离执行器（`mysql_routed()` 等），未配置从库时等同于主库。
+
+use crate::builder::query_builder::QueryBuilder;
+use crate::crud::{CursorPage, Page, RecordStream};
+use crate::db_pool::{DbDriver, DbPool};
+use crate::error::{Result, SqlxPlusError};
+use crate::primary_key::BindPrimaryKey;
+use crate::traits::Crud;
+use crate::transaction::Transaction;
+
+/// 连接池分派：`$executor` 依次绑定为各驱动的读写分离执行器
+///
+/// `$db` 为对应的 sqlx 数据库类型别名，用于 `insert` 等需要显式指定数据库类型的方法
+macro_rules! dispatch_pool {
+    ($pool:expr, |$executor:ident, $db:ident| $body:expr) => {
+        match $pool.driver() {
+            #[cfg(feature = "mysql")]
+            DbDriver::MySql => {
+                #[allow(dead_code)]
+                type $db = sqlx::MySql;
+                let $executor = $pool.mysql_routed();
+                $body
+            }
+            #[cfg(feature = "postgres")]
+            DbDriver::Postgres => {
+                #[allow(dead_code)]
+                type $db = sqlx::Postgres;
+                let $executor = $pool.pg_routed();
+                $body
+            }
+            #[cfg(feature = "sqlite")]
+            DbDriver::Sqlite => {
+                #[allow(dead_code)]
+                type $db = sqlx::Sqlite;
+                let $executor = $pool.sqlite_routed();
+                $body
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(SqlxPlusError::NoPoolAvailable),
+        }
+    };
+}
+
+/// 事务分派：`$executor` 依次绑定为事务内部的连接
+macro_rules! dispatch_tx {
+    ($tx:expr, |$executor:ident, $db:ident| $body:expr) => {
+        match $tx {
+            #[cfg(feature = "mysql")]
+            Transaction::MySql(tx) => {
+                #[allow(dead_code)]
+                type $db = sqlx::MySql;
+                let $executor = &mut **tx;
+                $body
+            }
+            #[cfg(feature = "postgres")]
+            Transaction::Postgres(tx) => {
+                #[allow(dead_code)]
+                type $db = sqlx::Postgres;
+                let $executor = &mut **tx;
+                $body
+            }
+            #[cfg(feature = "sqlite")]
+            Transaction::Sqlite(tx) => {
+                #[allow(dead_code)]
+                type $db = sqlx::Sqlite;
+                let $executor = &mut **tx;
+                $body
+            }
+        }
+    };
+}
+
+macro_rules! dispatch {
+    ($conn:expr, |$executor:ident, $db:ident| $body:expr) => {
+        match $conn {
+            DbConn::Pool(pool) => dispatch_pool!(pool, |$executor, $db| $body),
+            DbConn::Transaction(tx) => dispatch_tx!(tx, |$executor, $db| $body),
+        }
+    };
+    ($conn:expr, |$executor:ident| $body:expr) => {
+        dispatch!($conn, |$executor, _DB| $body)
+    };
+}
+
+/// 驱动无关的 CRUD 执行器：`&DbPool` 或 `&mut Transaction`
+///
+/// 方法与 `Crud` 一一对应，模型类型通过泛型参数指定。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// use sqlxplus::{DbConn, DbPool, Transaction};
+///
+/// // 驱动由 URL 决定，业务代码不再区分 MySQL / PostgreSQL / SQLite
+/// let pool = DbPool::connect(&std::env::var("DATABASE_URL")?).await?;
+///
+/// let id = pool.conn().insert(&user).await?;
+/// let user = pool.conn().find_by_id::<User>(id).await?;
+///
+/// let mut tx = Transaction::begin(&pool).await?;
+/// tx.conn().update(&user).await?;
+/// tx.conn().delete_by_id::<User>(id).await?;
+/// tx.commit().await?;
+///
+/// // 同时接受连接池和事务
+/// async fn active_users(conn: DbConn<'_, '_>) -> sqlxplus::Result<Vec<User>> {
+///     conn.find_all::<User>(Some(QueryBuilder::new("").and_eq("active", true))).await
+/// }
+/// ```
+#[derive(Debug)]
+pub enum DbConn<'a, 'tx> {
+    Pool(&'a DbPool),
+    Transaction(&'a mut Transaction<'tx>),
+}
+
+impl<'a> From<&'a DbPool> for DbConn<'a, '_> {
+    fn from(pool: &'a DbPool) -> Self {
+        DbConn::Pool(pool)
+    }
+}
+
+impl<'a, 'tx> From<&'a mut Transaction<'tx>> for DbConn<'a, 'tx> {
+    fn from(tx: &'a mut Transaction<'tx>) -> Self {
+        DbConn::Transaction(tx)
+    }
+}
+
+impl DbPool {
+    /// 驱动无关的 CRUD 执行器
+    pub fn conn(&self) -> DbConn<'_, 'static> {
+        DbConn::Pool(self)
+    }
+}
+
+impl<'tx> Transaction<'tx> {
+    /// 驱动无关的 CRUD 执行器（在事务中执行）
+    pub fn conn(&mut self) -> DbConn<'_, 'tx> {
+        DbConn::Transaction(self)
+    }
+}
+
+impl<'a, 'tx> DbConn<'a, 'tx> {
+    /// 数据库驱动
+    pub fn driver(&self) -> DbDriver {
+        match self {
+            DbConn::Pool(pool) => pool.driver(),
+            DbConn::Transaction(tx) => match tx {
+                #[cfg(feature = "mysql")]
+                Transaction::MySql(_) => DbDriver::MySql,
+                #[cfg(feature = "postgres")]
+                Transaction::Postgres(_) => DbDriver::Postgres,
+                #[cfg(feature = "sqlite")]
+                Transaction::Sqlite(_) => DbDriver::Sqlite,
+            },
+        }
+    }
+
+    /// 插入记录，见 `Crud::insert`
+    pub async fn insert<M>(self, model: &M) -> Result<M::Id>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor, DB| model.insert::<DB, _>(executor).await)
+    }
+
+    /// 批量插入记录，见 `Crud::insert_many`
+    pub async fn insert_many<M>(self, models: &[M]) -> Result<Vec<M::Id>>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor, DB| M::insert_many::<DB, _>(models, executor).await)
+    }
+
+    /// 更新记录（忽略 `None` 字段），见 `Crud::update`
+    pub async fn update<M>(self, model: &M) -> Result<()>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor, DB| model.update::<DB, _>(executor).await)
+    }
+
+    /// 更新记录（`None` 字段更新为 NULL），见 `Crud::update_with_none`
+    pub async fn update_with_none<M>(self, model: &M) -> Result<()>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor, DB| model.update_with_none::<DB, _>(executor).await)
+    }
+
+    /// 根据主键查找记录，见 `Crud::find_by_id`
+    pub async fn find_by_id<M>(self, id: impl Into<M::Id> + Send) -> Result<Option<M>>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        let id = id.into();
+        dispatch!(self, |executor| M::find_by_id(executor, id).await)
+    }
+
+    /// 根据多个主键查找记录，见 `Crud::find_by_ids`
+    pub async fn find_by_ids<M, I>(self, ids: I) -> Result<Vec<M>>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+        I: IntoIterator + Send,
+        I::Item: Into<M::Id>,
+    {
+        let ids: Vec<M::Id> = ids.into_iter().map(Into::into).collect();
+        dispatch!(self, |executor| M::find_by_ids(executor, ids).await)
+    }
+
+    /// 查找单条记录，见 `Crud::find_one`
+    pub async fn find_one<M>(self, builder: QueryBuilder) -> Result<Option<M>>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor| M::find_one(executor, builder).await)
+    }
+
+    /// 查找所有记录（最多 1000 条），见 `Crud::find_all`
+    pub async fn find_all<M>(self, builder: Option<QueryBuilder>) -> Result<Vec<M>>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor| M::find_all(executor, builder).await)
+    }
+
+    /// 以流的形式逐行返回记录，见 `Crud::stream`
+    pub fn stream<M>(self, builder: Option<QueryBuilder>) -> RecordStream<'a, M>
+    where
+        M: Crud + 'a,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        let result: Result<RecordStream<'a, M>> =
+            dispatch!(self, |executor| Ok(M::stream(executor, builder)));
+        result.unwrap_or_else(|err| Box::pin(futures_util::stream::once(async move { Err(err) })))
+    }
+
+    /// 统计记录数，见 `Crud::count`
+    pub async fn count<M>(self, builder: QueryBuilder) -> Result<u64>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor| M::count(executor, builder).await)
+    }
+
+    /// 分页查询，见 `Crud::paginate`
+    ///
+    /// 在事务中时，count 查询和分页查询在同一个连接上依次执行
+    pub async fn paginate<M>(self, builder: QueryBuilder, page: u32, size: u32) -> Result<Page<M>>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        match self {
+            DbConn::Pool(pool) => {
+                dispatch_pool!(pool, |executor, _DB| M::paginate(executor, builder, page, size).await)
+            }
+            DbConn::Transaction(tx) => match tx {
+                #[cfg(feature = "mysql")]
+                Transaction::MySql(tx) => {
+                    crate::crud::paginate_on_connection::<sqlx::MySql, M>(tx, builder, page, size).await
+                }
+                #[cfg(feature = "postgres")]
+                Transaction::Postgres(tx) => {
+                    crate::crud::paginate_on_connection::<sqlx::Postgres, M>(tx, builder, page, size).await
+                }
+                #[cfg(feature = "sqlite")]
+                Transaction::Sqlite(tx) => {
+                    crate::crud::paginate_on_connection::<sqlx::Sqlite, M>(tx, builder, page, size).await
+                }
+            },
+        }
+    }
+
+    /// 游标分页查询，见 `Crud::paginate_cursor`
+    pub async fn paginate_cursor<M>(
+        self,
+        builder: QueryBuilder,
+        cursor: Option<&str>,
+        size: u32,
+    ) -> Result<CursorPage<M>>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        dispatch!(self, |executor| M::paginate_cursor(executor, builder, cursor, size).await)
+    }
+
+    /// 根据主键删除记录（有逻辑删除字段时逻辑删除），见 `Crud::delete_by_id`
+    pub async fn delete_by_id<M>(self, id: impl Into<M::Id> + Send) -> Result<()>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        let id = id.into();
+        dispatch!(self, |executor| M::delete_by_id(executor, id).await)
+    }
+
+    /// 根据主键物理删除记录，见 `Crud::hard_delete_by_id`
+    pub async fn hard_delete_by_id<M>(self, id: impl Into<M::Id> + Send) -> Result<()>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        let id = id.into();
+        dispatch!(self, |executor| M::hard_delete_by_id(executor, id).await)
+    }
+
+    /// 根据主键逻辑删除记录，见 `Crud::soft_delete_by_id`
+    pub async fn soft_delete_by_id<M>(self, id: impl Into<M::Id> + Send) -> Result<()>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        let id = id.into();
+        dispatch!(self, |executor| M::soft_delete_by_id(executor, id).await)
+    }
+
+    /// 根据主键恢复逻辑删除的记录，见 `Crud::restore_by_id`
+    pub async fn restore_by_id<M>(self, id: impl Into<M::Id> + Send) -> Result<()>
+    where
+        M: Crud,
+        M::Id: BindPrimaryKey<sqlx::MySql> + BindPrimaryKey<sqlx::Postgres> + BindPrimaryKey<sqlx::Sqlite>,
+    {
+        let id = id.into();
+        dispatch!(self, |executor| M::restore_by_id(executor, id).await)
+    }
+}
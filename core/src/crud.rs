@@ -1,12 +1,12 @@
 use crate::builder::query_builder::{BindValue, QueryBuilder};
+use crate::builder::update_builder::UpdateFields;
 use crate::database_info::DatabaseInfo;
 use crate::error::{Result, SqlxPlusError};
+use crate::primary_key::{pk_condition, pk_in_condition, BindPrimaryKey};
+use crate::relation::{collect_keys, related_query, Related, RelatedMap};
 use crate::traits::Model;
 use sqlx::{Database, Row};
 
-/// 主键 ID 类型
-pub type Id = i64;
-
 /// 辅助函数：将单个绑定值应用到查询中
 /// 这是一个通用的绑定逻辑，通过宏来应用到不同的查询类型
 #[macro_export]
@@ -60,6 +60,12 @@ macro_rules! apply_bind_value {
             $crate::builder::query_builder::BindValue::Bytes(b) => {
                 $query = $query.bind(b);
             }
+            $crate::builder::query_builder::BindValue::DateTime(dt) => {
+                $query = $query.bind(dt);
+            }
+            $crate::builder::query_builder::BindValue::NaiveDateTime(dt) => {
+                $query = $query.bind(dt);
+            }
             $crate::builder::query_builder::BindValue::Null => {
                 $query = $query.bind(Option::<String>::None);
             }
@@ -102,6 +108,8 @@ where
     bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
 {
     for bind in binds {
         crate::apply_bind_value!(query, bind);
@@ -146,6 +154,8 @@ where
     bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
 {
     for bind in binds {
         crate::apply_bind_value!(query, bind);
@@ -166,7 +176,7 @@ pub struct Page<T> {
 impl<T> Page<T> {
     pub fn new(items: Vec<T>, total: i64, page: u32, size: u32) -> Self {
         let pages = if size > 0 {
-            ((total as u64 + size as u64 - 1) / size as u64) as u32
+            (total as u64).div_ceil(size as u64) as u32
         } else {
             0
         };
@@ -181,23 +191,126 @@ impl<T> Page<T> {
 }
 
 /// 游标分页结果
+///
+/// `next_cursor` / `prev_cursor` 为不透明的游标字符串，原样传回 `paginate_cursor` 即可翻页
 #[derive(Debug, Clone)]
 pub struct CursorPage<T> {
     pub items: Vec<T>,
     pub has_next: bool,
-    pub next_cursor: Option<i64>, // 下一次游标位置
+    pub has_prev: bool,
+    pub next_cursor: Option<String>, // 下一页游标（本页最后一条记录）
+    pub prev_cursor: Option<String>, // 上一页游标（本页第一条记录）
 }
 
 impl<T> CursorPage<T> {
-    pub fn new(items: Vec<T>, has_next: bool, next_cursor: Option<i64>) -> Self {
+    pub fn new(
+        items: Vec<T>,
+        has_next: bool,
+        has_prev: bool,
+        next_cursor: Option<String>,
+        prev_cursor: Option<String>,
+    ) -> Self {
         Self {
             items,
             has_next,
+            has_prev,
             next_cursor,
+            prev_cursor,
         }
     }
 }
 
+/// 构建多行 INSERT 语句（批量插入使用）
+///
+/// 生成 `INSERT INTO table (c1, c2) VALUES (?, ?), (?, ?)`，
+/// 当 `returning` 为 `Some(pk)` 时追加 `RETURNING pk`（PostgreSQL / SQLite 使用）。
+///
+/// # 参数
+///
+/// * `table` - 表名（未转义）
+/// * `columns` - 列名列表（未转义）
+/// * `rows` - 行数
+/// * `returning` - 需要返回的主键列名
+pub fn build_insert_sql<DB>(
+    table: &str,
+    columns: &[&str],
+    rows: usize,
+    returning: Option<&str>,
+) -> String
+where
+    DB: DatabaseInfo,
+{
+    build_insert_sql_with_db_now::<DB>(table, columns, &[], rows, returning)
+}
+
+/// 构建多行 INSERT 语句，`db_now_columns` 中的列写入 `CURRENT_TIMESTAMP` 而不是占位符
+///
+/// 用于 `TimestampKind::CurrentTimestamp` 类型的时间戳字段，这些列不占用绑定参数。
+pub fn build_insert_sql_with_db_now<DB>(
+    table: &str,
+    columns: &[&str],
+    db_now_columns: &[&str],
+    rows: usize,
+    returning: Option<&str>,
+) -> String
+where
+    DB: DatabaseInfo,
+{
+    let escaped_columns: Vec<String> = columns.iter().map(|c| DB::escape_identifier(c)).collect();
+    let mut placeholder_index = 0;
+    let mut values = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let placeholders: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                if db_now_columns.contains(column) {
+                    return crate::timestamp::CURRENT_TIMESTAMP.to_string();
+                }
+                let p = DB::placeholder(placeholder_index);
+                placeholder_index += 1;
+                p
+            })
+            .collect();
+        values.push(format!("({})", placeholders.join(", ")));
+    }
+    let mut sql = format!(
+        "INSERT INTO {} ({}) VALUES {}",
+        DB::escape_identifier(table),
+        escaped_columns.join(", "),
+        values.join(", ")
+    );
+    if let Some(pk) = returning {
+        sql.push_str(" RETURNING ");
+        sql.push_str(&DB::escape_identifier(pk));
+    }
+    sql
+}
+
+/// 计算批量插入时单条语句最多容纳的行数
+///
+/// 受数据库绑定参数上限约束（见 [`DbDriver::max_bind_params`](crate::db_pool::DbDriver::max_bind_params)），
+/// 至少返回 1。
+pub fn insert_chunk_size(driver: crate::db_pool::DbDriver, column_count: usize) -> usize {
+    (driver.max_bind_params() / column_count.max(1)).max(1)
+}
+
+/// 生成模型查询的列列表（已转义），`Model::COLUMNS` 为空时返回 `*`
+fn select_columns_sql<DB, M>() -> String
+where
+    DB: DatabaseInfo,
+    M: Model,
+{
+    if M::COLUMNS.is_empty() {
+        "*".to_string()
+    } else {
+        M::COLUMNS
+            .iter()
+            .map(|c| DB::escape_identifier(c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 /// 根据 ID 查找单条记录（泛型版本）
 ///
 /// 这是统一的泛型实现，支持所有实现了 `DatabaseInfo` 的数据库类型。
@@ -211,7 +324,7 @@ impl<T> CursorPage<T> {
 /// # 参数
 ///
 /// * `executor` - 数据库执行器（连接池或事务）
-/// * `id` - 主键 ID 值
+/// * `id` - 主键值（复合主键为元组）
 ///
 /// # 返回值
 ///
@@ -231,37 +344,37 @@ impl<T> CursorPage<T> {
 /// // SQLite
 /// let user = crud::find_by_id::<sqlx::Sqlite, User, _>(pool, 1).await?;
 /// ```
-pub async fn find_by_id<'e, 'c: 'e, DB, M, E>(
-    executor: E,
-    id: impl for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB> + Send + Sync,
-) -> Result<Option<M>>
+pub async fn find_by_id<'e, 'c: 'e, DB, M, E>(executor: E, id: M::Id) -> Result<Option<M>>
 where
     DB: Database + DatabaseInfo,
     for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
     M: Model + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+    M::Id: BindPrimaryKey<DB>,
     E: sqlx::Executor<'c, Database = DB> + Send,
 {
     // 使用 DatabaseInfo trait 获取数据库特定信息
     let escaped_table = DB::escape_identifier(M::TABLE);
-    let escaped_pk = DB::escape_identifier(M::PK);
-    let placeholder = DB::placeholder(0);
+    let pk_condition = pk_condition::<DB, M>(&mut 0);
 
     let sql_str = if let Some(soft_delete_field) = M::SOFT_DELETE_FIELD {
         let escaped_field = DB::escape_identifier(soft_delete_field);
         format!(
-            "SELECT * FROM {} WHERE {} = {} AND {} = 0",
-            escaped_table, escaped_pk, placeholder, escaped_field
+            "SELECT {} FROM {} WHERE {} AND {}",
+            select_columns_sql::<DB, M>(),
+            escaped_table,
+            pk_condition,
+            M::SOFT_DELETE_KIND.active_condition(&escaped_field)
         )
     } else {
         format!(
-            "SELECT * FROM {} WHERE {} = {}",
-            escaped_table, escaped_pk, placeholder
+            "SELECT {} FROM {} WHERE {}",
+            select_columns_sql::<DB, M>(), escaped_table, pk_condition
         )
     };
 
     // 执行查询 - sqlx::query 可以从 executor 推断数据库类型
-    match sqlx::query(&sql_str)
-        .bind(id)
+    match id
+        .bind_to(sqlx::query(&sql_str))
         .fetch_optional(executor)
         .await?
     {
@@ -286,7 +399,7 @@ where
 ///
 /// * `DB` - 数据库类型（如 `sqlx::MySql`, `sqlx::Postgres`, `sqlx::Sqlite`）
 /// * `M` - 模型类型，必须实现 `Model` trait 和对应数据库的 `FromRow`
-/// * `I` - ID 集合类型，可以是 `Vec<T>` 或其他实现了 `IntoIterator` 的类型
+/// * `I` - ID 集合类型，可以是 `Vec<T>` 或其他实现了 `IntoIterator` 的类型，元素可转换为 `M::Id`
 /// * `E` - 执行器类型，可以是连接池或事务
 ///
 /// # 参数
@@ -317,42 +430,44 @@ where
     DB: Database + DatabaseInfo,
     for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
     M: Model + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+    M::Id: BindPrimaryKey<DB>,
     I: IntoIterator + Send,
-    I::Item: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB> + Send + Sync + Clone,
+    I::Item: Into<M::Id>,
     E: sqlx::Executor<'c, Database = DB> + Send,
 {
-    let ids_vec: Vec<_> = ids.into_iter().collect();
+    let ids_vec: Vec<M::Id> = ids.into_iter().map(Into::into).collect();
     if ids_vec.is_empty() {
         return Ok(Vec::new());
     }
 
     // 使用 DatabaseInfo trait 获取数据库特定信息
     let escaped_table = DB::escape_identifier(M::TABLE);
-    let escaped_pk = DB::escape_identifier(M::PK);
-
-    // 为每个 ID 生成占位符
-    let placeholders: Vec<String> = (0..ids_vec.len()).map(|i| DB::placeholder(i)).collect();
-    let placeholders_str = placeholders.join(", ");
 
+    // 单列主键使用 IN 列表，复合主键展开为 OR 条件
     let mut sql_str = format!(
-        "SELECT * FROM {} WHERE {} IN ({})",
-        escaped_table, escaped_pk, placeholders_str
+        "SELECT {} FROM {} WHERE {}",
+        select_columns_sql::<DB, M>(),
+        escaped_table,
+        pk_in_condition::<DB, M>(ids_vec.len())
     );
 
     if let Some(soft_delete_field) = M::SOFT_DELETE_FIELD {
         let escaped_field = DB::escape_identifier(soft_delete_field);
-        sql_str.push_str(&format!(" AND {} = 0", escaped_field));
+        sql_str.push_str(&format!(" AND {}", M::SOFT_DELETE_KIND.active_condition(&escaped_field)));
     }
 
     // 执行查询
-    let mut query = sqlx::query_as::<DB, M>(&sql_str);
-    for id in &ids_vec {
-        query = query.bind(id.clone());
+    let mut query = sqlx::query(&sql_str);
+    for id in ids_vec {
+        query = id.bind_to(query);
     }
-    query
+    let rows = query
         .fetch_all(executor)
         .await
-        .map_err(|e| SqlxPlusError::DatabaseError(e))
+        .map_err(SqlxPlusError::DatabaseError)?;
+    rows.iter()
+        .map(|row| M::from_row(row).map_err(SqlxPlusError::DatabaseError))
+        .collect()
 }
 
 // 注意：find_by_ids_mysql, find_by_ids_postgres, find_by_ids_sqlite 等兼容层函数已移除
@@ -413,19 +528,19 @@ where
     bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
 {
     // 使用 DatabaseInfo trait 获取数据库特定信息
     let driver = DB::get_driver();
-    let escaped_table = DB::escape_identifier(M::TABLE);
 
     // 构建查询构建器
     let mut query_builder = builder;
-    query_builder = query_builder.with_base_sql(format!("SELECT * FROM {}", escaped_table));
+    let base_sql = query_builder.select_from_sql(driver, M::TABLE, M::COLUMNS);
+    query_builder = query_builder.with_base_sql(base_sql);
 
-    // 如果指定了逻辑删除字段，自动添加过滤条件（只查询未删除的记录）
-    if let Some(soft_delete_field) = M::SOFT_DELETE_FIELD {
-        query_builder = query_builder.and_eq(soft_delete_field, 0);
-    }
+    // 如果指定了逻辑删除字段，按 with_trashed / only_trashed 范围添加过滤条件（默认只查询未删除的记录）
+    query_builder = crate::soft_delete::apply_trashed_scope::<M>(query_builder);
 
     // 自动添加 LIMIT 1
     let mut sql = query_builder.into_sql(driver);
@@ -438,7 +553,7 @@ where
     query
         .fetch_optional(executor)
         .await
-        .map_err(|e| SqlxPlusError::DatabaseError(e))
+        .map_err(SqlxPlusError::DatabaseError)
 }
 
 // 注意：find_one_mysql, find_one_postgres, find_one_sqlite 等兼容层函数已移除
@@ -500,33 +615,286 @@ where
     bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+{
+    find_all_as::<DB, M, M, E>(executor, builder).await
+}
+
+/// 根据查询构建器查找所有记录，并映射到自定义的 `FromRow` 类型（泛型版本）
+///
+/// 表名和逻辑删除条件取自模型 `M`，结果映射为 `T`（通常是只包含部分字段的 DTO）。
+/// 未通过 `QueryBuilder::select` 指定列时使用 `M::COLUMNS`，`T` 会忽略多余的列。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct UserBrief {
+///     id: i64,
+///     username: String,
+/// }
+///
+/// let builder = QueryBuilder::new("").select(&["id", "username"]).and_eq("status", 1);
+/// let briefs = crud::find_all_as::<sqlx::MySql, User, UserBrief, _>(pool, Some(builder)).await?;
+/// ```
+pub async fn find_all_as<'e, 'c: 'e, DB, M, T, E>(
+    executor: E,
+    builder: Option<QueryBuilder>,
+) -> Result<Vec<T>>
+where
+    DB: Database + DatabaseInfo,
+    for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+    M: Model,
+    T: for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+    E: sqlx::Executor<'c, Database = DB> + Send,
+    // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+    // 虽然 sqlx 已经为这些类型实现了这些 trait，但在泛型上下文中需要显式声明
+    // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
 {
     // 使用 DatabaseInfo trait 获取数据库特定信息
     let driver = DB::get_driver();
-    let escaped_table = DB::escape_identifier(M::TABLE);
 
     // 构建查询构建器
-    let mut query_builder =
-        builder.unwrap_or_else(|| QueryBuilder::new(format!("SELECT * FROM {}", escaped_table)));
-    query_builder = query_builder.with_base_sql(format!("SELECT * FROM {}", escaped_table));
+    let mut query_builder = builder.unwrap_or_else(|| QueryBuilder::new(""));
+    let base_sql = query_builder.select_from_sql(driver, M::TABLE, M::COLUMNS);
+    query_builder = query_builder.with_base_sql(base_sql);
 
-    // 如果指定了逻辑删除字段，自动添加过滤条件（只查询未删除的记录）
-    if let Some(soft_delete_field) = M::SOFT_DELETE_FIELD {
-        query_builder = query_builder.and_eq(soft_delete_field, 0);
-    }
+    // 如果指定了逻辑删除字段，按 with_trashed / only_trashed 范围添加过滤条件（默认只查询未删除的记录）
+    query_builder = crate::soft_delete::apply_trashed_scope::<M>(query_builder);
 
     // 限制最多 1000 条
     let mut sql = query_builder.into_sql(driver);
     sql.push_str(" LIMIT 1000");
 
     let binds = query_builder.binds().to_vec();
-    let query = sqlx::query_as::<DB, M>(&sql);
+    let query = sqlx::query_as::<DB, T>(&sql);
     let query = apply_binds_to_query_as_generic(query, &binds);
 
     query
         .fetch_all(executor)
         .await
-        .map_err(|e| SqlxPlusError::DatabaseError(e))
+        .map_err(SqlxPlusError::DatabaseError)
+}
+
+/// 流式查询返回的记录流
+pub type RecordStream<'e, T> = futures_util::stream::BoxStream<'e, Result<T>>;
+
+/// 流式查询时生产者最多预取的记录数
+const STREAM_BUFFER: usize = 16;
+
+/// 根据查询构建器流式读取记录（泛型版本）
+///
+/// 与 `find_all` 不同，结果不会一次性收集到 `Vec` 中，也没有 1000 条的上限，
+/// 适合导出等需要遍历大量记录的场景。绑定值和逻辑删除过滤与 `find_all` 一致，
+/// 需要限制条数时使用 `QueryBuilder::limit`。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// use futures_util::TryStreamExt;
+///
+/// let builder = QueryBuilder::new("").and_eq("status", 1).order_by("id", true);
+/// let mut users = crud::stream::<sqlx::MySql, User, _>(pool, Some(builder));
+/// while let Some(user) = users.try_next().await? {
+///     // ...
+/// }
+/// ```
+pub fn stream<'e, 'c: 'e, DB, M, E>(executor: E, builder: Option<QueryBuilder>) -> RecordStream<'e, M>
+where
+    DB: Database + DatabaseInfo,
+    for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+    M: Model + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin + 'e,
+    E: sqlx::Executor<'c, Database = DB> + Send + 'e,
+    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+{
+    let driver = DB::get_driver();
+
+    let mut query_builder = builder.unwrap_or_else(|| QueryBuilder::new(""));
+    let base_sql = query_builder.select_from_sql(driver, M::TABLE, M::COLUMNS);
+    query_builder = query_builder.with_base_sql(base_sql);
+    query_builder = crate::soft_delete::apply_trashed_scope::<M>(query_builder);
+
+    let sql = query_builder.into_sql(driver);
+    let binds = query_builder.binds().to_vec();
+    fetch_stream::<DB, M, E>(executor, sql, binds)
+}
+
+/// 分批流式读取记录：每次产出最多 `chunk_size` 条记录（最后一批可能不足）
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// use futures_util::TryStreamExt;
+///
+/// let mut batches = crud::stream_chunks::<sqlx::MySql, User, _>(pool, None, 500);
+/// while let Some(batch) = batches.try_next().await? {
+///     writer.write_batch(&batch)?;
+/// }
+/// ```
+pub fn stream_chunks<'e, 'c: 'e, DB, M, E>(
+    executor: E,
+    builder: Option<QueryBuilder>,
+    chunk_size: usize,
+) -> RecordStream<'e, Vec<M>>
+where
+    DB: Database + DatabaseInfo,
+    for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+    M: Model + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin + 'e,
+    E: sqlx::Executor<'c, Database = DB> + Send + 'e,
+    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+{
+    use futures_util::StreamExt;
+
+    Box::pin(
+        stream::<DB, M, E>(executor, builder)
+            .chunks(chunk_size.max(1))
+            .map(|batch| batch.into_iter().collect::<Result<Vec<_>>>()),
+    )
+}
+
+/// 执行 SQL 并以流的形式返回映射后的记录
+///
+/// sqlx 的 `fetch` 需要借用 SQL 字符串，因此由生产者 future 持有 SQL 并驱动查询，
+/// 通过有界通道逐条转发给调用方；两者合并为同一个流，不需要额外的运行时任务。
+/// 遇到错误时转发该错误并结束。
+pub(crate) fn fetch_stream<'e, 'c: 'e, DB, T, E>(
+    executor: E,
+    sql: String,
+    binds: Vec<BindValue>,
+) -> RecordStream<'e, T>
+where
+    DB: Database + DatabaseInfo,
+    for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+    T: for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin + 'e,
+    E: sqlx::Executor<'c, Database = DB> + Send + 'e,
+    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+{
+    use futures_util::StreamExt;
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(STREAM_BUFFER);
+    let producer = async move {
+        let query = sqlx::query_as::<DB, T>(&sql);
+        let query = apply_binds_to_query_as_generic(query, &binds);
+        let mut rows = query.fetch(executor);
+        while let Some(row) = rows.next().await {
+            let failed = row.is_err();
+            let row = row.map_err(SqlxPlusError::DatabaseError);
+            if sender.send(row).await.is_err() || failed {
+                break;
+            }
+        }
+    };
+
+    let records = futures_util::stream::poll_fn(move |cx| receiver.poll_recv(cx));
+    let producer = futures_util::stream::once(producer)
+        .filter_map(|()| futures_util::future::ready(None::<Result<T>>));
+    Box::pin(futures_util::stream::select(records, producer))
+}
+
+/// 批量加载关联记录（泛型版本）
+///
+/// 根据 `M` 上 `#[relation(...)]` 声明的关系，收集所有父记录的关联键，只发出一条
+/// `SELECT ... FROM r WHERE remote_key IN (...)` 查询，并按关联键分组返回。
+/// 关联模型有逻辑删除字段时自动过滤已删除的记录；父记录没有可用的关联键时不会访问数据库。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// let users = User::find_all(pool.mysql_pool(), None).await?;
+/// let orders = crud::load_related::<sqlx::MySql, User, Order, _>(&users, pool.mysql_pool()).await?;
+/// for user in &users {
+///     println!("{:?}: {} orders", user.id, orders.get(user).len());
+/// }
+/// ```
+pub async fn load_related<'e, 'c: 'e, DB, M, R, E>(
+    parents: &[M],
+    executor: E,
+) -> Result<RelatedMap<R>>
+where
+    DB: Database + DatabaseInfo,
+    for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+    M: Related<R> + UpdateFields,
+    R: Model + UpdateFields + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+    E: sqlx::Executor<'c, Database = DB> + Send,
+    // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+    // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+{
+    let keys = collect_keys::<M, R>(parents);
+    if keys.is_empty() {
+        return Ok(RelatedMap::group_by_key(Vec::new(), M::REMOTE_KEY));
+    }
+
+    let driver = DB::get_driver();
+    let mut query_builder = related_query::<M, R>(keys);
+    let base_sql = query_builder.select_from_sql(driver, R::TABLE, R::COLUMNS);
+    query_builder = query_builder.with_base_sql(base_sql);
+
+    // 关联模型使用逻辑删除时，只加载未删除的记录
+    query_builder = crate::soft_delete::apply_trashed_scope::<R>(query_builder);
+
+    let sql = query_builder.into_sql(driver);
+    let binds = query_builder.binds().to_vec();
+    let query = sqlx::query_as::<DB, R>(&sql);
+    let query = apply_binds_to_query_as_generic(query, &binds);
+
+    let rows = query
+        .fetch_all(executor)
+        .await
+        .map_err(SqlxPlusError::DatabaseError)?;
+    Ok(RelatedMap::group_by_key(rows, M::REMOTE_KEY))
 }
 
 // 注意：find_all_mysql, find_all_postgres, find_all_sqlite 等兼容层函数已移除
@@ -589,18 +957,18 @@ where
     bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     usize: sqlx::ColumnIndex<DB::Row>,
 {
     // 使用 DatabaseInfo trait 获取数据库特定信息
     let driver = DB::get_driver();
-    let escaped_table = DB::escape_identifier(M::TABLE);
 
     let mut query_builder = builder;
-    query_builder = query_builder.with_base_sql(format!("SELECT * FROM {}", escaped_table));
+    let base_sql = query_builder.select_from_sql(driver, M::TABLE, M::COLUMNS);
+    query_builder = query_builder.with_base_sql(base_sql);
 
-    if let Some(soft_delete_field) = M::SOFT_DELETE_FIELD {
-        query_builder = query_builder.and_eq(soft_delete_field, 0);
-    }
+    query_builder = crate::soft_delete::apply_trashed_scope::<M>(query_builder);
 
     let binds = query_builder.binds().to_vec();
     let count_sql = query_builder.into_count_sql(driver);
@@ -657,7 +1025,7 @@ where
 /// ```
 pub async fn paginate<'e, 'c: 'e, DB, M, E>(
     executor: E,
-    mut builder: QueryBuilder,
+    builder: QueryBuilder,
     page: u32,
     size: u32,
 ) -> Result<Page<M>>
@@ -665,7 +1033,7 @@ where
     DB: Database + DatabaseInfo,
     for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
     M: Model + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
-    E: sqlx::Executor<'c, Database = DB> + Send + Clone,
+    E: sqlx::Executor<'c, Database = DB> + crate::database_type::DatabaseType<DB = DB> + Send + Clone,
     // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
     // i64 还需要实现 Decode<DB>（用于从查询结果中读取）
     // usize 需要实现 ColumnIndex<DB::Row>（用于通过索引访问列）
@@ -680,22 +1048,16 @@ where
     bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
     usize: sqlx::ColumnIndex<DB::Row>,
 {
-    let offset = ((page as u64).saturating_sub(1) * size as u64) as u32;
-    let driver = DB::get_driver();
-    let escaped_table = DB::escape_identifier(M::TABLE);
+    let (count_sql, data_sql, binds) = paginate_sql::<DB, M>(builder, page, size);
 
-    builder = builder.with_base_sql(format!("SELECT * FROM {}", escaped_table));
-
-    if let Some(soft_delete_field) = M::SOFT_DELETE_FIELD {
-        builder = builder.and_eq(soft_delete_field, 0);
-    }
-
-    let binds = builder.binds().to_vec();
+    // 读写分离时 count 查询和分页查询固定在同一个从库，避免总数与数据来自不同的复制进度
+    let executor = executor.pin_replica();
 
     // 执行 count 查询获取总数
-    let count_sql = builder.clone().into_count_sql(driver);
     let count_query = sqlx::query::<DB>(&count_sql);
     let count_query = apply_binds_to_query_generic(count_query, &binds);
     let executor_clone = executor.clone();
@@ -703,24 +1065,105 @@ where
     let total: i64 = row.get(0usize);
 
     // 执行分页查询获取数据
-    let data_sql = builder.clone().into_paginated_sql(driver, size, offset);
     let query = sqlx::query_as::<DB, M>(&data_sql);
     let query = apply_binds_to_query_as_generic(query, &binds);
     let items = query
         .fetch_all(executor)
         .await
-        .map_err(|e| SqlxPlusError::DatabaseError(e))?;
+        .map_err(SqlxPlusError::DatabaseError)?;
 
     Ok(Page::new(items, total, page, size))
 }
 
+/// 分页查询（单个连接版本）
+///
+/// 与 `paginate` 相同，但在同一个连接（如事务内部的连接）上依次执行 count 查询和分页查询，
+/// 因此不要求执行器实现 `Clone`。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// let page = crud::paginate_on_connection::<sqlx::MySql, User>(tx.as_mysql_executor(), builder, 1, 10).await?;
+/// ```
+pub async fn paginate_on_connection<DB, M>(
+    conn: &mut DB::Connection,
+    builder: QueryBuilder,
+    page: u32,
+    size: u32,
+) -> Result<Page<M>>
+where
+    DB: Database + DatabaseInfo,
+    for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    M: Model + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+    i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    usize: sqlx::ColumnIndex<DB::Row>,
+{
+    let (count_sql, data_sql, binds) = paginate_sql::<DB, M>(builder, page, size);
+
+    let count_query = sqlx::query::<DB>(&count_sql);
+    let count_query = apply_binds_to_query_generic(count_query, &binds);
+    let row = count_query.fetch_one(&mut *conn).await?;
+    let total: i64 = row.get(0usize);
+
+    let query = sqlx::query_as::<DB, M>(&data_sql);
+    let query = apply_binds_to_query_as_generic(query, &binds);
+    let items = query
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(SqlxPlusError::DatabaseError)?;
+
+    Ok(Page::new(items, total, page, size))
+}
+
+/// 生成分页查询的 count SQL、数据 SQL 和绑定值（已应用逻辑删除范围）
+fn paginate_sql<DB, M>(mut builder: QueryBuilder, page: u32, size: u32) -> (String, String, Vec<BindValue>)
+where
+    DB: DatabaseInfo,
+    M: Model,
+{
+    let offset = ((page as u64).saturating_sub(1) * size as u64) as u32;
+    let driver = DB::get_driver();
+
+    let base_sql = builder.select_from_sql(driver, M::TABLE, M::COLUMNS);
+    builder = builder.with_base_sql(base_sql);
+
+    builder = crate::soft_delete::apply_trashed_scope::<M>(builder);
+
+    let binds = builder.binds().to_vec();
+    let count_sql = builder.clone().into_count_sql(driver);
+    let data_sql = builder.into_paginated_sql(driver, size, offset);
+    (count_sql, data_sql, binds)
+}
+
 /// 游标分页查询（泛型版本）
 ///
 /// 这是统一的泛型实现，支持所有实现了 `DatabaseInfo` 的数据库类型。
+///
+/// 按 `builder` 中的排序字段进行键集分页（未包含主键时自动追加主键作为排序字段），
+/// 游标编码了分页边界行在全部排序字段上的值。排序字段的值不能为 `NULL`，
+/// 且类型需要是整数、浮点、布尔、字符串、二进制或时间类型之一。
+///
+/// # 参数
+///
+/// * `executor` - 数据库执行器（连接池或事务）
+/// * `builder` - 查询构建器
+/// * `cursor` - 上一次返回的 `next_cursor` / `prev_cursor`，`None` 表示第一页
+/// * `size` - 每页大小
 pub async fn paginate_cursor<'e, 'c: 'e, DB, M, E>(
     executor: E,
     mut builder: QueryBuilder,
-    cursor: Option<i64>,
+    cursor: Option<&str>,
     size: u32,
 ) -> Result<CursorPage<M>>
 where
@@ -728,35 +1171,59 @@ where
     for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
     M: Model + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
     E: sqlx::Executor<'c, Database = DB> + Send,
-    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
     i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
     i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
-    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
-    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+    f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+    f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+    bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
     Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>:
+        sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
+    chrono::NaiveDateTime:
+        sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB> + for<'r> sqlx::Decode<'r, DB>,
     for<'a> &'a str: sqlx::ColumnIndex<DB::Row>,
 {
+    use crate::cursor::{Cursor, CursorDirection};
+
     let driver = DB::get_driver();
-    let escaped_table = DB::escape_identifier(M::TABLE);
 
-    builder = builder.with_base_sql(format!("SELECT * FROM {}", escaped_table));
+    let base_sql = builder.select_from_sql(driver, M::TABLE, M::COLUMNS);
+    builder = builder.with_base_sql(base_sql);
 
-    if let Some(soft_delete_field) = M::SOFT_DELETE_FIELD {
-        builder = builder.and_eq(soft_delete_field, 0);
+    builder = crate::soft_delete::apply_trashed_scope::<M>(builder);
+
+    // 排序字段必须唯一确定记录的位置，缺少主键时追加主键
+    let pk_columns: Vec<String> = crate::primary_key::pk_columns::<M>()
+        .map(|column| builder.qualify_column(M::TABLE, column))
+        .collect();
+    let keys = crate::cursor::sort_keys(builder.order_by_fields(), pk_columns);
+
+    let cursor = cursor.map(Cursor::decode).transpose()?;
+    let direction = cursor
+        .as_ref()
+        .map(|cursor| cursor.direction)
+        .unwrap_or(CursorDirection::Next);
+    let has_cursor = cursor.is_some();
+    if let Some(cursor) = cursor {
+        builder = crate::cursor::apply_keyset(builder, driver, &keys, cursor)?;
     }
 
-    // 默认游标从 0 开始
-    let c = cursor.unwrap_or(0);
-    // 默认行为：假设使用主键并按升序查询下一页
-    // 如果用户需要更复杂的游标逻辑，应传入 None 并在 builder 中自行追加条件
-    builder = builder.and_gt(M::PK, c);
+    // 向前翻页时反转排序方向，取回后再恢复原顺序
+    let query_order = match direction {
+        CursorDirection::Next => keys.clone(),
+        CursorDirection::Prev => keys
+            .iter()
+            .map(|(field, ascending)| (field.clone(), !ascending))
+            .collect(),
+    };
+    builder = builder.with_order_by(query_order);
 
     let binds = builder.binds().to_vec();
 
-    // 核心逻辑：查询 size + 1 条，以判断是否有下一页
+    // 核心逻辑：查询 size + 1 条，以判断翻页方向上是否还有更多记录
     let fetch_size = size + 1;
     let data_sql = builder.into_paginated_sql(driver, fetch_size, 0);
 
@@ -765,31 +1232,109 @@ where
     let mut rows = query
         .fetch_all(executor)
         .await
-        .map_err(|e| SqlxPlusError::DatabaseError(e))?;
+        .map_err(SqlxPlusError::DatabaseError)?;
 
-    let has_next = rows.len() as u32 > size;
-    if has_next {
+    let has_more = rows.len() as u32 > size;
+    if has_more {
         rows.pop(); // 移除多余的第 size + 1 条
     }
-
-    let mut next_cursor = None;
-    if let Some(last_row) = rows.last() {
-        use sqlx::Row;
-        // 尝试提取最后一行的主键值作为下一次游标
-        // 先尝试按 i64 提取，如果不成功再尝试按 i32 提取
-        if let Ok(id) = last_row.try_get::<i64, &str>(M::PK) {
-            next_cursor = Some(id);
-        } else if let Ok(id) = last_row.try_get::<i32, &str>(M::PK) {
-            next_cursor = Some(id as i64);
-        }
+    if direction == CursorDirection::Prev {
+        rows.reverse();
     }
 
+    let (has_next, has_prev) = match direction {
+        CursorDirection::Next => (has_more, has_cursor),
+        CursorDirection::Prev => (true, has_more),
+    };
+
+    let boundary_cursor = |row: &DB::Row, direction: CursorDirection| -> Result<String> {
+        let values = keys
+            .iter()
+            .map(|(field, _)| decode_sort_key::<DB>(row, crate::cursor::column_name(field)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Cursor::new(direction, values).encode())
+    };
+    let next_cursor = match rows.last() {
+        Some(row) if has_next => Some(boundary_cursor(row, CursorDirection::Next)?),
+        _ => None,
+    };
+    let prev_cursor = match rows.first() {
+        Some(row) if has_prev => Some(boundary_cursor(row, CursorDirection::Prev)?),
+        _ => None,
+    };
+
     let mut items = Vec::with_capacity(rows.len());
     for row in rows {
-        items.push(M::from_row(&row).map_err(|e| SqlxPlusError::DatabaseError(e))?);
+        items.push(M::from_row(&row).map_err(SqlxPlusError::DatabaseError)?);
     }
 
-    Ok(CursorPage::new(items, has_next, next_cursor))
+    Ok(CursorPage::new(
+        items,
+        has_next,
+        has_prev,
+        next_cursor,
+        prev_cursor,
+    ))
+}
+
+/// 读取结果行中排序字段的值，依次尝试各个支持的类型
+fn decode_sort_key<DB>(row: &DB::Row, column: &str) -> Result<BindValue>
+where
+    DB: Database,
+    String: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    i32: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    i16: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    f64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    f32: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    bool: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    Vec<u8>: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+    for<'a> &'a str: sqlx::ColumnIndex<DB::Row>,
+{
+    use sqlx::ValueRef;
+
+    if row.try_get_raw(column)?.is_null() {
+        return Err(SqlxPlusError::InvalidCursor(format!(
+            "sort column '{}' must not be NULL",
+            column
+        )));
+    }
+    if let Ok(v) = row.try_get::<i64, _>(column) {
+        return Ok(BindValue::Int64(v));
+    }
+    if let Ok(v) = row.try_get::<i32, _>(column) {
+        return Ok(BindValue::Int32(v));
+    }
+    if let Ok(v) = row.try_get::<i16, _>(column) {
+        return Ok(BindValue::Int16(v));
+    }
+    if let Ok(v) = row.try_get::<bool, _>(column) {
+        return Ok(BindValue::Bool(v));
+    }
+    if let Ok(v) = row.try_get::<f64, _>(column) {
+        return Ok(BindValue::Float64(v));
+    }
+    if let Ok(v) = row.try_get::<f32, _>(column) {
+        return Ok(BindValue::Float32(v));
+    }
+    if let Ok(v) = row.try_get::<String, _>(column) {
+        return Ok(BindValue::String(v));
+    }
+    if let Ok(v) = row.try_get::<chrono::NaiveDateTime, _>(column) {
+        return Ok(BindValue::NaiveDateTime(v));
+    }
+    if let Ok(v) = row.try_get::<chrono::DateTime<chrono::Utc>, _>(column) {
+        return Ok(BindValue::DateTime(v));
+    }
+    if let Ok(v) = row.try_get::<Vec<u8>, _>(column) {
+        return Ok(BindValue::Bytes(v));
+    }
+    Err(SqlxPlusError::InvalidCursor(format!(
+        "unsupported type for sort column '{}'",
+        column
+    )))
 }
 
 // 注意：paginate_mysql, paginate_postgres, paginate_sqlite 等兼容层函数已移除
@@ -809,7 +1354,7 @@ where
 /// # 参数
 ///
 /// * `executor` - 数据库执行器（连接池或事务）
-/// * `id` - 主键 ID 值
+/// * `id` - 主键值（复合主键为元组）
 ///
 /// # 返回值
 ///
@@ -829,31 +1374,29 @@ where
 /// // SQLite
 /// crud::hard_delete_by_id::<sqlx::Sqlite, User, _>(pool, 1).await?;
 /// ```
-pub async fn hard_delete_by_id<'e, 'c: 'e, DB, M, E>(
-    executor: E,
-    id: impl for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB> + Send + Sync,
-) -> Result<()>
+pub async fn hard_delete_by_id<'e, 'c: 'e, DB, M, E>(executor: E, id: M::Id) -> Result<()>
 where
     DB: Database + DatabaseInfo,
     for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
     M: Model,
+    M::Id: BindPrimaryKey<DB>,
     E: sqlx::Executor<'c, Database = DB> + Send,
 {
     let escaped_table = DB::escape_identifier(M::TABLE);
-    let escaped_pk = DB::escape_identifier(M::PK);
-    let placeholder = DB::placeholder(0);
     let sql = format!(
-        "DELETE FROM {} WHERE {} = {}",
-        escaped_table, escaped_pk, placeholder
+        "DELETE FROM {} WHERE {}",
+        escaped_table,
+        pk_condition::<DB, M>(&mut 0)
     );
-    sqlx::query(&sql).bind(id).execute(executor).await?;
+    id.bind_to(sqlx::query(&sql)).execute(executor).await?;
     Ok(())
 }
 
 /// 根据 ID 逻辑删除记录（泛型版本）
 ///
 /// 这是统一的泛型实现，支持所有实现了 `DatabaseInfo` 的数据库类型。
-/// 逻辑删除会将 `SOFT_DELETE_FIELD` 字段设置为 1。
+/// 逻辑删除会将 `SOFT_DELETE_FIELD` 字段设置为 `SOFT_DELETE_KIND` 对应的已删除值
+/// （默认 1，删除时间语义下为当前时间），并刷新更新时间字段。
 ///
 /// # 类型参数
 ///
@@ -864,7 +1407,7 @@ where
 /// # 参数
 ///
 /// * `executor` - 数据库执行器（连接池或事务）
-/// * `id` - 主键 ID 值
+/// * `id` - 主键值（复合主键为元组）
 ///
 /// # 返回值
 ///
@@ -884,55 +1427,108 @@ where
 /// // SQLite
 /// crud::soft_delete_by_id::<sqlx::Sqlite, User, _>(pool, 1).await?;
 /// ```
-pub async fn soft_delete_by_id<'e, 'c: 'e, DB, M, E>(
-    executor: E,
-    id: impl for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB> + Send + Sync,
-) -> Result<()>
+pub async fn soft_delete_by_id<'e, 'c: 'e, DB, M, E>(executor: E, id: M::Id) -> Result<()>
 where
     DB: Database + DatabaseInfo,
     for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
     M: Model,
+    M::Id: BindPrimaryKey<DB>,
     E: sqlx::Executor<'c, Database = DB> + Send,
     i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
 {
-    let soft_delete_field = M::SOFT_DELETE_FIELD.ok_or_else(|| {
-        SqlxPlusError::DatabaseError(sqlx::Error::Configuration(
-            format!(
-                "Model {} does not have SOFT_DELETE_FIELD defined",
-                std::any::type_name::<M>()
-            )
-            .into(),
-        ))
-    })?;
-
     let escaped_table = DB::escape_identifier(M::TABLE);
-    let escaped_pk = DB::escape_identifier(M::PK);
-    let escaped_field = DB::escape_identifier(soft_delete_field);
-
-    if let Some(updated_at_field) = M::UPDATED_AT_FIELD {
-        let escaped_updated = DB::escape_identifier(updated_at_field);
-        let placeholder_updated = DB::placeholder(0);
-        let placeholder_id = DB::placeholder(1);
-        let sql = format!(
-            "UPDATE {} SET {} = 1, {} = {} WHERE {} = {}",
-            escaped_table, escaped_field, escaped_updated, placeholder_updated, escaped_pk, placeholder_id
-        );
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as i64;
-        sqlx::query(&sql).bind(now).bind(id).execute(executor).await?;
-    } else {
-        let placeholder = DB::placeholder(0);
-        let sql = format!(
-            "UPDATE {} SET {} = 1 WHERE {} = {}",
-            escaped_table, escaped_field, escaped_pk, placeholder
-        );
-        sqlx::query(&sql).bind(id).execute(executor).await?;
+
+    // SET 子句：逻辑删除字段按 SOFT_DELETE_KIND 取值，同时刷新更新时间字段
+    let mut placeholder_index = 0;
+    let (set_clause, timestamps) =
+        crate::soft_delete::deleted_set_clause::<DB, M>(&mut placeholder_index)
+            .ok_or_else(missing_soft_delete_field::<M>)?;
+    let sql = format!(
+        "UPDATE {} SET {} WHERE {}",
+        escaped_table,
+        set_clause,
+        pk_condition::<DB, M>(&mut placeholder_index)
+    );
+    let now = crate::timestamp::now();
+    let mut query = sqlx::query(&sql);
+    for kind in timestamps {
+        crate::bind_timestamp!(query, kind, now);
+    }
+    id.bind_to(query).execute(executor).await?;
+    Ok(())
+}
+
+/// 根据 ID 恢复逻辑删除的记录（泛型版本）
+///
+/// 将 `SOFT_DELETE_FIELD` 字段恢复为 `SOFT_DELETE_KIND` 对应的未删除值
+/// （默认 0，删除时间语义下为 `NULL`），并刷新更新时间字段。
+///
+/// # 返回值
+///
+/// 恢复成功返回 `Ok(())`；如果模型未定义 `SOFT_DELETE_FIELD`，返回错误
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// use sqlxplus::crud;
+///
+/// crud::restore_by_id::<sqlx::MySql, User, _>(pool, 1).await?;
+/// ```
+pub async fn restore_by_id<'e, 'c: 'e, DB, M, E>(executor: E, id: M::Id) -> Result<()>
+where
+    DB: Database + DatabaseInfo,
+    for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+    M: Model,
+    M::Id: BindPrimaryKey<DB>,
+    E: sqlx::Executor<'c, Database = DB> + Send,
+    i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+{
+    let soft_delete_field = M::SOFT_DELETE_FIELD.ok_or_else(missing_soft_delete_field::<M>)?;
+
+    let mut placeholder_index = 0;
+    let mut set_parts = vec![format!(
+        "{} = {}",
+        DB::escape_identifier(soft_delete_field),
+        M::SOFT_DELETE_KIND.active_value()
+    )];
+    let updated_at = M::UPDATED_AT_FIELD.filter(|f| *f != soft_delete_field);
+    if let Some(updated_at_field) = updated_at {
+        set_parts.push(format!(
+            "{} = {}",
+            DB::escape_identifier(updated_at_field),
+            crate::timestamp::placeholder::<DB>(M::UPDATED_AT_KIND, &mut placeholder_index)
+        ));
     }
+    let sql = format!(
+        "UPDATE {} SET {} WHERE {}",
+        DB::escape_identifier(M::TABLE),
+        set_parts.join(", "),
+        pk_condition::<DB, M>(&mut placeholder_index)
+    );
+    let now = crate::timestamp::now();
+    let mut query = sqlx::query(&sql);
+    if updated_at.is_some() {
+        crate::bind_timestamp!(query, M::UPDATED_AT_KIND, now);
+    }
+    id.bind_to(query).execute(executor).await?;
     Ok(())
 }
 
+/// 模型未定义逻辑删除字段时的错误
+fn missing_soft_delete_field<M: Model>() -> SqlxPlusError {
+    SqlxPlusError::DatabaseError(sqlx::Error::Configuration(
+        format!(
+            "Model {} does not have SOFT_DELETE_FIELD defined",
+            std::any::type_name::<M>()
+        )
+        .into(),
+    ))
+}
+
 /// 根据 ID 删除记录（泛型版本）
 ///
 /// 这是统一的泛型实现，支持所有实现了 `DatabaseInfo` 的数据库类型。
@@ -947,7 +1543,7 @@ where
 /// # 参数
 ///
 /// * `executor` - 数据库执行器（连接池或事务）
-/// * `id` - 主键 ID 值
+/// * `id` - 主键值（复合主键为元组）
 ///
 /// # 返回值
 ///
@@ -967,16 +1563,16 @@ where
 /// // SQLite
 /// crud::delete_by_id::<sqlx::Sqlite, User, _>(pool, 1).await?;
 /// ```
-pub async fn delete_by_id<'e, 'c: 'e, DB, M, E>(
-    executor: E,
-    id: impl for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB> + Send + Sync,
-) -> Result<()>
+pub async fn delete_by_id<'e, 'c: 'e, DB, M, E>(executor: E, id: M::Id) -> Result<()>
 where
     DB: Database + DatabaseInfo,
     for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
     M: Model,
+    M::Id: BindPrimaryKey<DB>,
     E: sqlx::Executor<'c, Database = DB> + Send,
     i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+    chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
 {
     if M::SOFT_DELETE_FIELD.is_some() {
         soft_delete_by_id::<DB, M, E>(executor, id).await
@@ -984,3 +1580,65 @@ where
         hard_delete_by_id::<DB, M, E>(executor, id).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db_pool::DbDriver;
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_mysql_build_insert_sql() {
+        let sql = build_insert_sql::<sqlx::MySql>("user", &["name", "age"], 2, None);
+        assert_eq!(
+            sql,
+            "INSERT INTO `user` (`name`, `age`) VALUES (?, ?), (?, ?)"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres_build_insert_sql() {
+        let sql = build_insert_sql::<sqlx::Postgres>("user", &["name", "age"], 2, Some("id"));
+        assert_eq!(
+            sql,
+            r#"INSERT INTO "user" ("name", "age") VALUES ($1, $2), ($3, $4) RETURNING "id""#
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_build_insert_sql() {
+        let sql = build_insert_sql::<sqlx::Sqlite>("user", &["name"], 3, Some("id"));
+        assert_eq!(
+            sql,
+            r#"INSERT INTO "user" ("name") VALUES (?), (?), (?) RETURNING "id""#
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_build_insert_sql_with_db_now() {
+        let sql = build_insert_sql_with_db_now::<sqlx::Postgres>(
+            "user",
+            &["name", "created_at", "updated_at"],
+            &["created_at"],
+            2,
+            None,
+        );
+        assert_eq!(
+            sql,
+            r#"INSERT INTO "user" ("name", "created_at", "updated_at") VALUES ($1, CURRENT_TIMESTAMP, $2), ($3, CURRENT_TIMESTAMP, $4)"#
+        );
+    }
+
+    #[test]
+    fn test_insert_chunk_size() {
+        assert_eq!(insert_chunk_size(DbDriver::MySql, 5), 13107);
+        assert_eq!(insert_chunk_size(DbDriver::Postgres, 65535), 1);
+        assert_eq!(insert_chunk_size(DbDriver::Sqlite, 2), 499);
+        // 列数超过上限时仍至少插入一行
+        assert_eq!(insert_chunk_size(DbDriver::Sqlite, 1000), 1);
+        assert_eq!(insert_chunk_size(DbDriver::MySql, 0), 65535);
+    }
+}
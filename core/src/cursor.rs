@@ -0,0 +1,288 @@
+//! 键集（keyset）游标分页
+//!
+//! 游标记录分页边界行在全部排序字段上的取值，翻页时通过比较排序字段定位，
+//! 而不是使用 `OFFSET`，因此深翻页的性能不会退化。
+//!
+//! 游标对调用方是不透明的字符串（URL 安全的 base64 编码 JSON），只应原样传回 `paginate_cursor`。
+
+use crate::builder::query_builder::{BindValue, QueryBuilder};
+use crate::db_pool::DbDriver;
+use crate::error::{Result, SqlxPlusError};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::{json, Map, Value};
+
+const NAIVE_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// 翻页方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorDirection {
+    /// 下一页：取边界行之后的记录
+    Next,
+    /// 上一页：取边界行之前的记录
+    Prev,
+}
+
+/// 解码后的游标：翻页方向以及边界行的排序字段取值（与排序字段一一对应）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    pub direction: CursorDirection,
+    pub values: Vec<BindValue>,
+}
+
+impl Cursor {
+    pub fn new(direction: CursorDirection, values: Vec<BindValue>) -> Self {
+        Self { direction, values }
+    }
+
+    /// 编码为不透明的游标字符串
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            CursorDirection::Next => "next",
+            CursorDirection::Prev => "prev",
+        };
+        let values: Vec<Value> = self.values.iter().map(value_to_json).collect();
+        let payload = json!({ "dir": direction, "keys": values });
+        URL_SAFE_NO_PAD.encode(payload.to_string())
+    }
+
+    /// 解码游标字符串
+    pub fn decode(cursor: &str) -> Result<Self> {
+        let invalid = |reason: &str| SqlxPlusError::InvalidCursor(reason.to_string());
+        let bytes = URL_SAFE_NO_PAD
+            .decode(cursor.trim())
+            .map_err(|_| invalid("not valid base64"))?;
+        let payload: Value =
+            serde_json::from_slice(&bytes).map_err(|_| invalid("not valid JSON"))?;
+        let direction = match payload.get("dir").and_then(Value::as_str) {
+            Some("next") => CursorDirection::Next,
+            Some("prev") => CursorDirection::Prev,
+            _ => return Err(invalid("missing direction")),
+        };
+        let values = payload
+            .get("keys")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid("missing sort keys"))?
+            .iter()
+            .map(value_from_json)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid("unsupported sort key value"))?;
+        Ok(Self { direction, values })
+    }
+}
+
+/// 游标分页的排序字段：builder 中的排序字段，缺少主键时追加主键（方向同最后一个排序字段）
+///
+/// 排序字段必须唯一确定每一行的位置，否则翻页时可能遗漏或重复记录；未指定排序时按主键升序
+pub(crate) fn sort_keys(
+    order_by: &[(String, bool)],
+    pk_columns: impl IntoIterator<Item = String>,
+) -> Vec<(String, bool)> {
+    let mut keys = order_by.to_vec();
+    let ascending = keys.last().map(|(_, ascending)| *ascending).unwrap_or(true);
+    for pk in pk_columns {
+        if !keys.iter().any(|(field, _)| column_name(field) == column_name(&pk)) {
+            keys.push((pk, ascending));
+        }
+    }
+    keys
+}
+
+/// 排序字段在结果行中的列名（去掉表名 / 别名限定）
+pub(crate) fn column_name(field: &str) -> &str {
+    field.rsplit('.').next().unwrap_or(field)
+}
+
+/// 添加定位到游标之后（按翻页方向）的键集条件
+///
+/// 所有排序字段方向一致时，PostgreSQL / SQLite 使用行值比较 `(a, b) > (?, ?)`；
+/// MySQL 的优化器无法对行值比较使用范围索引，以及方向混合时，展开为
+/// `(a > ?) OR (a = ? AND b > ?)`
+pub(crate) fn apply_keyset(
+    builder: QueryBuilder,
+    driver: DbDriver,
+    keys: &[(String, bool)],
+    cursor: Cursor,
+) -> Result<QueryBuilder> {
+    if cursor.values.len() != keys.len() {
+        return Err(SqlxPlusError::InvalidCursor(format!(
+            "expected {} sort keys, got {}",
+            keys.len(),
+            cursor.values.len()
+        )));
+    }
+    if cursor.values.contains(&BindValue::Null) {
+        return Err(SqlxPlusError::InvalidCursor(
+            "sort keys must not be NULL".to_string(),
+        ));
+    }
+
+    // 每个字段是否取"更大"的值：升序的下一页、降序的上一页
+    let greater: Vec<bool> = keys
+        .iter()
+        .map(|(_, ascending)| *ascending == (cursor.direction == CursorDirection::Next))
+        .collect();
+    let fields: Vec<&str> = keys.iter().map(|(field, _)| field.as_str()).collect();
+    // 用户条件可能包含顶层 OR，需要加括号后再追加 keyset 条件
+    let builder = builder.group_conditions();
+
+    let uniform = greater.iter().all(|g| *g == greater[0]);
+    if uniform && driver != DbDriver::MySql {
+        return if greater[0] {
+            builder.and_row_gt(&fields, cursor.values)
+        } else {
+            builder.and_row_lt(&fields, cursor.values)
+        };
+    }
+
+    let values = cursor.values;
+    Ok(builder.and_group(|group| {
+        (0..fields.len()).fold(group, |group, i| {
+            group.or_group(|branch| {
+                let branch = (0..i).fold(branch, |branch, j| {
+                    branch.and_eq(fields[j], values[j].clone())
+                });
+                if greater[i] {
+                    branch.and_gt(fields[i], values[i].clone())
+                } else {
+                    branch.and_lt(fields[i], values[i].clone())
+                }
+            })
+        })
+    }))
+}
+
+fn value_to_json(value: &BindValue) -> Value {
+    let (tag, value) = match value {
+        BindValue::String(s) => ("s", json!(s)),
+        BindValue::Int64(i) => ("i64", json!(i)),
+        BindValue::Int32(i) => ("i32", json!(i)),
+        BindValue::Int16(i) => ("i16", json!(i)),
+        BindValue::Int8(i) => ("i8", json!(i)),
+        BindValue::UInt64(i) => ("u64", json!(i)),
+        BindValue::UInt32(i) => ("u32", json!(i)),
+        BindValue::UInt16(i) => ("u16", json!(i)),
+        BindValue::UInt8(i) => ("u8", json!(i)),
+        BindValue::Float64(f) => ("f64", json!(f)),
+        BindValue::Float32(f) => ("f32", json!(f)),
+        BindValue::Bool(b) => ("b", json!(b)),
+        BindValue::Bytes(bytes) => ("x", json!(URL_SAFE_NO_PAD.encode(bytes))),
+        BindValue::DateTime(dt) => ("tz", json!(dt.to_rfc3339())),
+        BindValue::NaiveDateTime(dt) => ("dt", json!(dt.format(NAIVE_DATETIME_FORMAT).to_string())),
+        BindValue::Null => return Value::Null,
+    };
+    let mut map = Map::new();
+    map.insert(tag.to_string(), value);
+    Value::Object(map)
+}
+
+fn value_from_json(value: &Value) -> Option<BindValue> {
+    if value.is_null() {
+        return Some(BindValue::Null);
+    }
+    let (tag, value) = value.as_object()?.iter().next()?;
+    let int = || value.as_i64();
+    let uint = || value.as_u64();
+    Some(match tag.as_str() {
+        "s" => BindValue::String(value.as_str()?.to_string()),
+        "i64" => BindValue::Int64(int()?),
+        "i32" => BindValue::Int32(i32::try_from(int()?).ok()?),
+        "i16" => BindValue::Int16(i16::try_from(int()?).ok()?),
+        "i8" => BindValue::Int8(i8::try_from(int()?).ok()?),
+        "u64" => BindValue::UInt64(uint()?),
+        "u32" => BindValue::UInt32(u32::try_from(uint()?).ok()?),
+        "u16" => BindValue::UInt16(u16::try_from(uint()?).ok()?),
+        "u8" => BindValue::UInt8(u8::try_from(uint()?).ok()?),
+        "f64" => BindValue::Float64(value.as_f64()?),
+        "f32" => BindValue::Float32(value.as_f64()? as f32),
+        "b" => BindValue::Bool(value.as_bool()?),
+        "x" => BindValue::Bytes(URL_SAFE_NO_PAD.decode(value.as_str()?).ok()?),
+        "tz" => BindValue::DateTime(
+            DateTime::parse_from_rfc3339(value.as_str()?)
+                .ok()?
+                .with_timezone(&Utc),
+        ),
+        "dt" => BindValue::NaiveDateTime(
+            NaiveDateTime::parse_from_str(value.as_str()?, NAIVE_DATETIME_FORMAT).ok()?,
+        ),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let created_at = NaiveDateTime::parse_from_str("2024-05-01 08:30:00.250", "%Y-%m-%d %H:%M:%S%.f").unwrap();
+        let cursor = Cursor::new(
+            CursorDirection::Prev,
+            vec![
+                BindValue::NaiveDateTime(created_at),
+                BindValue::Int64(42),
+                BindValue::String("a/b".to_string()),
+                BindValue::DateTime(created_at.and_utc()),
+            ],
+        );
+        let encoded = cursor.encode();
+        assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(Cursor::decode(&encoded).unwrap(), cursor);
+
+        assert!(matches!(Cursor::decode("not a cursor"), Err(SqlxPlusError::InvalidCursor(_))));
+    }
+
+    #[test]
+    fn test_sort_keys() {
+        let order = vec![("created_at".to_string(), false)];
+        assert_eq!(
+            sort_keys(&order, ["id".to_string()]),
+            vec![("created_at".to_string(), false), ("id".to_string(), false)]
+        );
+
+        let order = vec![("u.id".to_string(), true)];
+        assert_eq!(sort_keys(&order, ["u.id".to_string()]), order);
+        assert_eq!(sort_keys(&[], ["id".to_string()]), vec![("id".to_string(), true)]);
+    }
+
+    #[test]
+    fn test_apply_keyset() {
+        let keys = vec![("created_at".to_string(), false), ("id".to_string(), false)];
+        let values = vec![BindValue::String("2024-01-01".to_string()), BindValue::Int64(7)];
+
+        let next = Cursor::new(CursorDirection::Next, values.clone());
+        let builder = apply_keyset(QueryBuilder::new("SELECT * FROM post"), DbDriver::Postgres, &keys, next.clone()).unwrap();
+        assert_eq!(
+            builder.into_sql(DbDriver::Postgres),
+            r#"SELECT * FROM post WHERE ("created_at", "id") < ($1, $2)"#
+        );
+
+        let builder = apply_keyset(QueryBuilder::new("SELECT * FROM post"), DbDriver::MySql, &keys, next).unwrap();
+        assert_eq!(
+            builder.into_sql(DbDriver::MySql),
+            "SELECT * FROM post WHERE ((`created_at` < ?) OR (`created_at` = ? AND `id` < ?))"
+        );
+        assert_eq!(builder.binds().len(), 3);
+
+        let mixed = vec![("created_at".to_string(), false), ("id".to_string(), true)];
+        let prev = Cursor::new(CursorDirection::Prev, values);
+        let builder = apply_keyset(QueryBuilder::new("SELECT * FROM post"), DbDriver::Sqlite, &mixed, prev).unwrap();
+        assert_eq!(
+            builder.into_sql(DbDriver::Sqlite),
+            r#"SELECT * FROM post WHERE (("created_at" > ?) OR ("created_at" = ? AND "id" < ?))"#
+        );
+
+        // 用户条件包含顶层 OR 时加括号，keyset 条件作用于所有分支
+        let filtered = QueryBuilder::new("SELECT * FROM post").and_eq("a", 1i64).or_eq("a", 2i64);
+        let next = Cursor::new(CursorDirection::Next, vec![BindValue::Int64(7)]);
+        let builder = apply_keyset(filtered, DbDriver::Postgres, &keys[1..], next).unwrap();
+        assert_eq!(
+            builder.into_sql(DbDriver::Postgres),
+            r#"SELECT * FROM post WHERE ("a" = $1 OR "a" = $2) AND ("id") < ($3)"#
+        );
+
+        let short = Cursor::new(CursorDirection::Next, vec![BindValue::Int64(1)]);
+        assert!(apply_keyset(QueryBuilder::new(""), DbDriver::Sqlite, &keys, short).is_err());
+    }
+}
@@ -46,6 +46,8 @@ pub trait DatabaseInfo: Database {
     /// * MySQL: `` `name` ``
     /// * PostgreSQL/SQLite: `"name"`
     ///
+    /// 带限定符的名称会按 `.` 分段转义，如 `u.id` -> `` `u`.`id` ``
+    ///
     /// # 示例
     ///
     /// ```rust,ignore
@@ -60,6 +62,11 @@ pub trait DatabaseInfo: Database {
     ///
     /// 对应的 `DbDriver` 枚举值
     fn get_driver() -> DbDriver;
+
+    /// 获取执行结果影响的行数
+    ///
+    /// 供 derive 生成的代码在泛型上下文中读取 `rows_affected`（如乐观锁检查）
+    fn rows_affected(result: &Self::QueryResult) -> u64;
 }
 
 // ========== MySQL 实现 ==========
@@ -73,12 +80,16 @@ impl DatabaseInfo for sqlx::MySql {
 
     fn escape_identifier(name: &str) -> String {
         // MySQL 使用反引号转义标识符
-        format!("`{}`", name)
+        crate::utils::escape_identifier(DbDriver::MySql, name)
     }
 
     fn get_driver() -> DbDriver {
         DbDriver::MySql
     }
+
+    fn rows_affected(result: &Self::QueryResult) -> u64 {
+        result.rows_affected()
+    }
 }
 
 // ========== PostgreSQL 实现 ==========
@@ -92,12 +103,16 @@ impl DatabaseInfo for sqlx::Postgres {
 
     fn escape_identifier(name: &str) -> String {
         // PostgreSQL 使用双引号转义标识符
-        format!("\"{}\"", name)
+        crate::utils::escape_identifier(DbDriver::Postgres, name)
     }
 
     fn get_driver() -> DbDriver {
         DbDriver::Postgres
     }
+
+    fn rows_affected(result: &Self::QueryResult) -> u64 {
+        result.rows_affected()
+    }
 }
 
 // ========== SQLite 实现 ==========
@@ -111,12 +126,16 @@ impl DatabaseInfo for sqlx::Sqlite {
 
     fn escape_identifier(name: &str) -> String {
         // SQLite 使用双引号转义标识符
-        format!("\"{}\"", name)
+        crate::utils::escape_identifier(DbDriver::Sqlite, name)
     }
 
     fn get_driver() -> DbDriver {
         DbDriver::Sqlite
     }
+
+    fn rows_affected(result: &Self::QueryResult) -> u64 {
+        result.rows_affected()
+    }
 }
 
 #[cfg(test)]
@@ -142,6 +161,11 @@ mod tests {
             <sqlx::MySql as DatabaseInfo>::escape_identifier("user_name"),
             "`user_name`"
         );
+        assert_eq!(
+            <sqlx::MySql as DatabaseInfo>::escape_identifier("u.id"),
+            "`u`.`id`"
+        );
+        assert_eq!(<sqlx::MySql as DatabaseInfo>::escape_identifier("u.*"), "`u`.*");
     }
 
     #[cfg(feature = "mysql")]
@@ -169,6 +193,10 @@ mod tests {
             <sqlx::Postgres as DatabaseInfo>::escape_identifier("user_name"),
             "\"user_name\""
         );
+        assert_eq!(
+            <sqlx::Postgres as DatabaseInfo>::escape_identifier("public.user"),
+            "\"public\".\"user\""
+        );
     }
 
     #[cfg(feature = "postgres")]
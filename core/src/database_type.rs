@@ -5,6 +5,16 @@
 pub trait DatabaseType {
     /// 关联的数据库类型
     type DB: sqlx::Database + crate::database_info::DatabaseInfo;
+
+    /// 固定后续只读语句的执行位置（如分页的 count 查询和数据查询需要读到同一份数据）
+    ///
+    /// 默认原样返回；读写分离执行器会固定使用一个从库，见 `RoutedPool::pin_replica`
+    fn pin_replica(self) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
 }
 
 // 为 Pool 实现 DatabaseType
@@ -39,6 +49,34 @@ impl DatabaseType for &sqlx::Pool<sqlx::Sqlite> {
     type DB = sqlx::Sqlite;
 }
 
+// 为读写分离执行器实现 DatabaseType
+#[cfg(feature = "mysql")]
+impl DatabaseType for crate::replica::RoutedPool<'_, sqlx::MySql> {
+    type DB = sqlx::MySql;
+
+    fn pin_replica(self) -> Self {
+        crate::replica::RoutedPool::pin_replica(self)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl DatabaseType for crate::replica::RoutedPool<'_, sqlx::Postgres> {
+    type DB = sqlx::Postgres;
+
+    fn pin_replica(self) -> Self {
+        crate::replica::RoutedPool::pin_replica(self)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl DatabaseType for crate::replica::RoutedPool<'_, sqlx::Sqlite> {
+    type DB = sqlx::Sqlite;
+
+    fn pin_replica(self) -> Self {
+        crate::replica::RoutedPool::pin_replica(self)
+    }
+}
+
 // 为 Transaction 实现 DatabaseType
 #[cfg(feature = "mysql")]
 impl<'tx> DatabaseType for &mut sqlx::Transaction<'tx, sqlx::MySql> {
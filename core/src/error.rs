@@ -20,6 +20,15 @@ pub enum SqlxPlusError {
     /// Not implemented error
     #[error("Not implemented: {0}")]
     NotImplemented(String),
+    /// Optimistic lock failure: the row was modified (or removed) since it was read
+    #[error("Stale version: {0}")]
+    StaleVersion(String),
+    /// Malformed or mismatched pagination cursor
+    #[error("Invalid cursor: {0}")]
+    InvalidCursor(String),
+    /// No datasource registered under the given name
+    #[error("Datasource not found: {0}")]
+    DatasourceNotFound(String),
 }
 
 pub type Result<T> = std::result::Result<T, SqlxPlusError>;
\ No newline at end of file
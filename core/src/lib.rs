@@ -1,10 +1,21 @@
+pub mod column;
+pub mod conn;
 pub mod crud;
+pub mod cursor;
 pub mod database_info;
 pub mod database_type;
 pub mod db_pool;
 pub mod error;
 pub mod executor;
 pub mod macros_api;
+pub mod pool_options;
+pub mod primary_key;
+pub mod registry;
+pub mod relation;
+pub mod replica;
+pub mod schema;
+pub mod soft_delete;
+pub mod timestamp;
 pub mod traits;
 pub mod transaction;
 pub mod utils;
@@ -12,16 +23,26 @@ pub mod utils;
 // CRUD Builder 模块
 pub mod builder;
 
+pub use column::Column;
+pub use conn::DbConn;
 pub use database_info::DatabaseInfo;
 pub use database_type::DatabaseType;
-pub use db_pool::{DbDriver, DbPool};
+pub use db_pool::{DbDriver, DbPool, DbPoolBuilder};
+pub use macros_api::{FieldMeta, ModelMeta};
+pub use pool_options::DbPoolOptions;
+pub use primary_key::{BindPrimaryKey, PrimaryKey};
+pub use registry::DbRegistry;
+pub use relation::{Related, RelatedMap, RelationKind, WithRelated};
+pub use replica::{ReplicaPolicy, RoutedPool};
+pub use soft_delete::{SoftDeleteKind, TrashedScope};
+pub use timestamp::TimestampKind;
 pub use traits::{Crud, Model};
 
 // CRUD Builder 导出
 pub use builder::{
-    BindValue, DeleteBuilder, InsertBuilder, QueryBuilder, UpdateBuilder, UpdateFields,
+    BindValue, DeleteBuilder, InsertBuilder, JoinType, QueryBuilder, UpdateBuilder, UpdateFields,
 };
-pub use crud::{CursorPage, Page};
+pub use crud::{CursorPage, Page, RecordStream};
 
 #[cfg(feature = "mysql")]
 pub use transaction::with_mysql_nested_transaction;
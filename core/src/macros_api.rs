@@ -1,15 +1,24 @@
 //! 宏接口模块，供 proc-macro crate 使用
 //!
 //! 这个模块提供了 derive 宏生成代码时需要的辅助函数和类型
-//! 
+
 /// 字段元数据，由 derive(ModelMeta) 生成
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FieldMeta {
+    /// 结构体字段名
     pub name: &'static str,
+    /// 数据库列名（`#[column(name = "...")]`，默认为字段名）
     pub column: &'static str,
+    /// 字段的 Rust 类型（去掉 `Option<>`，如 `String`、`chrono::NaiveDateTime`）
+    pub rust_type: &'static str,
+    /// 字段类型是否为 `Option<T>`
+    pub nullable: bool,
+    /// 是否 `#[skip]`（不对应数据库列）
     pub skip: bool,
     /// 是否创建索引：None 表示不创建索引，Some(name) 表示创建索引并使用指定名称，如果 name 为空则使用默认名称
     pub index: Option<&'static str>,
-    /// 是否创建联合索引：None 表示不创建联合索引，Some((name, order)) 表示加入名为 name 的联合索引，order 指定在联合索引中的顺序（数字越小越靠前）
+    /// 是否创建联合索引：None 表示不创建联合索引，Some((name, order)) 表示加入名为 name 的联合索引，order 指定在联合索引中的顺序（数字越小越靠前），
+    /// `i32::MAX` 表示未指定顺序，按字段在结构体中的位置排列
     pub combine_index: Option<(&'static str, i32)>,
     /// 是否创建唯一索引
     pub unique: bool,
@@ -29,11 +38,12 @@ pub struct FieldMeta {
     pub comment: Option<&'static str>,
 }
 
-/// 模型元数据，由 derive(ModelMeta) 生成
+/// 模型元数据，由 derive(ModelMeta) 生成，通过 `Model::meta()` 获取
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ModelMeta {
     /// 表名
     pub table: &'static str,
-    /// 主键字段名
+    /// 主键列名（复合主键以逗号分隔）
     pub pk: &'static str,
     /// 逻辑删除字段名
     pub soft_delete: Option<&'static str>,
@@ -42,3 +52,78 @@ pub struct ModelMeta {
     /// 表注释
     pub table_comment: Option<&'static str>,
 }
+
+impl FieldMeta {
+    /// 列是否允许 NULL：`Option<T>` 字段且未设置 `not_null`，主键列始终不允许
+    pub fn is_nullable(&self) -> bool {
+        self.nullable && !self.not_null && !self.primary_key
+    }
+}
+
+impl ModelMeta {
+    /// 按字段名或列名查找字段
+    pub fn field(&self, name: &str) -> Option<&'static FieldMeta> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name || field.column == name)
+    }
+
+    /// 对应数据库列的字段（不含 `#[skip]` 字段）
+    pub fn columns(&self) -> impl Iterator<Item = &'static FieldMeta> {
+        self.fields.iter().filter(|field| !field.skip)
+    }
+
+    /// 主键字段
+    pub fn primary_keys(&self) -> impl Iterator<Item = &'static FieldMeta> {
+        self.columns().filter(|field| field.primary_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn field(name: &'static str) -> FieldMeta {
+        FieldMeta {
+            name,
+            column: name,
+            rust_type: "String",
+            nullable: true,
+            skip: false,
+            index: None,
+            combine_index: None,
+            unique: false,
+            not_null: false,
+            default: None,
+            length: None,
+            auto_increment: false,
+            primary_key: false,
+            soft_delete: false,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn test_model_meta_lookup() {
+        static FIELDS: [FieldMeta; 3] = [
+            FieldMeta { rust_type: "i64", primary_key: true, ..field("id") },
+            FieldMeta { column: "user_name", not_null: true, ..field("name") },
+            FieldMeta { skip: true, ..field("cache") },
+        ];
+        let meta = ModelMeta {
+            table: "user",
+            pk: "id",
+            soft_delete: None,
+            fields: &FIELDS,
+            table_comment: None,
+        };
+
+        assert_eq!(meta.field("user_name").map(|f| f.name), Some("name"));
+        assert_eq!(meta.field("name").map(|f| f.column), Some("user_name"));
+        assert_eq!(meta.columns().count(), 2);
+        assert_eq!(meta.primary_keys().map(|f| f.column).collect::<Vec<_>>(), vec!["id"]);
+        assert!(!meta.field("id").unwrap().is_nullable());
+        assert!(!meta.field("name").unwrap().is_nullable());
+        assert!(meta.field("cache").unwrap().is_nullable());
+    }
+}
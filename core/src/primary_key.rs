@@ -0,0 +1,216 @@
+//! 主键类型
+//!
+//! `Model::Id` 由主键字段类型推导：`i16`、`i32`、`i64`、`u32`、`u64`、`String`，以及启用 `uuid`
+//! 特性后的 `uuid::Uuid`。复合主键（`#[model(pk = "tenant_id,user_id")]`）为各主键字段类型组成的元组。
+//!
+//! 整数主键未赋值时由数据库生成（自增），其他类型的主键需要在插入前赋值。
+
+use crate::database_info::DatabaseInfo;
+use crate::error::{Result, SqlxPlusError};
+use crate::traits::Model;
+use sqlx::query::Query;
+use sqlx::Database;
+
+/// 模型主键值
+pub trait PrimaryKey: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// 未赋值时是否可以由数据库生成（自增整数主键）
+    const AUTO_INCREMENT: bool = false;
+
+    /// 将数据库生成的自增 ID 转换为主键，非整数主键无法由数据库生成，返回 `None`
+    fn from_generated(_id: i64) -> Option<Self> {
+        None
+    }
+}
+
+/// 将主键值绑定到查询（复合主键按列顺序依次绑定）
+pub trait BindPrimaryKey<DB: Database>: PrimaryKey {
+    /// 绑定主键值
+    fn bind_to<'q>(
+        self,
+        query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+    ) -> Query<'q, DB, <DB as Database>::Arguments<'q>>;
+}
+
+macro_rules! impl_scalar_primary_key {
+    ($($ty:ty => $auto_increment:literal, $generated:expr),* $(,)?) => {
+        $(
+            impl PrimaryKey for $ty {
+                const AUTO_INCREMENT: bool = $auto_increment;
+
+                fn from_generated(id: i64) -> Option<Self> {
+                    let convert: fn(i64) -> Option<$ty> = $generated;
+                    convert(id)
+                }
+            }
+
+            impl<DB: Database> BindPrimaryKey<DB> for $ty
+            where
+                $ty: for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+            {
+                fn bind_to<'q>(
+                    self,
+                    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+                ) -> Query<'q, DB, <DB as Database>::Arguments<'q>> {
+                    query.bind(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_primary_key! {
+    i16 => true, |id| i16::try_from(id).ok(),
+    i32 => true, |id| i32::try_from(id).ok(),
+    i64 => true, Some,
+    u32 => true, |id| u32::try_from(id).ok(),
+    u64 => true, |id| u64::try_from(id).ok(),
+    String => false, |_| None,
+}
+
+#[cfg(feature = "uuid")]
+impl_scalar_primary_key! {
+    uuid::Uuid => false, |_| None,
+}
+
+macro_rules! impl_tuple_primary_key {
+    ($($name:ident),+) => {
+        impl<$($name: PrimaryKey),+> PrimaryKey for ($($name,)+) {}
+
+        impl<DB: Database, $($name: BindPrimaryKey<DB>),+> BindPrimaryKey<DB> for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn bind_to<'q>(
+                self,
+                mut query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+            ) -> Query<'q, DB, <DB as Database>::Arguments<'q>> {
+                let ($($name,)+) = self;
+                $(
+                    query = $name.bind_to(query);
+                )+
+                query
+            }
+        }
+    };
+}
+
+impl_tuple_primary_key!(A, B);
+impl_tuple_primary_key!(A, B, C);
+impl_tuple_primary_key!(A, B, C, D);
+
+/// 模型的主键列（复合主键按声明顺序）
+pub fn pk_columns<M: Model>() -> impl Iterator<Item = &'static str> {
+    M::PK.split(',').map(str::trim)
+}
+
+/// 插入时主键未赋值：确认主键可以由数据库生成，否则返回错误
+pub fn ensure_generated<M: Model>() -> Result<()> {
+    if M::Id::AUTO_INCREMENT {
+        Ok(())
+    } else {
+        Err(SqlxPlusError::InvalidField(format!(
+            "Primary key '{}' of '{}' must be set before insert",
+            M::PK,
+            M::TABLE
+        )))
+    }
+}
+
+/// 将数据库生成的自增 ID 转换为模型主键
+pub fn generated_id<M: Model>(id: i64) -> Result<M::Id> {
+    M::Id::from_generated(id).ok_or_else(|| {
+        SqlxPlusError::InvalidField(format!(
+            "Generated id {} does not fit primary key '{}' of '{}'",
+            id,
+            M::PK,
+            M::TABLE
+        ))
+    })
+}
+
+/// 按主键匹配单条记录的条件，如 `` `id` = ? `` 或 `` `tenant_id` = ? AND `user_id` = ? ``
+///
+/// 占位符从 `placeholder_index` 开始，每个主键列占用一个并递增索引
+pub fn pk_condition<DB: DatabaseInfo, M: Model>(placeholder_index: &mut usize) -> String {
+    pk_columns::<M>()
+        .map(|column| {
+            let condition = format!(
+                "{} = {}",
+                DB::escape_identifier(column),
+                DB::placeholder(*placeholder_index)
+            );
+            *placeholder_index += 1;
+            condition
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// 按主键匹配多条记录的条件：单列主键为 `id IN (...)`，复合主键为 `(a = ? AND b = ?) OR ...`
+pub fn pk_in_condition<DB: DatabaseInfo, M: Model>(count: usize) -> String {
+    let mut placeholder_index = 0;
+    if pk_columns::<M>().count() == 1 {
+        let placeholders: Vec<String> = (0..count).map(|i| DB::placeholder(i)).collect();
+        return format!(
+            "{} IN ({})",
+            DB::escape_identifier(M::PK.trim()),
+            placeholders.join(", ")
+        );
+    }
+    let conditions: Vec<String> = (0..count)
+        .map(|_| format!("({})", pk_condition::<DB, M>(&mut placeholder_index)))
+        .collect();
+    format!("({})", conditions.join(" OR "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Membership;
+
+    impl Model for Membership {
+        const TABLE: &'static str = "membership";
+        const PK: &'static str = "tenant_id,user_id";
+        type Id = (i64, String);
+    }
+
+    struct Account;
+
+    impl Model for Account {
+        const TABLE: &'static str = "account";
+        const PK: &'static str = "id";
+        type Id = i64;
+    }
+
+    #[test]
+    fn test_from_generated() {
+        assert_eq!(i64::from_generated(7), Some(7));
+        assert_eq!(u64::from_generated(-1), None);
+        assert_eq!(i16::from_generated(70000), None);
+        assert_eq!(String::from_generated(7), None);
+        assert_eq!(<(i64, i64)>::from_generated(7), None);
+
+        assert!(ensure_generated::<Account>().is_ok());
+        assert!(ensure_generated::<Membership>().is_err());
+        assert_eq!(generated_id::<Account>(42).unwrap(), 42);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_pk_condition() {
+        let mut index = 2;
+        assert_eq!(
+            pk_condition::<sqlx::Postgres, Membership>(&mut index),
+            r#""tenant_id" = $3 AND "user_id" = $4"#
+        );
+        assert_eq!(index, 4);
+
+        assert_eq!(
+            pk_in_condition::<sqlx::Postgres, Account>(3),
+            r#""id" IN ($1, $2, $3)"#
+        );
+        assert_eq!(
+            pk_in_condition::<sqlx::Postgres, Membership>(2),
+            r#"(("tenant_id" = $1 AND "user_id" = $2) OR ("tenant_id" = $3 AND "user_id" = $4))"#
+        );
+    }
+}
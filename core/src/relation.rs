@@ -0,0 +1,464 @@
+//! 模型关联关系
+//!
+//! 在模型上通过 `#[relation(...)]` 声明 has_many / belongs_to 关系，由 `derive(ModelMeta)`
+//! 生成 [`Related`] 实现。加载时每个关系只发出一条 `IN (...)` 查询，再按关联键分组，
+//! 避免逐条查询子记录的 N+1 问题。
+//!
+//! # 示例
+//!
+//! ```rust,ignore
+//! #[derive(Debug, sqlx::FromRow, sqlxplus::ModelMeta, sqlxplus::CRUD)]
+//! #[model(table = "user", pk = "id")]
+//! #[relation(has_many = "Order", foreign_key = "user_id")]
+//! pub struct User { /* ... */ }
+//!
+//! #[derive(Debug, Clone, sqlx::FromRow, sqlxplus::ModelMeta, sqlxplus::CRUD)]
+//! #[model(table = "orders", pk = "id")]
+//! #[relation(belongs_to = "User", foreign_key = "user_id")]
+//! pub struct Order { /* ... */ }
+//!
+//! // 一条查询加载所有用户的订单
+//! let orders = sqlxplus::crud::load_related::<_, User, Order, _>(&users, pool.mysql_pool()).await?;
+//! for user in &users {
+//!     println!("{:?} -> {}", user.id, orders.get(user).len());
+//! }
+//!
+//! // 或者直接把子记录挂到父记录上
+//! let users_with_orders = users.with::<Order, _>(pool.mysql_pool()).await?;
+//! ```
+
+use std::collections::HashMap;
+
+use crate::builder::query_builder::{BindValue, QueryBuilder};
+use crate::builder::update_builder::UpdateFields;
+use crate::error::Result;
+use crate::traits::Model;
+
+/// 关联关系类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationKind {
+    /// 一对多：关联模型上的外键指向当前模型（如 User has_many Order）
+    HasMany,
+    /// 多对一：当前模型上的外键指向关联模型（如 Order belongs_to User）
+    BelongsTo,
+}
+
+/// 当前模型与关联模型 `R` 之间的关系（由 `#[relation(...)]` 生成）
+pub trait Related<R: Model>: Model {
+    /// 关系类型
+    const KIND: RelationKind;
+    /// 当前模型上用于匹配的列（has_many 为主键或 `local_key`，belongs_to 为外键）
+    const LOCAL_KEY: &'static str;
+    /// 关联模型上用于 `IN (...)` 查询的列（has_many 为外键，belongs_to 为关联模型主键或 `owner_key`）
+    const REMOTE_KEY: &'static str;
+}
+
+/// 将关联键的值规整为可哈希的 key
+///
+/// 整数统一转为十进制字符串，使 `i32` 外键可以匹配 `i64` 主键；`NULL` 与浮点数、字节数组不参与匹配。
+pub(crate) fn relation_key(value: &BindValue) -> Option<String> {
+    match value {
+        BindValue::String(s) => Some(s.clone()),
+        BindValue::Int64(i) => Some(i.to_string()),
+        BindValue::Int32(i) => Some(i.to_string()),
+        BindValue::Int16(i) => Some(i.to_string()),
+        BindValue::Int8(i) => Some(i.to_string()),
+        BindValue::UInt64(i) => Some(i.to_string()),
+        BindValue::UInt32(i) => Some(i.to_string()),
+        BindValue::UInt16(i) => Some(i.to_string()),
+        BindValue::UInt8(i) => Some(i.to_string()),
+        BindValue::Bool(b) => Some(b.to_string()),
+        BindValue::DateTime(dt) => Some(dt.to_rfc3339()),
+        BindValue::NaiveDateTime(dt) => Some(dt.to_string()),
+        BindValue::Float64(_) | BindValue::Float32(_) | BindValue::Bytes(_) | BindValue::Null => {
+            None
+        }
+    }
+}
+
+/// 收集父记录上去重后的关联键值（保持首次出现的顺序）
+pub(crate) fn collect_keys<M, R>(parents: &[M]) -> Vec<BindValue>
+where
+    M: Related<R> + UpdateFields,
+    R: Model,
+{
+    let mut seen = std::collections::HashSet::new();
+    let mut keys = Vec::new();
+    for parent in parents {
+        if let Some(value) = parent.get_field_value(M::LOCAL_KEY) {
+            if let Some(key) = relation_key(&value) {
+                if seen.insert(key) {
+                    keys.push(value);
+                }
+            }
+        }
+    }
+    keys
+}
+
+/// 构建加载关联记录的查询：`SELECT ... FROM r WHERE remote_key IN (...)`
+pub(crate) fn related_query<M, R>(keys: Vec<BindValue>) -> QueryBuilder
+where
+    M: Related<R>,
+    R: Model,
+{
+    QueryBuilder::new("").and_in(M::REMOTE_KEY, keys)
+}
+
+/// 按关联键分组的关联记录
+///
+/// 由 [`crate::crud::load_related`] 返回，通过父记录查询其关联记录。
+#[derive(Debug)]
+pub struct RelatedMap<R> {
+    groups: HashMap<String, Vec<R>>,
+}
+
+impl<R> RelatedMap<R>
+where
+    R: Model + UpdateFields,
+{
+    /// 按关联模型上的 `remote_key` 列分组
+    pub(crate) fn group_by_key(rows: Vec<R>, remote_key: &str) -> Self {
+        let mut groups: HashMap<String, Vec<R>> = HashMap::new();
+        for row in rows {
+            if let Some(key) = row.get_field_value(remote_key).as_ref().and_then(relation_key) {
+                groups.entry(key).or_default().push(row);
+            }
+        }
+        Self { groups }
+    }
+
+    /// 获取父记录的所有关联记录（没有关联记录时返回空切片）
+    pub fn get<M>(&self, parent: &M) -> &[R]
+    where
+        M: Related<R> + UpdateFields,
+    {
+        Self::parent_key(parent)
+            .and_then(|key| self.groups.get(&key))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// 获取父记录的第一条关联记录（适用于 belongs_to）
+    pub fn first<M>(&self, parent: &M) -> Option<&R>
+    where
+        M: Related<R> + UpdateFields,
+    {
+        self.get(parent).first()
+    }
+
+    /// 取出父记录的关联记录，之后再次获取同一个 key 将返回空
+    pub fn take<M>(&mut self, parent: &M) -> Vec<R>
+    where
+        M: Related<R> + UpdateFields,
+    {
+        Self::parent_key(parent)
+            .and_then(|key| self.groups.remove(&key))
+            .unwrap_or_default()
+    }
+
+    /// 有关联记录的 key 数量
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// 是否没有任何关联记录
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    fn parent_key<M>(parent: &M) -> Option<String>
+    where
+        M: Related<R> + UpdateFields,
+    {
+        parent
+            .get_field_value(M::LOCAL_KEY)
+            .as_ref()
+            .and_then(relation_key)
+    }
+}
+
+/// 为父记录列表挂载关联记录
+///
+/// 每个父记录与其关联记录组成一个元组，顺序与原列表一致。多个父记录共享同一条关联记录时
+/// （如多个订单属于同一用户）会克隆该记录，因此要求 `R: Clone`。
+#[async_trait::async_trait]
+pub trait WithRelated<M>: Sized {
+    /// 使用一条 `IN (...)` 查询加载关联模型 `R`，并按关联键挂到每个父记录上
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let users = User::find_all(pool.mysql_pool(), None).await?;
+    /// for (user, orders) in users.with::<Order, _>(pool.mysql_pool()).await? {
+    ///     println!("{:?}: {} orders", user.id, orders.len());
+    /// }
+    /// ```
+    async fn with<'e, 'c: 'e, R, E>(self, executor: E) -> Result<Vec<(M, Vec<R>)>>
+    where
+        M: Related<R>,
+        R: Model
+            + UpdateFields
+            + Clone
+            + Send
+            + Unpin
+            + for<'r> sqlx::FromRow<
+                'r,
+                <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
+            >,
+        E: crate::database_type::DatabaseType
+            + sqlx::Executor<'c, Database = <E as crate::database_type::DatabaseType>::DB>
+            + Send,
+        <E as crate::database_type::DatabaseType>::DB:
+            sqlx::Database + crate::database_info::DatabaseInfo,
+        for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
+            sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+        String: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i16: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        bool: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Vec<u8>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>;
+}
+
+#[async_trait::async_trait]
+impl<M> WithRelated<M> for Vec<M>
+where
+    M: Model + UpdateFields + Send + Sync,
+{
+    async fn with<'e, 'c: 'e, R, E>(self, executor: E) -> Result<Vec<(M, Vec<R>)>>
+    where
+        M: Related<R>,
+        R: Model
+            + UpdateFields
+            + Clone
+            + Send
+            + Unpin
+            + for<'r> sqlx::FromRow<
+                'r,
+                <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
+            >,
+        E: crate::database_type::DatabaseType
+            + sqlx::Executor<'c, Database = <E as crate::database_type::DatabaseType>::DB>
+            + Send,
+        <E as crate::database_type::DatabaseType>::DB:
+            sqlx::Database + crate::database_info::DatabaseInfo,
+        for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
+            sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        String: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i16: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        bool: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Vec<u8>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+    {
+        let related = crate::crud::load_related::<
+            <E as crate::database_type::DatabaseType>::DB,
+            M,
+            R,
+            E,
+        >(&self, executor)
+        .await?;
+        Ok(attach(self, related))
+    }
+}
+
+/// 把分组后的关联记录按顺序挂到父记录上；同一个 key 最后一次出现时移出，之前的出现克隆
+fn attach<M, R>(parents: Vec<M>, mut related: RelatedMap<R>) -> Vec<(M, Vec<R>)>
+where
+    M: Related<R> + UpdateFields,
+    R: Model + UpdateFields + Clone,
+{
+    let mut remaining: HashMap<String, usize> = HashMap::new();
+    for parent in &parents {
+        if let Some(key) = RelatedMap::<R>::parent_key(parent) {
+            *remaining.entry(key).or_default() += 1;
+        }
+    }
+
+    parents
+        .into_iter()
+        .map(|parent| {
+            let children = match RelatedMap::<R>::parent_key(&parent) {
+                Some(key) => {
+                    let count = remaining.get_mut(&key).expect("key counted above");
+                    *count -= 1;
+                    if *count == 0 {
+                        related.take(&parent)
+                    } else {
+                        related.get(&parent).to_vec()
+                    }
+                }
+                None => Vec::new(),
+            };
+            (parent, children)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Author {
+        id: Option<i64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Post {
+        id: Option<i64>,
+        author_id: Option<i32>,
+    }
+
+    impl Model for Author {
+        const TABLE: &'static str = "author";
+        const PK: &'static str = "id";
+        type Id = i64;
+    }
+
+    impl Model for Post {
+        const TABLE: &'static str = "post";
+        const PK: &'static str = "id";
+        type Id = i64;
+    }
+
+    impl UpdateFields for Author {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "id" => self.id.map(BindValue::Int64),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &[]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            field_name == "id"
+        }
+    }
+
+    impl UpdateFields for Post {
+        fn get_field_value(&self, field_name: &str) -> Option<BindValue> {
+            match field_name {
+                "id" => self.id.map(BindValue::Int64),
+                "author_id" => self.author_id.map(BindValue::Int32),
+                _ => None,
+            }
+        }
+
+        fn get_all_field_names() -> &'static [&'static str] {
+            &["author_id"]
+        }
+
+        fn has_field(field_name: &str) -> bool {
+            matches!(field_name, "id" | "author_id")
+        }
+    }
+
+    impl Related<Post> for Author {
+        const KIND: RelationKind = RelationKind::HasMany;
+        const LOCAL_KEY: &'static str = "id";
+        const REMOTE_KEY: &'static str = "author_id";
+    }
+
+    impl Related<Author> for Post {
+        const KIND: RelationKind = RelationKind::BelongsTo;
+        const LOCAL_KEY: &'static str = "author_id";
+        const REMOTE_KEY: &'static str = "id";
+    }
+
+    fn post(id: i64, author_id: Option<i32>) -> Post {
+        Post {
+            id: Some(id),
+            author_id,
+        }
+    }
+
+    #[test]
+    fn test_collect_keys_dedups_and_skips_null() {
+        let posts = vec![post(1, Some(7)), post(2, None), post(3, Some(7)), post(4, Some(8))];
+        let keys = collect_keys::<Post, Author>(&posts);
+        assert_eq!(keys.len(), 2);
+        assert!(matches!(keys[0], BindValue::Int32(7)));
+        assert!(matches!(keys[1], BindValue::Int32(8)));
+
+        let sql = related_query::<Post, Author>(keys).into_sql(crate::db_pool::DbDriver::Postgres);
+        assert_eq!(sql, " WHERE \"id\" IN ($1, $2)");
+    }
+
+    #[test]
+    fn test_has_many_groups_by_foreign_key() {
+        let posts = vec![post(1, Some(1)), post(2, Some(2)), post(3, Some(1)), post(4, None)];
+        let mut map = RelatedMap::group_by_key(posts, <Author as Related<Post>>::REMOTE_KEY);
+        assert_eq!(map.len(), 2);
+
+        // i32 外键与 i64 主键可以匹配
+        let alice = Author { id: Some(1) };
+        let bob = Author { id: Some(2) };
+        let carol = Author { id: Some(3) };
+        let ids: Vec<_> = map.get(&alice).iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![Some(1), Some(3)]);
+        assert_eq!(map.get(&carol).len(), 0);
+        assert_eq!(map.take(&bob).len(), 1);
+        assert!(map.get(&bob).is_empty());
+    }
+
+    #[test]
+    fn test_attach_belongs_to_clones_shared_owner() {
+        let posts = vec![post(1, Some(1)), post(2, Some(2)), post(3, Some(1)), post(4, None)];
+        let authors = vec![Author { id: Some(1) }, Author { id: Some(2) }];
+        let map = RelatedMap::group_by_key(authors, <Post as Related<Author>>::REMOTE_KEY);
+
+        let attached = attach(posts, map);
+        let owners: Vec<_> = attached
+            .iter()
+            .map(|(p, a)| (p.id, a.first().and_then(|a| a.id)))
+            .collect();
+        assert_eq!(
+            owners,
+            vec![
+                (Some(1), Some(1)),
+                (Some(2), Some(2)),
+                (Some(3), Some(1)),
+                (Some(4), None)
+            ]
+        );
+    }
+}
@@ -0,0 +1,622 @@
+//! 运行时建表
+//!
+//! 根据 `Model::meta()` 的运行时元数据为当前驱动生成 CREATE TABLE / CREATE INDEX / 注释语句，
+//! 类型映射和默认值格式化规则与 `sqlxplus-cli sql` 一致。适用于集成测试和嵌入式 SQLite 部署中初始化表结构；
+//! 已存在的表不会被修改（不做 ALTER TABLE）。
+
+use std::collections::BTreeMap;
+
+use crate::db_pool::{DbDriver, DbPool};
+use crate::error::{Result, SqlxPlusError};
+use crate::macros_api::{FieldMeta, ModelMeta};
+use crate::traits::Model;
+use crate::utils::escape_identifier;
+
+/// 建表模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CreateMode {
+    /// `CREATE TABLE`，表已存在时报错
+    Create,
+    /// `CREATE TABLE IF NOT EXISTS`，索引同样只在不存在时创建
+    #[default]
+    IfNotExists,
+}
+
+/// 创建模型对应的表及其索引、注释
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// use sqlxplus::schema::{self, CreateMode};
+///
+/// schema::create_table::<User>(&pool, CreateMode::IfNotExists).await?;
+/// ```
+pub async fn create_table<M: Model>(pool: &DbPool, mode: CreateMode) -> Result<()> {
+    execute_all(pool, &create_table_sql(M::meta(), pool.driver(), mode)?).await
+}
+
+/// 依次创建所有模型的表（已存在的表跳过）
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// schema::sync(&pool, &[User::meta(), Order::meta()]).await?;
+/// ```
+pub async fn sync(pool: &DbPool, models: &[&ModelMeta]) -> Result<()> {
+    for meta in models {
+        execute_all(
+            pool,
+            &create_table_sql(meta, pool.driver(), CreateMode::IfNotExists)?,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// 生成建表语句（每个元素为一条独立的 SQL 语句）
+///
+/// - MySQL：索引和注释内联在 CREATE TABLE 中（MySQL 不支持 `CREATE INDEX IF NOT EXISTS`）
+/// - PostgreSQL：CREATE TABLE 之后依次为 CREATE INDEX、COMMENT ON COLUMN / TABLE
+/// - SQLite：CREATE TABLE 之后为 CREATE INDEX，不支持注释
+pub fn create_table_sql(
+    meta: &ModelMeta,
+    driver: DbDriver,
+    mode: CreateMode,
+) -> Result<Vec<String>> {
+    if meta.fields.is_empty() {
+        return Err(SqlxPlusError::Other(format!(
+            "model `{}` has no field metadata; derive ModelMeta to use schema APIs",
+            meta.table
+        )));
+    }
+
+    let escape = |name: &str| escape_identifier(driver, name);
+    let table = escape(meta.table);
+    let if_not_exists = match mode {
+        CreateMode::Create => "",
+        CreateMode::IfNotExists => "IF NOT EXISTS ",
+    };
+
+    let mut pk_columns: Vec<&str> = meta.primary_keys().map(|field| field.column).collect();
+    if pk_columns.is_empty() {
+        pk_columns = meta.pk.split(',').map(str::trim).collect();
+    }
+    let single_pk = pk_columns.len() == 1;
+
+    let mut definitions = Vec::new();
+    let mut unique_constraints = Vec::new();
+    let mut indexes: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut combined: BTreeMap<&str, Vec<(i32, &str)>> = BTreeMap::new();
+    let mut column_comments = Vec::new();
+    let mut column_types: Vec<(&str, String)> = Vec::new();
+
+    for (position, field) in meta.fields.iter().enumerate() {
+        if field.skip {
+            continue;
+        }
+        let is_pk = pk_columns.contains(&field.column);
+        definitions.push(column_definition(field, driver, is_pk && single_pk, is_pk));
+        column_types.push((field.column, column_type(field, driver)));
+
+        if let Some(comment) = field.comment {
+            column_comments.push((field.column, comment));
+        }
+        if let Some((name, order)) = field.combine_index {
+            // 与 CLI 一致：未指定顺序（i32::MAX）时使用字段在结构体中的位置
+            let order = if order == i32::MAX { position as i32 } else { order };
+            combined
+                .entry(name)
+                .or_default()
+                .push((order, field.column));
+        }
+        // unique 创建唯一约束（index 指定约束名），否则 index 创建普通索引
+        let index_name = |prefix: &str| match field.index {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => format!("{}_{}_{}", prefix, meta.table, field.column),
+        };
+        if field.unique {
+            let column = escape(field.column);
+            unique_constraints.push(match driver {
+                DbDriver::MySql => format!(
+                    "UNIQUE KEY {} ({})",
+                    escape(&index_name("uk")),
+                    mysql_key_part(&column, &column_type(field, driver))
+                ),
+                DbDriver::Postgres => format!(
+                    "CONSTRAINT {} UNIQUE ({})",
+                    escape(&index_name("uk")),
+                    column
+                ),
+                DbDriver::Sqlite => format!("UNIQUE ({})", column),
+            });
+        } else if field.index.is_some() {
+            indexes.push((index_name("idx"), vec![field.column]));
+        }
+    }
+    for (name, mut columns) in combined {
+        columns.sort_by_key(|(order, _)| *order);
+        indexes.push((
+            name.to_string(),
+            columns.into_iter().map(|(_, column)| column).collect(),
+        ));
+    }
+
+    // MySQL 的 TEXT / BLOB 列作为索引列时需要指定前缀长度
+    let key_columns = |columns: &[&str]| {
+        columns
+            .iter()
+            .map(|column| {
+                let escaped = escape(column);
+                match column_types.iter().find(|(name, _)| name == column) {
+                    Some((_, sql_type)) if driver == DbDriver::MySql => mysql_key_part(&escaped, sql_type),
+                    _ => escaped,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    definitions.push(format!("PRIMARY KEY ({})", key_columns(&pk_columns)));
+    definitions.extend(unique_constraints);
+
+    let mut statements = Vec::new();
+    match driver {
+        DbDriver::MySql => {
+            for (name, columns) in &indexes {
+                definitions.push(format!("KEY {} ({})", escape(name), key_columns(columns)));
+            }
+            let mut sql = format!(
+                "CREATE TABLE {}{} (\n    {}\n)",
+                if_not_exists,
+                table,
+                definitions.join(",\n    ")
+            );
+            if let Some(comment) = meta.table_comment {
+                sql.push_str(&format!(" COMMENT '{}'", comment.replace('\'', "''")));
+            }
+            statements.push(sql);
+        }
+        DbDriver::Postgres | DbDriver::Sqlite => {
+            statements.push(format!(
+                "CREATE TABLE {}{} (\n    {}\n)",
+                if_not_exists,
+                table,
+                definitions.join(",\n    ")
+            ));
+            for (name, columns) in &indexes {
+                statements.push(format!(
+                    "CREATE INDEX {}{} ON {} ({})",
+                    if_not_exists,
+                    escape(name),
+                    table,
+                    key_columns(columns)
+                ));
+            }
+        }
+    }
+
+    if driver == DbDriver::Postgres {
+        for (column, comment) in column_comments {
+            statements.push(format!(
+                "COMMENT ON COLUMN {}.{} IS '{}'",
+                table,
+                escape(column),
+                comment.replace('\'', "''")
+            ));
+        }
+        if let Some(comment) = meta.table_comment {
+            statements.push(format!(
+                "COMMENT ON TABLE {} IS '{}'",
+                table,
+                comment.replace('\'', "''")
+            ));
+        }
+    }
+
+    Ok(statements)
+}
+
+/// 列定义：列名、类型、NOT NULL、DEFAULT、自增、注释（MySQL）
+fn column_definition(field: &FieldMeta, driver: DbDriver, single_pk: bool, is_pk: bool) -> String {
+    let column = escape_identifier(driver, field.column);
+    let sql_type = column_type(field, driver);
+
+    // 单列整数主键（与 `PrimaryKey::AUTO_INCREMENT` 一致）或 `auto_increment` 整数列自增：
+    // MySQL AUTO_INCREMENT，PostgreSQL SMALLSERIAL / SERIAL / BIGSERIAL，SQLite INTEGER 主键即 rowid
+    let auto_increment = is_integer_type(&sql_type) && (single_pk || field.auto_increment);
+    if auto_increment && driver == DbDriver::Postgres {
+        let serial = match sql_type.as_str() {
+            "BIGINT" => "BIGSERIAL",
+            "SMALLINT" => "SMALLSERIAL",
+            _ => "SERIAL",
+        };
+        return format!("{} {}", column, serial);
+    }
+
+    let mut definition = format!("{} {}", column, sql_type);
+    if !is_pk && !field.is_nullable() {
+        definition.push_str(" NOT NULL");
+    }
+    if let Some(default) = field.default {
+        definition.push_str(" DEFAULT ");
+        definition.push_str(&default_value(default, field, &sql_type, driver));
+    }
+    if auto_increment && driver == DbDriver::MySql {
+        definition.push_str(" AUTO_INCREMENT");
+    }
+    if let (DbDriver::MySql, Some(comment)) = (driver, field.comment) {
+        definition.push_str(&format!(" COMMENT '{}'", comment.replace('\'', "''")));
+    }
+    definition
+}
+
+/// 是否整数类型（不含 MySQL 用于布尔的 TINYINT(1)）
+fn is_integer_type(sql_type: &str) -> bool {
+    let base = sql_type.split(['(', ' ']).next().unwrap_or_default();
+    matches!(base, "SMALLINT" | "INT" | "INTEGER" | "MEDIUMINT" | "BIGINT")
+}
+
+/// MySQL 索引列：TEXT / BLOB 列需要前缀长度（否则报错 1170），使用 255 个字符
+fn mysql_key_part(column: &str, sql_type: &str) -> String {
+    if sql_type.ends_with("TEXT") || sql_type.ends_with("BLOB") {
+        format!("{}(255)", column)
+    } else {
+        column.to_string()
+    }
+}
+
+/// 字段的 SQL 类型：根据 Rust 类型推断，`length` 覆盖 VARCHAR / CHAR 长度，
+/// 未指定长度且字段名包含 `text` 的字符串字段使用 TEXT
+fn column_type(field: &FieldMeta, driver: DbDriver) -> String {
+    let sql_type = rust_type_to_sql(field.rust_type, driver);
+    match field.length {
+        Some(length) if sql_type.starts_with("VARCHAR") => format!("VARCHAR({})", length),
+        Some(length) if sql_type.starts_with("CHAR") => format!("CHAR({})", length),
+        None if sql_type.starts_with("VARCHAR") && field.name.to_lowercase().contains("text") => {
+            "TEXT".to_string()
+        }
+        _ => sql_type.to_string(),
+    }
+}
+
+/// Rust 类型（去掉 `Option<>` 和空白）转换为 SQL 类型，未知类型按字符串处理
+fn rust_type_to_sql(rust_type: &str, driver: DbDriver) -> &'static str {
+    let by_driver = |mysql, postgres, sqlite| match driver {
+        DbDriver::MySql => mysql,
+        DbDriver::Postgres => postgres,
+        DbDriver::Sqlite => sqlite,
+    };
+    match rust_type {
+        "i64" => by_driver("BIGINT", "BIGINT", "INTEGER"),
+        "i32" => by_driver("INT", "INTEGER", "INTEGER"),
+        "i16" => by_driver("SMALLINT", "SMALLINT", "INTEGER"),
+        "u64" => by_driver("BIGINT UNSIGNED", "BIGINT", "INTEGER"),
+        "u32" => by_driver("INT UNSIGNED", "INTEGER", "INTEGER"),
+        "u16" => by_driver("SMALLINT UNSIGNED", "SMALLINT", "INTEGER"),
+        "String" => by_driver("VARCHAR(255)", "VARCHAR(255)", "TEXT"),
+        "f64" => by_driver("DOUBLE", "DOUBLE PRECISION", "REAL"),
+        "f32" => by_driver("FLOAT", "REAL", "REAL"),
+        "bool" => by_driver("TINYINT(1)", "BOOLEAN", "INTEGER"),
+        "Vec<u8>" => by_driver("BLOB", "BYTEA", "BLOB"),
+        _ if rust_type.ends_with("DateTime<Utc>") || rust_type.ends_with("DateTime<chrono::Utc>") => {
+            by_driver("TIMESTAMP(3)", "TIMESTAMP WITH TIME ZONE", "TEXT")
+        }
+        // 不带时区的类型在 PostgreSQL 中对应 TIMESTAMP / TIME（WITH TIME ZONE 会按会话时区转换）
+        _ if rust_type.ends_with("NaiveDateTime") => by_driver("TIMESTAMP(3)", "TIMESTAMP", "TEXT"),
+        _ if rust_type.ends_with("NaiveDate") => by_driver("DATE", "DATE", "TEXT"),
+        _ if rust_type.ends_with("NaiveTime") => by_driver("TIME(3)", "TIME", "TEXT"),
+        _ if rust_type.ends_with("Value") => by_driver("JSON", "JSONB", "TEXT"),
+        _ if rust_type.ends_with("Uuid") => by_driver("CHAR(36)", "UUID", "TEXT"),
+        _ => by_driver("VARCHAR(255)", "VARCHAR(255)", "TEXT"),
+    }
+}
+
+/// 格式化默认值：函数调用和数字原样使用，布尔值按驱动格式化，其余作为字符串字面量
+fn default_value(default: &str, field: &FieldMeta, sql_type: &str, driver: DbDriver) -> String {
+    if default.starts_with("CURRENT_") || default.starts_with("NOW()") {
+        return default.to_string();
+    }
+    if default.is_empty() || default == "''" || default == "\"\"" {
+        return "''".to_string();
+    }
+    if field.rust_type == "bool" || sql_type == "BOOLEAN" || sql_type == "TINYINT(1)" {
+        let value = default.trim_start_matches("b'").trim_end_matches('\'');
+        let enabled = match value.to_ascii_lowercase().as_str() {
+            "true" => true,
+            "false" => false,
+            other => other.parse::<i64>().map(|n| n != 0).unwrap_or(false),
+        };
+        return match (driver, enabled) {
+            (DbDriver::Postgres, true) => "TRUE".to_string(),
+            (DbDriver::Postgres, false) => "FALSE".to_string(),
+            (_, true) => "1".to_string(),
+            (_, false) => "0".to_string(),
+        };
+    }
+    if default.parse::<f64>().is_ok() {
+        return default.to_string();
+    }
+    if (default.starts_with('\'') && default.ends_with('\''))
+        || (default.starts_with('"') && default.ends_with('"'))
+    {
+        return default.to_string();
+    }
+    format!("'{}'", default.replace('\'', "''"))
+}
+
+/// 在主库上依次执行语句（DDL 不使用参数绑定，也不做占位符转换）
+async fn execute_all(pool: &DbPool, statements: &[String]) -> Result<()> {
+    for sql in statements {
+        match pool.driver() {
+            #[cfg(feature = "mysql")]
+            DbDriver::MySql => {
+                sqlx::raw_sql(sql).execute(pool.mysql_pool()).await?;
+            }
+            #[cfg(feature = "postgres")]
+            DbDriver::Postgres => {
+                sqlx::raw_sql(sql).execute(pool.pg_pool()).await?;
+            }
+            #[cfg(feature = "sqlite")]
+            DbDriver::Sqlite => {
+                sqlx::raw_sql(sql).execute(pool.sqlite_pool()).await?;
+            }
+            #[allow(unreachable_patterns)]
+            _ => return Err(SqlxPlusError::NoPoolAvailable),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn field(name: &'static str, rust_type: &'static str) -> FieldMeta {
+        FieldMeta {
+            name,
+            column: name,
+            rust_type,
+            nullable: true,
+            skip: false,
+            index: None,
+            combine_index: None,
+            unique: false,
+            not_null: false,
+            default: None,
+            length: None,
+            auto_increment: false,
+            primary_key: false,
+            soft_delete: false,
+            comment: None,
+        }
+    }
+
+    static FIELDS: [FieldMeta; 7] = [
+        FieldMeta {
+            primary_key: true,
+            ..field("id", "i64")
+        },
+        FieldMeta {
+            length: Some(64),
+            unique: true,
+            comment: Some("user's email"),
+            ..field("email", "String")
+        },
+        FieldMeta {
+            index: Some(""),
+            ..field("body_text", "String")
+        },
+        FieldMeta {
+            not_null: true,
+            default: Some("true"),
+            ..field("active", "bool")
+        },
+        FieldMeta {
+            combine_index: Some(("idx_tenant_created", 1)),
+            ..field("created_at", "chrono::NaiveDateTime")
+        },
+        FieldMeta {
+            combine_index: Some(("idx_tenant_created", 0)),
+            default: Some("guest"),
+            ..field("tenant", "String")
+        },
+        FieldMeta {
+            skip: true,
+            ..field("cache", "String")
+        },
+    ];
+
+    static USER: ModelMeta = ModelMeta {
+        table: "user",
+        pk: "id",
+        soft_delete: None,
+        fields: &FIELDS,
+        table_comment: Some("users"),
+    };
+
+    #[test]
+    fn test_create_table_mysql() {
+        let sql = create_table_sql(&USER, DbDriver::MySql, CreateMode::IfNotExists).unwrap();
+        assert_eq!(
+            sql,
+            vec![concat!(
+                "CREATE TABLE IF NOT EXISTS `user` (\n",
+                "    `id` BIGINT AUTO_INCREMENT,\n",
+                "    `email` VARCHAR(64) COMMENT 'user''s email',\n",
+                "    `body_text` TEXT,\n",
+                "    `active` TINYINT(1) NOT NULL DEFAULT 1,\n",
+                "    `created_at` TIMESTAMP(3),\n",
+                "    `tenant` VARCHAR(255) DEFAULT 'guest',\n",
+                "    PRIMARY KEY (`id`),\n",
+                "    UNIQUE KEY `uk_user_email` (`email`),\n",
+                "    KEY `idx_user_body_text` (`body_text`(255)),\n",
+                "    KEY `idx_tenant_created` (`tenant`, `created_at`)\n",
+                ") COMMENT 'users'"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_create_table_postgres() {
+        let sql = create_table_sql(&USER, DbDriver::Postgres, CreateMode::Create).unwrap();
+        assert_eq!(sql.len(), 5);
+        assert!(sql[0].starts_with("CREATE TABLE \"user\" (\n    \"id\" BIGSERIAL,\n"));
+        assert!(sql[0].contains("\"active\" BOOLEAN NOT NULL DEFAULT TRUE"));
+        assert!(sql[0].contains("CONSTRAINT \"uk_user_email\" UNIQUE (\"email\")"));
+        assert_eq!(
+            sql[1],
+            "CREATE INDEX \"idx_user_body_text\" ON \"user\" (\"body_text\")"
+        );
+        assert_eq!(
+            sql[2],
+            "CREATE INDEX \"idx_tenant_created\" ON \"user\" (\"tenant\", \"created_at\")"
+        );
+        assert_eq!(
+            sql[3],
+            "COMMENT ON COLUMN \"user\".\"email\" IS 'user''s email'"
+        );
+        assert_eq!(sql[4], "COMMENT ON TABLE \"user\" IS 'users'");
+    }
+
+    #[test]
+    fn test_create_table_sqlite() {
+        let sql = create_table_sql(&USER, DbDriver::Sqlite, CreateMode::IfNotExists).unwrap();
+        assert_eq!(sql.len(), 3);
+        assert!(sql[0].contains("\"id\" INTEGER,\n"));
+        assert!(sql[0].contains("\"active\" INTEGER NOT NULL DEFAULT 1"));
+        assert!(sql[0].contains("UNIQUE (\"email\")"));
+        assert_eq!(
+            sql[1],
+            "CREATE INDEX IF NOT EXISTS \"idx_user_body_text\" ON \"user\" (\"body_text\")"
+        );
+
+        let empty = ModelMeta {
+            fields: &[],
+            ..USER
+        };
+        assert!(create_table_sql(&empty, DbDriver::Sqlite, CreateMode::Create).is_err());
+    }
+
+    #[test]
+    fn test_rust_type_to_sql() {
+        // 与 sqlxplus-cli 共用的映射表
+        for line in include_str!("../testdata/type_mapping.txt").lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = line.split('|').map(str::trim).collect();
+            let [rust_type, mysql, postgres, sqlite] = parts[..] else {
+                panic!("invalid mapping line: {}", line);
+            };
+            for (driver, expected) in [
+                (DbDriver::MySql, mysql),
+                (DbDriver::Postgres, postgres),
+                (DbDriver::Sqlite, sqlite),
+            ] {
+                assert_eq!(rust_type_to_sql(rust_type, driver), expected, "{} on {:?}", rust_type, driver);
+            }
+        }
+    }
+
+    #[test]
+    fn test_auto_increment_consistent_across_drivers() {
+        // 单列整数主键在所有驱动上都自增，字符串主键不自增
+        let cases = [
+            ("i32", DbDriver::MySql, "`id` INT AUTO_INCREMENT"),
+            ("i32", DbDriver::Postgres, "\"id\" SERIAL"),
+            ("i16", DbDriver::Postgres, "\"id\" SMALLSERIAL"),
+            ("u64", DbDriver::MySql, "`id` BIGINT UNSIGNED AUTO_INCREMENT"),
+            ("i64", DbDriver::Sqlite, "\"id\" INTEGER"),
+            ("String", DbDriver::MySql, "`id` VARCHAR(255)"),
+            ("String", DbDriver::Postgres, "\"id\" VARCHAR(255)"),
+        ];
+        for (rust_type, driver, expected) in cases {
+            let field = FieldMeta {
+                primary_key: true,
+                ..field("id", rust_type)
+            };
+            assert_eq!(column_definition(&field, driver, true, true), expected, "{} on {:?}", rust_type, driver);
+        }
+
+        // 复合主键不自增，显式 auto_increment 的整数列自增
+        let id = FieldMeta {
+            primary_key: true,
+            ..field("id", "i64")
+        };
+        assert_eq!(column_definition(&id, DbDriver::MySql, false, true), "`id` BIGINT");
+        assert_eq!(column_definition(&id, DbDriver::Postgres, false, true), "\"id\" BIGINT");
+        let seq = FieldMeta {
+            auto_increment: true,
+            nullable: false,
+            ..field("seq", "i32")
+        };
+        assert_eq!(column_definition(&seq, DbDriver::MySql, false, false), "`seq` INT NOT NULL AUTO_INCREMENT");
+        assert_eq!(column_definition(&seq, DbDriver::Postgres, false, false), "\"seq\" SERIAL");
+    }
+
+    #[test]
+    fn test_mysql_text_key_prefix() {
+        static FIELDS: [FieldMeta; 3] = [
+            FieldMeta {
+                primary_key: true,
+                ..field("id", "i64")
+            },
+            FieldMeta {
+                unique: true,
+                ..field("body_text", "String")
+            },
+            FieldMeta {
+                combine_index: Some(("idx_name_data", 0)),
+                ..field("data", "Vec<u8>")
+            },
+        ];
+        let meta = ModelMeta {
+            table: "doc",
+            pk: "id",
+            soft_delete: None,
+            fields: &FIELDS,
+            table_comment: None,
+        };
+        let sql = create_table_sql(&meta, DbDriver::MySql, CreateMode::Create).unwrap();
+        assert!(sql[0].contains("UNIQUE KEY `uk_doc_body_text` (`body_text`(255))"));
+        assert!(sql[0].contains("KEY `idx_name_data` (`data`(255))"));
+
+        let sql = create_table_sql(&meta, DbDriver::Postgres, CreateMode::Create).unwrap();
+        assert!(sql[0].contains("UNIQUE (\"body_text\")"));
+        assert_eq!(sql[1], "CREATE INDEX \"idx_name_data\" ON \"doc\" (\"data\")");
+    }
+
+    #[test]
+    fn test_combine_index_unspecified_order() {
+        // 未指定顺序（i32::MAX）时按字段在结构体中的位置排列，#[skip] 字段同样占位
+        static FIELDS: [FieldMeta; 4] = [
+            FieldMeta {
+                primary_key: true,
+                ..field("id", "i64")
+            },
+            FieldMeta {
+                combine_index: Some(("idx_status_owner", 5)),
+                ..field("owner", "i64")
+            },
+            FieldMeta {
+                skip: true,
+                ..field("cache", "String")
+            },
+            FieldMeta {
+                combine_index: Some(("idx_status_owner", i32::MAX)),
+                ..field("status", "i32")
+            },
+        ];
+        let meta = ModelMeta {
+            table: "task",
+            pk: "id",
+            soft_delete: None,
+            fields: &FIELDS,
+            table_comment: None,
+        };
+        let sql = create_table_sql(&meta, DbDriver::Sqlite, CreateMode::Create).unwrap();
+        assert_eq!(
+            sql[1],
+            "CREATE INDEX \"idx_status_owner\" ON \"task\" (\"status\", \"owner\")"
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! 逻辑删除语义
+//!
+//! 通过 `#[model(soft_delete = "...")]` 指定逻辑删除字段，`soft_delete_kind` 指定取值方式：
+//!
+//! - `"int"`：整数标记，默认 `0` 表示未删除、`1` 表示已删除
+//! - `"bool"`：布尔标记，默认 `FALSE` 表示未删除、`TRUE` 表示已删除
+//! - `"timestamp"`：删除时间，`NULL` 表示未删除，删除时写入当前时间（时间戳类型同 [`TimestampKind`]）
+//!
+//! 整数和布尔标记可以通过 `soft_delete_active` / `soft_delete_deleted` 自定义取值。
+//! 未指定 `soft_delete_kind` 时根据字段类型推断（`bool` 为布尔标记，时间类型为删除时间，其余为整数标记）。
+//!
+//! 查询默认排除已删除的记录，可以通过 `QueryBuilder::with_trashed()` / `only_trashed()` 调整范围。
+
+use crate::builder::query_builder::QueryBuilder;
+use crate::database_info::DatabaseInfo;
+use crate::timestamp::TimestampKind;
+use crate::traits::Model;
+
+/// 逻辑删除字段的取值方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoftDeleteKind {
+    /// 整数标记
+    Int {
+        /// 未删除时的值
+        active: i64,
+        /// 已删除时的值
+        deleted: i64,
+    },
+    /// 布尔标记
+    Bool {
+        /// 已删除时的值（未删除为其取反）
+        deleted: bool,
+    },
+    /// 删除时间：`NULL` 表示未删除
+    Timestamp(TimestampKind),
+}
+
+/// 逻辑删除记录的查询范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrashedScope {
+    /// 排除已删除的记录（默认）
+    #[default]
+    Exclude,
+    /// 包含已删除的记录
+    With,
+    /// 只查询已删除的记录
+    Only,
+}
+
+impl SoftDeleteKind {
+    /// 默认语义：整数标记，`0` 未删除、`1` 已删除
+    pub const DEFAULT: SoftDeleteKind = SoftDeleteKind::Int { active: 0, deleted: 1 };
+
+    /// "未删除"条件的 SQL 片段（`escaped_field` 为已转义的列名）
+    pub fn active_condition(self, escaped_field: &str) -> String {
+        match self {
+            SoftDeleteKind::Int { active, .. } => format!("{} = {}", escaped_field, active),
+            SoftDeleteKind::Bool { deleted } => {
+                format!("{} = {}", escaped_field, bool_literal(!deleted))
+            }
+            SoftDeleteKind::Timestamp(_) => format!("{} IS NULL", escaped_field),
+        }
+    }
+
+    /// 查询范围对应的 SQL 条件片段（`escaped_field` 为已转义的列名），`TrashedScope::With` 返回 `None`
+    pub fn scope_condition(self, scope: TrashedScope, escaped_field: &str) -> Option<String> {
+        match (scope, self) {
+            (TrashedScope::With, _) => None,
+            (TrashedScope::Exclude, kind) => Some(kind.active_condition(escaped_field)),
+            (TrashedScope::Only, SoftDeleteKind::Int { active, .. }) => {
+                Some(format!("{} <> {}", escaped_field, active))
+            }
+            (TrashedScope::Only, SoftDeleteKind::Bool { deleted }) => {
+                Some(format!("{} = {}", escaped_field, bool_literal(deleted)))
+            }
+            (TrashedScope::Only, SoftDeleteKind::Timestamp(_)) => {
+                Some(format!("{} IS NOT NULL", escaped_field))
+            }
+        }
+    }
+
+    /// 恢复时 SET 子句中的取值（未删除值，删除时间语义下为 `NULL`）
+    pub fn active_value(self) -> String {
+        match self {
+            SoftDeleteKind::Int { active, .. } => active.to_string(),
+            SoftDeleteKind::Bool { deleted } => bool_literal(!deleted).to_string(),
+            SoftDeleteKind::Timestamp(_) => "NULL".to_string(),
+        }
+    }
+
+    /// 逻辑删除时 SET 子句中的取值
+    ///
+    /// 删除时间需要绑定当前时间时使用 `placeholder_index` 处的占位符并递增索引
+    pub fn deleted_value<DB: DatabaseInfo>(self, placeholder_index: &mut usize) -> String {
+        match self {
+            SoftDeleteKind::Int { deleted, .. } => deleted.to_string(),
+            SoftDeleteKind::Bool { deleted } => bool_literal(deleted).to_string(),
+            SoftDeleteKind::Timestamp(kind) => {
+                crate::timestamp::placeholder::<DB>(kind, placeholder_index)
+            }
+        }
+    }
+
+    /// 删除时间的时间戳类型（非删除时间语义返回 `None`）
+    pub fn timestamp_kind(self) -> Option<TimestampKind> {
+        match self {
+            SoftDeleteKind::Timestamp(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// 按查询范围添加逻辑删除过滤条件（有 JOIN 时自动限定为模型 `M` 的表）
+///
+/// 默认只保留未删除的记录；`with_trashed()` 不添加条件，`only_trashed()` 只保留已删除的记录。
+/// 用户条件包含顶层 OR 时先加括号（与 UpdateBuilder / DeleteBuilder 一致）。
+/// 模型未定义逻辑删除字段时原样返回
+pub fn apply_trashed_scope<M: Model>(builder: QueryBuilder) -> QueryBuilder {
+    let Some(field) = M::SOFT_DELETE_FIELD else {
+        return builder;
+    };
+    if builder.trashed_scope() == TrashedScope::With {
+        return builder;
+    }
+    let column = builder.qualify_column(M::TABLE, field);
+    let builder = builder.group_conditions();
+    match (builder.trashed_scope(), M::SOFT_DELETE_KIND) {
+        (TrashedScope::With, _) => builder,
+        (TrashedScope::Exclude, SoftDeleteKind::Int { active, .. }) => builder.and_eq(column, active),
+        (TrashedScope::Exclude, SoftDeleteKind::Bool { deleted }) => builder.and_eq(column, !deleted),
+        (TrashedScope::Exclude, SoftDeleteKind::Timestamp(_)) => builder.and_is_null(column),
+        (TrashedScope::Only, SoftDeleteKind::Int { active, .. }) => builder.and_ne(column, active),
+        (TrashedScope::Only, SoftDeleteKind::Bool { deleted }) => builder.and_eq(column, deleted),
+        (TrashedScope::Only, SoftDeleteKind::Timestamp(_)) => builder.and_is_not_null(column),
+    }
+}
+
+/// 模型 `M` 在指定查询范围下的逻辑删除条件（用于 UPDATE / DELETE 语句，不占用绑定参数）
+///
+/// 模型未定义逻辑删除字段或范围为 `TrashedScope::With` 时返回 `None`
+pub(crate) fn scope_condition<DB: DatabaseInfo, M: Model>(scope: TrashedScope) -> Option<String> {
+    let field = M::SOFT_DELETE_FIELD?;
+    M::SOFT_DELETE_KIND.scope_condition(scope, &DB::escape_identifier(field))
+}
+
+/// 逻辑删除的 SET 子句（同时刷新更新时间字段）
+///
+/// 返回 SQL 片段以及需要依次绑定当前时间的时间戳类型；模型未定义逻辑删除字段时返回 `None`
+pub(crate) fn deleted_set_clause<DB: DatabaseInfo, M: Model>(
+    placeholder_index: &mut usize,
+) -> Option<(String, Vec<TimestampKind>)> {
+    let field = M::SOFT_DELETE_FIELD?;
+    let mut timestamps = Vec::new();
+    let mut parts = vec![format!(
+        "{} = {}",
+        DB::escape_identifier(field),
+        M::SOFT_DELETE_KIND.deleted_value::<DB>(placeholder_index)
+    )];
+    if let Some(kind) = M::SOFT_DELETE_KIND.timestamp_kind() {
+        if !kind.is_db_side() {
+            timestamps.push(kind);
+        }
+    }
+    if let Some(updated_at) = M::UPDATED_AT_FIELD.filter(|f| *f != field) {
+        let kind = M::UPDATED_AT_KIND;
+        parts.push(format!(
+            "{} = {}",
+            DB::escape_identifier(updated_at),
+            crate::timestamp::placeholder::<DB>(kind, placeholder_index)
+        ));
+        if !kind.is_db_side() {
+            timestamps.push(kind);
+        }
+    }
+    Some((parts.join(", "), timestamps))
+}
+
+impl Default for SoftDeleteKind {
+    fn default() -> Self {
+        SoftDeleteKind::DEFAULT
+    }
+}
+
+fn bool_literal(value: bool) -> &'static str {
+    if value {
+        "TRUE"
+    } else {
+        "FALSE"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query_builder::BindValue;
+    use crate::db_pool::DbDriver;
+
+    struct Archived;
+
+    impl Model for Archived {
+        const TABLE: &'static str = "archived";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const SOFT_DELETE_FIELD: Option<&'static str> = Some("deleted_at");
+        const SOFT_DELETE_KIND: SoftDeleteKind =
+            SoftDeleteKind::Timestamp(TimestampKind::NaiveDateTime);
+    }
+
+    struct Flagged;
+
+    impl Model for Flagged {
+        const TABLE: &'static str = "flagged";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const SOFT_DELETE_FIELD: Option<&'static str> = Some("is_active");
+        const SOFT_DELETE_KIND: SoftDeleteKind = SoftDeleteKind::Bool { deleted: false };
+    }
+
+    #[test]
+    fn test_exclude_deleted() {
+        let builder = apply_trashed_scope::<Archived>(QueryBuilder::new("SELECT * FROM archived"));
+        assert_eq!(
+            builder.into_sql(DbDriver::Postgres),
+            r#"SELECT * FROM archived WHERE "deleted_at" IS NULL"#
+        );
+
+        let builder = apply_trashed_scope::<Flagged>(QueryBuilder::new("SELECT * FROM flagged"));
+        assert_eq!(builder.binds(), vec![BindValue::Bool(true)]);
+    }
+
+    #[test]
+    fn test_trashed_scopes() {
+        let builder = apply_trashed_scope::<Archived>(QueryBuilder::new("SELECT * FROM archived").with_trashed());
+        assert_eq!(builder.into_sql(DbDriver::Postgres), "SELECT * FROM archived");
+
+        let builder = apply_trashed_scope::<Archived>(QueryBuilder::new("SELECT * FROM archived").only_trashed());
+        assert_eq!(
+            builder.into_sql(DbDriver::Postgres),
+            r#"SELECT * FROM archived WHERE "deleted_at" IS NOT NULL"#
+        );
+
+        let builder = apply_trashed_scope::<Flagged>(QueryBuilder::new("SELECT * FROM flagged").only_trashed());
+        assert_eq!(builder.binds(), vec![BindValue::Bool(false)]);
+
+        // 顶层 OR 需要加括号，范围条件才能作用于所有分支
+        let builder = apply_trashed_scope::<Archived>(
+            QueryBuilder::new("SELECT * FROM archived")
+                .and_eq("a", 1i64)
+                .or_eq("b", 2i64)
+                .only_trashed(),
+        );
+        assert_eq!(
+            builder.into_sql(DbDriver::Postgres),
+            r#"SELECT * FROM archived WHERE ("a" = $1 OR "b" = $2) AND "deleted_at" IS NOT NULL"#
+        );
+        let builder = apply_trashed_scope::<Flagged>(
+            QueryBuilder::new("SELECT * FROM flagged").and_eq("a", 1i64).or_in("b", vec![2i64, 3]),
+        );
+        assert_eq!(
+            builder.into_sql(DbDriver::Postgres),
+            r#"SELECT * FROM flagged WHERE ("a" = $1 OR "b" IN ($2, $3)) AND "is_active" = $4"#
+        );
+        assert_eq!(
+            builder.binds(),
+            vec![BindValue::Int64(1), BindValue::Int64(2), BindValue::Int64(3), BindValue::Bool(true)]
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_condition_and_deleted_value() {
+        let custom = SoftDeleteKind::Int { active: 1, deleted: -1 };
+        assert_eq!(custom.active_condition(r#""state""#), r#""state" = 1"#);
+        assert_eq!(
+            SoftDeleteKind::Bool { deleted: true }.active_condition("\"is_deleted\""),
+            "\"is_deleted\" = FALSE"
+        );
+
+        assert_eq!(
+            custom.scope_condition(TrashedScope::Only, r#""state""#).as_deref(),
+            Some(r#""state" <> 1"#)
+        );
+        assert_eq!(custom.scope_condition(TrashedScope::With, r#""state""#), None);
+
+        let mut index = 0;
+        assert_eq!(custom.deleted_value::<sqlx::Postgres>(&mut index), "-1");
+        assert_eq!(index, 0);
+        let archived = SoftDeleteKind::Timestamp(TimestampKind::NaiveDateTime);
+        assert_eq!(archived.deleted_value::<sqlx::Postgres>(&mut index), "$1");
+        assert_eq!(index, 1);
+        let db_side = SoftDeleteKind::Timestamp(TimestampKind::CurrentTimestamp);
+        assert_eq!(db_side.deleted_value::<sqlx::Postgres>(&mut index), "CURRENT_TIMESTAMP");
+        assert_eq!(index, 1);
+    }
+}
@@ -0,0 +1,152 @@
+//! 自动时间戳字段
+//!
+//! 通过 `#[model(created_at = "...", updated_at = "...")]` 指定创建 / 更新时间字段
+//! （未指定时自动识别列名为 `created_at` / `updated_at` 的字段）。字段值为空时，
+//! insert、update、`soft_delete_by_id` 以及各个 Builder 会按列的 [`TimestampKind`] 填充当前时间。
+//!
+//! 时间戳类型默认根据字段类型推断（`i64` 为毫秒、`NaiveDateTime`、`DateTime<Utc>`），
+//! 也可以通过 `#[column(timestamp = "millis" | "seconds" | "naive" | "utc" | "db")]` 显式指定。
+
+use crate::builder::query_builder::BindValue;
+use crate::database_info::DatabaseInfo;
+use crate::traits::Model;
+
+/// 数据库端当前时间表达式（`TimestampKind::CurrentTimestamp` 使用）
+pub const CURRENT_TIMESTAMP: &str = "CURRENT_TIMESTAMP";
+
+/// 自动时间戳字段的取值方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampKind {
+    /// Unix 毫秒时间戳（`i64`），默认值
+    EpochMillis,
+    /// Unix 秒时间戳（`i64`）
+    EpochSeconds,
+    /// `chrono::NaiveDateTime`（UTC 时间）
+    NaiveDateTime,
+    /// `chrono::DateTime<Utc>`
+    DateTimeUtc,
+    /// 由数据库生成：SQL 中直接写入 `CURRENT_TIMESTAMP`，不绑定参数
+    CurrentTimestamp,
+}
+
+impl TimestampKind {
+    /// 指定时间对应的绑定值；`CurrentTimestamp` 由数据库生成，返回 `None`
+    pub fn value_at(self, now: chrono::DateTime<chrono::Utc>) -> Option<BindValue> {
+        match self {
+            TimestampKind::EpochMillis => Some(BindValue::Int64(now.timestamp_millis())),
+            TimestampKind::EpochSeconds => Some(BindValue::Int64(now.timestamp())),
+            TimestampKind::NaiveDateTime => Some(BindValue::NaiveDateTime(now.naive_utc())),
+            TimestampKind::DateTimeUtc => Some(BindValue::DateTime(now)),
+            TimestampKind::CurrentTimestamp => None,
+        }
+    }
+
+    /// 是否由数据库生成当前时间
+    pub fn is_db_side(self) -> bool {
+        self == TimestampKind::CurrentTimestamp
+    }
+}
+
+/// 当前 UTC 时间（同一条语句中的多个时间戳字段共用一个时间点）
+pub fn now() -> chrono::DateTime<chrono::Utc> {
+    chrono::Utc::now()
+}
+
+/// 插入时需要自动填充的时间戳列（创建时间和更新时间）的类型
+pub fn insert_timestamp_kind<M: Model>(column: &str) -> Option<TimestampKind> {
+    if M::CREATED_AT_FIELD == Some(column) {
+        Some(M::CREATED_AT_KIND)
+    } else {
+        update_timestamp_kind::<M>(column)
+    }
+}
+
+/// 更新时需要自动填充的时间戳列（更新时间）的类型
+pub fn update_timestamp_kind<M: Model>(column: &str) -> Option<TimestampKind> {
+    if M::UPDATED_AT_FIELD == Some(column) {
+        Some(M::UPDATED_AT_KIND)
+    } else {
+        None
+    }
+}
+
+/// 时间戳列在 SQL 中的取值：由数据库生成时为 [`CURRENT_TIMESTAMP`]，否则为下一个占位符
+pub fn placeholder<DB: DatabaseInfo>(kind: TimestampKind, index: &mut usize) -> String {
+    if kind.is_db_side() {
+        CURRENT_TIMESTAMP.to_string()
+    } else {
+        let placeholder = DB::placeholder(*index);
+        *index += 1;
+        placeholder
+    }
+}
+
+/// 按时间戳类型为查询绑定当前时间（供 derive 生成的代码使用）
+///
+/// `CurrentTimestamp` 不绑定参数，对应位置应在 SQL 中写入 [`CURRENT_TIMESTAMP`]。
+#[macro_export]
+macro_rules! bind_timestamp {
+    ($query:expr, $kind:expr, $now:expr) => {
+        match $kind {
+            $crate::timestamp::TimestampKind::EpochMillis => {
+                $query = $query.bind($now.timestamp_millis());
+            }
+            $crate::timestamp::TimestampKind::EpochSeconds => {
+                $query = $query.bind($now.timestamp());
+            }
+            $crate::timestamp::TimestampKind::NaiveDateTime => {
+                $query = $query.bind($now.naive_utc());
+            }
+            $crate::timestamp::TimestampKind::DateTimeUtc => {
+                $query = $query.bind($now);
+            }
+            $crate::timestamp::TimestampKind::CurrentTimestamp => {}
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Post;
+
+    impl Model for Post {
+        const TABLE: &'static str = "post";
+        const PK: &'static str = "id";
+        type Id = i64;
+        const CREATED_AT_FIELD: Option<&'static str> = Some("created");
+        const CREATED_AT_KIND: TimestampKind = TimestampKind::DateTimeUtc;
+        const UPDATED_AT_FIELD: Option<&'static str> = Some("modified");
+        const UPDATED_AT_KIND: TimestampKind = TimestampKind::CurrentTimestamp;
+    }
+
+    #[test]
+    fn test_timestamp_kind_lookup() {
+        assert_eq!(insert_timestamp_kind::<Post>("created"), Some(TimestampKind::DateTimeUtc));
+        assert_eq!(insert_timestamp_kind::<Post>("modified"), Some(TimestampKind::CurrentTimestamp));
+        assert_eq!(update_timestamp_kind::<Post>("created"), None);
+        // 不再按列名 created_at / updated_at 猜测
+        assert_eq!(insert_timestamp_kind::<Post>("created_at"), None);
+        assert_eq!(update_timestamp_kind::<Post>("updated_at"), None);
+    }
+
+    #[test]
+    fn test_value_at() {
+        let now = chrono::DateTime::from_timestamp(1_700_000_000, 123_000_000).unwrap();
+        assert_eq!(
+            TimestampKind::EpochMillis.value_at(now),
+            Some(BindValue::Int64(1_700_000_000_123))
+        );
+        assert_eq!(
+            TimestampKind::EpochSeconds.value_at(now),
+            Some(BindValue::Int64(1_700_000_000))
+        );
+        assert_eq!(
+            TimestampKind::NaiveDateTime.value_at(now),
+            Some(BindValue::NaiveDateTime(now.naive_utc()))
+        );
+        assert_eq!(TimestampKind::DateTimeUtc.value_at(now), Some(BindValue::DateTime(now)));
+        assert_eq!(TimestampKind::CurrentTimestamp.value_at(now), None);
+    }
+}
@@ -1,20 +1,54 @@
 use crate::builder::query_builder::QueryBuilder;
 use crate::crud::Page;
 use crate::error::Result;
-
-/// 主键 ID 类型
-pub type Id = i64;
+use crate::macros_api::ModelMeta;
+use crate::primary_key::PrimaryKey;
+use crate::soft_delete::SoftDeleteKind;
+use crate::timestamp::TimestampKind;
 
 /// Model trait 定义了模型的基本元数据
 pub trait Model: Sized {
     /// 表名
     const TABLE: &'static str;
-    /// 主键字段名
+    /// 主键字段名（复合主键以逗号分隔，如 `"tenant_id,user_id"`）
     const PK: &'static str;
+    /// 主键类型（由主键字段类型推导，复合主键为元组）
+    type Id: PrimaryKey;
     /// 逻辑删除字段名（可选），如果为 Some，则使用逻辑删除
     const SOFT_DELETE_FIELD: Option<&'static str> = None;
-    /// 更新时间字段名（可选），软删除时会自动更新此字段为当前毫秒时间戳
+    /// 逻辑删除字段的取值方式，默认整数标记（0 未删除，1 已删除）
+    const SOFT_DELETE_KIND: SoftDeleteKind = SoftDeleteKind::DEFAULT;
+    /// 创建时间字段名（可选），插入时字段值为空则自动填充当前时间
+    const CREATED_AT_FIELD: Option<&'static str> = None;
+    /// 创建时间字段的时间戳类型
+    const CREATED_AT_KIND: TimestampKind = TimestampKind::EpochMillis;
+    /// 更新时间字段名（可选），插入、更新和软删除时字段值为空则自动填充当前时间
     const UPDATED_AT_FIELD: Option<&'static str> = None;
+    /// 更新时间字段的时间戳类型
+    const UPDATED_AT_KIND: TimestampKind = TimestampKind::EpochMillis;
+    /// 唯一约束字段名（`#[column(unique)]`），upsert 未指定冲突列时默认使用第一个
+    const UNIQUE_FIELDS: &'static [&'static str] = &[];
+    /// 乐观锁版本字段名（`#[model(version = "...")]`），更新时自动校验并自增
+    const VERSION_FIELD: Option<&'static str> = None;
+    /// 查询时显式选择的列（由 ModelMeta 根据字段生成，不含 `#[skip]` 字段），为空时使用 `SELECT *`
+    const COLUMNS: &'static [&'static str] = &[];
+    /// 默认数据源名称（`#[model(datasource = "...")]`），为 None 时使用 `DbRegistry` 中的 `"default"`
+    const DATASOURCE: Option<&'static str> = None;
+
+    /// 运行时模型元数据（表、主键以及每个字段的 `#[column(...)]` 定义）
+    ///
+    /// derive(ModelMeta) 会生成包含全部字段的实现；手动实现 Model 时默认只包含表级信息
+    fn meta() -> &'static ModelMeta {
+        &const {
+            ModelMeta {
+                table: Self::TABLE,
+                pk: Self::PK,
+                soft_delete: Self::SOFT_DELETE_FIELD,
+                fields: &[],
+                table_comment: None,
+            }
+        }
+    }
 }
 
 /// Crud trait 提供了基本的 CRUD 操作
@@ -37,6 +71,9 @@ pub trait Crud:
 {
     /// 插入记录
     ///
+    /// 返回插入记录的主键：主键字段已赋值时原样返回（并写入主键列），
+    /// 整数主键未赋值时返回数据库生成的自增 ID，其他类型的主键未赋值时返回错误。
+    ///
     /// 根据传入的 Pool 或 Transaction 自动推断数据库类型，无需显式指定数据库类型参数。
     ///
     /// # 示例
@@ -48,11 +85,12 @@ pub trait Crud:
     /// // 使用 Transaction（自动推断为 MySql）
     /// let id = user.insert(tx.as_mysql_executor()).await?;
     /// ```
-    async fn insert<'e, 'c: 'e, DB, E>(&self, executor: E) -> Result<Id>
+    async fn insert<'e, 'c: 'e, DB, E>(&self, executor: E) -> Result<Self::Id>
     where
         DB: sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
         E: crate::database_type::DatabaseType<DB = DB> + sqlx::Executor<'c, Database = DB> + Send,
+        Self::Id: crate::primary_key::BindPrimaryKey<DB>,
         // PostgreSQL 使用 query_scalar 需要这些约束
         i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
         usize: sqlx::ColumnIndex<DB::Row>,
@@ -85,6 +123,66 @@ pub trait Crud:
         serde_json::Value: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
         Option<serde_json::Value>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>;
 
+    /// 批量插入记录
+    ///
+    /// 生成多行 `INSERT ... VALUES (...), (...)` 语句，并按数据库绑定参数上限自动拆分
+    /// （MySQL / PostgreSQL 65535，SQLite 999，兼容 3.32.0 之前的默认上限）。相邻且插入列一致（`Option` 字段是否为 `Some` 相同）
+    /// 的记录会合并到同一条语句中。
+    ///
+    /// 返回值与传入记录一一对应的主键（主键字段已赋值的记录原样返回，其余由数据库生成）：
+    /// - PostgreSQL / SQLite：通过 `RETURNING` 获取
+    /// - MySQL：通过 `LAST_INSERT_ID()` 推算连续区间（要求 `auto_increment_increment = 1`）
+    ///
+    /// 注意：拆分为多条语句时不会自动开启事务，需要原子性请在事务中调用。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// // 使用 Pool（自动推断为 MySql）
+    /// let ids = User::insert_many(&users, pool.mysql_pool()).await?;
+    ///
+    /// // 使用 Transaction
+    /// let ids = User::insert_many(&users, tx.as_mysql_executor()).await?;
+    /// ```
+    async fn insert_many<'e, 'c: 'e, DB, E>(models: &[Self], executor: E) -> Result<Vec<Self::Id>>
+    where
+        DB: sqlx::Database + crate::database_info::DatabaseInfo,
+        for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+        E: crate::database_type::DatabaseType<DB = DB> + sqlx::Acquire<'c, Database = DB> + Send,
+        Self::Id: crate::primary_key::BindPrimaryKey<DB>,
+        for<'x> &'x mut DB::Connection: sqlx::Executor<'x, Database = DB>,
+        // RETURNING 需要这些约束
+        i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+        usize: sqlx::ColumnIndex<DB::Row>,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+        String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i64: for<'b> sqlx::Encode<'b, DB>,
+        i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<i64>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<i32>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<i16>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<f64>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<f32>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<bool>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<chrono::DateTime<chrono::Utc>>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<chrono::NaiveDateTime>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveDate: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<chrono::NaiveDate>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        chrono::NaiveTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<chrono::NaiveTime>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<Vec<u8>>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        serde_json::Value: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+        Option<serde_json::Value>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>;
+
     /// 更新记录（Patch 语义）
     ///
     /// - 非 `Option` 字段：始终参与更新，生成 `SET col = ?` 并绑定当前值。
@@ -92,6 +190,9 @@ pub trait Crud:
     ///   - `Some(v)`：生成 `SET col = ?` 并绑定 `v`；
     ///   - `None`：不生成对应的 `SET` 子句，即**不修改该列**，保留数据库中的原值。
     ///
+    /// 配置了乐观锁版本字段（`#[model(version = "...")]`）时，数据库中的版本会自增，但 `&self` 中的版本字段不会改变：
+    /// 用同一个值再次更新前需要重新查询，或自行将版本字段加 1，否则会返回 `SqlxPlusError::StaleVersion`。
+    ///
     /// 根据传入的 Pool 或 Transaction 自动推断数据库类型，无需显式指定数据库类型参数。
     ///
     /// # 示例
@@ -108,6 +209,7 @@ pub trait Crud:
         DB: sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
         E: crate::database_type::DatabaseType<DB = DB> + sqlx::Executor<'c, Database = DB> + Send,
+        Self::Id: crate::primary_key::BindPrimaryKey<DB>,
         // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
         String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
         i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
@@ -143,6 +245,9 @@ pub trait Crud:
     ///   - Some(v)：更新为 v
     ///   - None：更新为数据库默认值（等价于 `SET col = DEFAULT`，具体行为由数据库决定）
     ///
+    /// 配置了乐观锁版本字段（`#[model(version = "...")]`）时，数据库中的版本会自增，但 `&self` 中的版本字段不会改变：
+    /// 用同一个值再次更新前需要重新查询，或自行将版本字段加 1，否则会返回 `SqlxPlusError::StaleVersion`。
+    ///
     /// 根据传入的 Pool 或 Transaction 自动推断数据库类型，无需显式指定数据库类型参数。
     ///
     /// # 示例
@@ -159,6 +264,7 @@ pub trait Crud:
         DB: sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
         E: crate::database_type::DatabaseType<DB = DB> + sqlx::Executor<'c, Database = DB> + Send,
+        Self::Id: crate::primary_key::BindPrimaryKey<DB>,
         // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
         String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
         i64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
@@ -202,10 +308,7 @@ pub trait Crud:
     /// ```
     async fn find_by_id<'e, 'c: 'e, E>(
         executor: E,
-        id: impl for<'q> sqlx::Encode<'q, <E as crate::database_type::DatabaseType>::DB>
-            + sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + Send
-            + Sync,
+        id: impl Into<Self::Id> + Send,
     ) -> Result<Option<Self>>
     where
         E: crate::database_type::DatabaseType
@@ -215,6 +318,7 @@ pub trait Crud:
             sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
             sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self::Id: crate::primary_key::BindPrimaryKey<<E as crate::database_type::DatabaseType>::DB>,
         Self: for<'r> sqlx::FromRow<
                 'r,
                 <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
@@ -222,7 +326,8 @@ pub trait Crud:
             + Unpin,
     {
         crate::crud::find_by_id::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
-            executor, id,
+            executor,
+            id.into(),
         )
         .await
     }
@@ -249,17 +354,14 @@ pub trait Crud:
             sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
             sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self::Id: crate::primary_key::BindPrimaryKey<<E as crate::database_type::DatabaseType>::DB>,
         Self: for<'r> sqlx::FromRow<
                 'r,
                 <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
             > + Send
             + Unpin,
         I: IntoIterator + Send,
-        I::Item: for<'q> sqlx::Encode<'q, <E as crate::database_type::DatabaseType>::DB>
-            + sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + Send
-            + Sync
-            + Clone,
+        I::Item: Into<Self::Id>,
     {
         crate::crud::find_by_ids::<<E as crate::database_type::DatabaseType>::DB, Self, I, E>(
             executor, ids,
@@ -317,6 +419,10 @@ pub trait Crud:
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
         Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
     {
         crate::crud::find_one::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
             executor, builder,
@@ -380,6 +486,10 @@ pub trait Crud:
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
         Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
     {
         crate::crud::find_all::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
             executor, builder,
@@ -387,6 +497,191 @@ pub trait Crud:
         .await
     }
 
+    /// 根据查询构建器流式读取记录
+    ///
+    /// 基于 sqlx 的 `fetch` 逐条读取，不会把结果收集到 `Vec` 中，也没有 `find_all` 的 1000 条上限，
+    /// 适合导出大量数据。绑定值和逻辑删除过滤与 `find_all` 一致。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let builder = QueryBuilder::new("").and_eq("status", 1).order_by("id", true);
+    /// let mut users = User::stream(pool.mysql_pool(), Some(builder));
+    /// while let Some(user) = users.try_next().await? {
+    ///     // ...
+    /// }
+    /// ```
+    fn stream<'e, 'c: 'e, E>(
+        executor: E,
+        builder: Option<QueryBuilder>,
+    ) -> crate::crud::RecordStream<'e, Self>
+    where
+        E: crate::database_type::DatabaseType
+            + sqlx::Executor<'c, Database = <E as crate::database_type::DatabaseType>::DB>
+            + Send
+            + 'e,
+        <E as crate::database_type::DatabaseType>::DB:
+            sqlx::Database + crate::database_info::DatabaseInfo,
+        for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
+            sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self: for<'r> sqlx::FromRow<
+                'r,
+                <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
+            > + Send
+            + Unpin
+            + 'e,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+        String: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i16: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        bool: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Vec<u8>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+    {
+        crate::crud::stream::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
+            executor, builder,
+        )
+    }
+
+    /// 分批流式读取记录：每次产出最多 `chunk_size` 条记录
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut batches = User::stream_chunks(pool.mysql_pool(), None, 500);
+    /// while let Some(batch) = batches.try_next().await? {
+    ///     writer.write_batch(&batch)?;
+    /// }
+    /// ```
+    fn stream_chunks<'e, 'c: 'e, E>(
+        executor: E,
+        builder: Option<QueryBuilder>,
+        chunk_size: usize,
+    ) -> crate::crud::RecordStream<'e, Vec<Self>>
+    where
+        E: crate::database_type::DatabaseType
+            + sqlx::Executor<'c, Database = <E as crate::database_type::DatabaseType>::DB>
+            + Send
+            + 'e,
+        <E as crate::database_type::DatabaseType>::DB:
+            sqlx::Database + crate::database_info::DatabaseInfo,
+        for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
+            sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self: for<'r> sqlx::FromRow<
+                'r,
+                <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
+            > + Send
+            + Unpin
+            + 'e,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+        String: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i16: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        bool: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Vec<u8>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+    {
+        crate::crud::stream_chunks::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
+            executor, builder, chunk_size,
+        )
+    }
+
+    /// 根据查询构建器查找所有记录，并映射到自定义的 `FromRow` 类型（如只包含部分字段的 DTO）
+    ///
+    /// 配合 `QueryBuilder::select` 只查询需要的列。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// let builder = QueryBuilder::new("").select(&[User::ID, User::USERNAME]);
+    /// let briefs = User::find_all_as::<UserBrief, _>(pool.mysql_pool(), Some(builder)).await?;
+    /// ```
+    async fn find_all_as<'e, 'c: 'e, T, E>(
+        executor: E,
+        builder: Option<QueryBuilder>,
+    ) -> Result<Vec<T>>
+    where
+        E: crate::database_type::DatabaseType
+            + sqlx::Executor<'c, Database = <E as crate::database_type::DatabaseType>::DB>
+            + Send,
+        <E as crate::database_type::DatabaseType>::DB:
+            sqlx::Database + crate::database_info::DatabaseInfo,
+        for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
+            sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        T: for<'r> sqlx::FromRow<
+                'r,
+                <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
+            > + Send
+            + Unpin,
+        // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+        // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+        String: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        i16: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        f32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        bool: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Vec<u8>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+    {
+        crate::crud::find_all_as::<<E as crate::database_type::DatabaseType>::DB, Self, T, E>(
+            executor, builder,
+        )
+        .await
+    }
+
     /// 统计记录数量
     ///
     /// 根据传入的 Pool 或 Transaction 自动推断数据库类型，无需显式指定数据库类型参数。
@@ -434,6 +729,10 @@ pub trait Crud:
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
         Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
         usize: sqlx::ColumnIndex<
             <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
         >,
@@ -502,6 +801,10 @@ pub trait Crud:
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
         Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
         usize: sqlx::ColumnIndex<
             <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
         >,
@@ -515,28 +818,28 @@ pub trait Crud:
     /// 游标分页查询
     ///
     /// 相比于 `paginate`，游标分页不需要执行 `COUNT` 查询，且在深翻页时性能更好。
-    /// 
+    ///
     /// 根据传入的 Pool 或 Transaction 自动推断数据库类型，无需显式指定数据库类型参数。
-    /// 如果你提供了 `cursor` 参数，默认会自动为你补充 `AND pk > cursor` 的升序分页条件。
-    /// 如果这不符合你的要求（比如降序或多条件游标，以及以其他字段为游标），请传入 `None` 并在 `builder` 中自行构建相关的条件。
+    /// 按 `builder` 中的排序字段（如 `created_at DESC, id DESC`）进行键集分页，未包含主键时自动追加主键；
+    /// 返回的 `next_cursor` / `prev_cursor` 为不透明的游标字符串，编码了边界记录的全部排序字段值。
     ///
     /// # 示例
     ///
     /// ```rust,ignore
     /// use sqlxplus::QueryBuilder;
     ///
-    /// // 第一次查询
-    /// let builder = QueryBuilder::new("").order_by("id", true);
-    /// let page = User::paginate_cursor(pool.mysql_pool(), builder, None, 10).await?;
+    /// // 第一页
+    /// let builder = QueryBuilder::new("").order_by("created_at", false).order_by("id", false);
+    /// let page = User::paginate_cursor(pool.mysql_pool(), builder.clone(), None, 10).await?;
     ///
-    /// // 获取下一页（假设上一页最后一条记录的 id 为 `last_id`）
-    /// let builder = QueryBuilder::new("").order_by("id", true);
-    /// let next_page = User::paginate_cursor(pool.mysql_pool(), builder, Some(last_id), 10).await?;
+    /// // 下一页 / 上一页（使用相同的排序条件）
+    /// let next = User::paginate_cursor(pool.mysql_pool(), builder.clone(), page.next_cursor.as_deref(), 10).await?;
+    /// let prev = User::paginate_cursor(pool.mysql_pool(), builder, next.prev_cursor.as_deref(), 10).await?;
     /// ```
     async fn paginate_cursor<'e, 'c: 'e, E>(
         executor: E,
         builder: QueryBuilder,
-        cursor: Option<i64>,
+        cursor: Option<&str>,
         size: u32,
     ) -> Result<crate::crud::CursorPage<Self>>
     where
@@ -553,7 +856,8 @@ pub trait Crud:
             > + Send
             + Unpin,
         String: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
             + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
@@ -561,17 +865,28 @@ pub trait Crud:
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
             + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         i16: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         f64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         f32: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         bool: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         Vec<u8>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         Option<String>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>
+            + for<'r> sqlx::Decode<'r, <E as crate::database_type::DatabaseType>::DB>,
         for<'a> &'a str: sqlx::ColumnIndex<
             <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Row,
         >,
@@ -597,10 +912,7 @@ pub trait Crud:
     /// ```
     async fn hard_delete_by_id<'e, 'c: 'e, E>(
         executor: E,
-        id: impl for<'q> sqlx::Encode<'q, <E as crate::database_type::DatabaseType>::DB>
-            + sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + Send
-            + Sync,
+        id: impl Into<Self::Id> + Send,
     ) -> Result<()>
     where
         E: crate::database_type::DatabaseType
@@ -610,9 +922,11 @@ pub trait Crud:
             sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
             sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self::Id: crate::primary_key::BindPrimaryKey<<E as crate::database_type::DatabaseType>::DB>,
     {
         crate::crud::hard_delete_by_id::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
-            executor, id,
+            executor,
+            id.into(),
         )
         .await
     }
@@ -632,10 +946,7 @@ pub trait Crud:
     /// ```
     async fn soft_delete_by_id<'e, 'c: 'e, E>(
         executor: E,
-        id: impl for<'q> sqlx::Encode<'q, <E as crate::database_type::DatabaseType>::DB>
-            + sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + Send
-            + Sync,
+        id: impl Into<Self::Id> + Send,
     ) -> Result<()>
     where
         E: crate::database_type::DatabaseType
@@ -645,11 +956,59 @@ pub trait Crud:
             sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
             sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self::Id: crate::primary_key::BindPrimaryKey<<E as crate::database_type::DatabaseType>::DB>,
         i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
     {
         crate::crud::soft_delete_by_id::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
-            executor, id,
+            executor,
+            id.into(),
+        )
+        .await
+    }
+
+    /// 根据 ID 恢复逻辑删除的记录
+    ///
+    /// 逻辑删除字段恢复为未删除值，并刷新更新时间字段。
+    ///
+    /// 根据传入的 Pool 或 Transaction 自动推断数据库类型，无需显式指定数据库类型参数。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// // 使用 Pool（自动推断为 MySql）
+    /// User::restore_by_id(pool.mysql_pool(), 1).await?;
+    ///
+    /// // 使用 Transaction（自动推断为 MySql）
+    /// User::restore_by_id(tx.as_mysql_executor(), 1).await?;
+    /// ```
+    async fn restore_by_id<'e, 'c: 'e, E>(
+        executor: E,
+        id: impl Into<Self::Id> + Send,
+    ) -> Result<()>
+    where
+        E: crate::database_type::DatabaseType
+            + sqlx::Executor<'c, Database = <E as crate::database_type::DatabaseType>::DB>
+            + Send,
+        <E as crate::database_type::DatabaseType>::DB:
+            sqlx::Database + crate::database_info::DatabaseInfo,
+        for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
+            sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self::Id: crate::primary_key::BindPrimaryKey<<E as crate::database_type::DatabaseType>::DB>,
+        i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+    {
+        crate::crud::restore_by_id::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
+            executor,
+            id.into(),
         )
         .await
     }
@@ -670,10 +1029,7 @@ pub trait Crud:
     /// ```
     async fn delete_by_id<'e, 'c: 'e, E>(
         executor: E,
-        id: impl for<'q> sqlx::Encode<'q, <E as crate::database_type::DatabaseType>::DB>
-            + sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
-            + Send
-            + Sync,
+        id: impl Into<Self::Id> + Send,
     ) -> Result<()>
     where
         E: crate::database_type::DatabaseType
@@ -683,11 +1039,17 @@ pub trait Crud:
             sqlx::Database + crate::database_info::DatabaseInfo,
         for<'a> <<E as crate::database_type::DatabaseType>::DB as sqlx::Database>::Arguments<'a>:
             sqlx::IntoArguments<'a, <E as crate::database_type::DatabaseType>::DB>,
+        Self::Id: crate::primary_key::BindPrimaryKey<<E as crate::database_type::DatabaseType>::DB>,
         i64: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
             + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::DateTime<chrono::Utc>: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
+        chrono::NaiveDateTime: sqlx::Type<<E as crate::database_type::DatabaseType>::DB>
+            + for<'b> sqlx::Encode<'b, <E as crate::database_type::DatabaseType>::DB>,
     {
         crate::crud::delete_by_id::<<E as crate::database_type::DatabaseType>::DB, Self, E>(
-            executor, id,
+            executor,
+            id.into(),
         )
         .await
     }
@@ -34,12 +34,23 @@ pub fn is_safe_field_name(name: &str) -> bool {
 }
 
 /// 转义 SQL 标识符
+///
+/// 支持带限定符的列名：`u.id` 会转义为 `` `u`.`id` ``，`u.*` 转义为 `` `u`.* ``
 pub fn escape_identifier(driver: DbDriver, name: &str) -> String {
-    match driver {
-        DbDriver::MySql => format!("`{}`", name),
-        DbDriver::Postgres => format!("\"{}\"", name),
-        DbDriver::Sqlite => format!("\"{}\"", name),
-    }
+    let quote = match driver {
+        DbDriver::MySql => '`',
+        DbDriver::Postgres | DbDriver::Sqlite => '"',
+    };
+    name.split('.')
+        .map(|part| {
+            if part == "*" {
+                part.to_string()
+            } else {
+                format!("{}{}{}", quote, part, quote)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
 }
 
 
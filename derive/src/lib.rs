@@ -1,16 +1,11 @@
 use proc_macro::TokenStream;
-use proc_macro2;
 use quote::quote;
 use syn::{parse::Parser, parse_macro_input, Data, DataStruct, DeriveInput, Fields, Meta};
 
 /// 去除原始标识符的 r# 前缀
 /// 例如：r#type -> type
 fn strip_raw_identifier_prefix(ident: &str) -> String {
-    if ident.starts_with("r#") {
-        ident[2..].to_string()
-    } else {
-        ident.to_string()
-    }
+    ident.strip_prefix("r#").unwrap_or(ident).to_string()
 }
 
 /// 解析字段的 column 属性，获取列名
@@ -52,9 +47,469 @@ fn parse_column_name(attrs: &[syn::Attribute], field_name: &str) -> String {
     strip_raw_identifier_prefix(field_name)
 }
 
+/// 检查字段的 column 属性中是否包含指定的标记（如 `#[column(unique)]`）
+fn has_column_flag(attrs: &[syn::Attribute], flag: &str) -> bool {
+    for attr in attrs {
+        if attr.path().is_ident("column") {
+            if let syn::Meta::List(list) = &attr.meta {
+                let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+                if let Ok(metas) = parser.parse2(list.tokens.clone()) {
+                    for meta in metas {
+                        if let Meta::Path(path) = meta {
+                            if path.is_ident(flag) {
+                                return true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// 生成字段的 `sqlxplus::macros_api::FieldMeta`
+///
+/// 解析 `#[column(...)]` 中的 `index`、`combine_index`、`unique`、`not_null`、`default`、`length`、
+/// `auto_increment`、`primary_key`、`soft_delete`、`comment`，与 CLI 生成建表 SQL 时的语义一致；
+/// `combine_index` 未指定顺序时与 CLI 一样记为 `i32::MAX`，建表时再按字段声明顺序排列
+fn field_meta(
+    field: &syn::Field,
+    pk_columns: &[&str],
+    soft_delete_column: Option<&str>,
+) -> proc_macro2::TokenStream {
+    let field_name = strip_raw_identifier_prefix(&field.ident.as_ref().unwrap().to_string());
+    let column = parse_column_name(&field.attrs, &field_name);
+    let inner_type = get_option_inner_type(&field.ty).unwrap_or(&field.ty);
+    let rust_type = quote! { #inner_type }.to_string().replace(' ', "");
+    let nullable = is_option_type(&field.ty);
+    let skip = field.attrs.iter().any(|attr| attr.path().is_ident("skip"));
+
+    let mut index: Option<String> = None;
+    let mut combine_index: Option<(String, i32)> = None;
+    let mut unique = false;
+    let mut not_null = false;
+    let mut default: Option<String> = None;
+    let mut length: Option<u32> = None;
+    let mut auto_increment = false;
+    let mut primary_key = pk_columns.contains(&column.as_str());
+    let mut soft_delete = soft_delete_column == Some(column.as_str());
+    let mut comment: Option<String> = None;
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("column") {
+            continue;
+        }
+        let syn::Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+        let Ok(metas) = parser.parse2(list.tokens.clone()) else {
+            continue;
+        };
+        for meta in metas {
+            match meta {
+                Meta::Path(path) => {
+                    if path.is_ident("index") {
+                        index = Some(String::new());
+                    } else if path.is_ident("unique") {
+                        unique = true;
+                    } else if path.is_ident("not_null") {
+                        not_null = true;
+                    } else if path.is_ident("auto_increment") {
+                        auto_increment = true;
+                    } else if path.is_ident("primary_key") || path.is_ident("pk") {
+                        primary_key = true;
+                    } else if path.is_ident("soft_delete") {
+                        soft_delete = true;
+                    }
+                }
+                Meta::NameValue(nv) => match &nv.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) => {
+                        if nv.path.is_ident("index") {
+                            index = Some(s.value());
+                        } else if nv.path.is_ident("combine_index") {
+                            let value = s.value();
+                            combine_index = Some(match value.split_once(':') {
+                                Some((name, order)) => match order.trim().parse::<i32>() {
+                                    Ok(order) => (name.to_string(), order),
+                                    Err(_) => (value.clone(), 0),
+                                },
+                                None => (value, i32::MAX),
+                            });
+                        } else if nv.path.is_ident("default") {
+                            default = Some(s.value());
+                        } else if nv.path.is_ident("comment") {
+                            comment = Some(s.value());
+                        }
+                    }
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(i),
+                        ..
+                    }) if nv.path.is_ident("length") => {
+                        length = i.base10_parse::<u32>().ok();
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+
+    let opt_str = |value: Option<String>| match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    };
+    let index = opt_str(index);
+    let combine_index = match combine_index {
+        Some((name, order)) => quote! { Some((#name, #order)) },
+        None => quote! { None },
+    };
+    let default = opt_str(default);
+    let comment = opt_str(comment);
+    let length = match length {
+        Some(length) => quote! { Some(#length) },
+        None => quote! { None },
+    };
+
+    quote! {
+        sqlxplus::macros_api::FieldMeta {
+            name: #field_name,
+            column: #column,
+            rust_type: #rust_type,
+            nullable: #nullable,
+            skip: #skip,
+            index: #index,
+            combine_index: #combine_index,
+            unique: #unique,
+            not_null: #not_null,
+            default: #default,
+            length: #length,
+            auto_increment: #auto_increment,
+            primary_key: #primary_key,
+            soft_delete: #soft_delete,
+            comment: #comment,
+        }
+    }
+}
+
+/// 解析字段的 `#[column(timestamp = "...")]` 属性，未指定时根据字段类型推断时间戳类型
+///
+/// 可选值：`millis`、`seconds`、`naive`、`utc`、`db`；`NaiveDateTime` 字段推断为 `naive`，
+/// `DateTime<Utc>` 字段推断为 `utc`，其余（如 `i64`）为 `millis`
+fn timestamp_kind(field: &syn::Field) -> syn::Result<proc_macro2::TokenStream> {
+    let mut explicit: Option<syn::LitStr> = None;
+    for attr in &field.attrs {
+        if attr.path().is_ident("column") {
+            if let syn::Meta::List(list) = &attr.meta {
+                let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+                if let Ok(metas) = parser.parse2(list.tokens.clone()) {
+                    for meta in metas {
+                        if let Meta::NameValue(nv) = meta {
+                            if nv.path.is_ident("timestamp") {
+                                if let syn::Expr::Lit(syn::ExprLit {
+                                    lit: syn::Lit::Str(s),
+                                    ..
+                                }) = nv.value
+                                {
+                                    explicit = Some(s);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let kind = match explicit {
+        Some(lit) => match lit.value().as_str() {
+            "millis" => quote! { EpochMillis },
+            "seconds" => quote! { EpochSeconds },
+            "naive" => quote! { NaiveDateTime },
+            "utc" => quote! { DateTimeUtc },
+            "db" => quote! { CurrentTimestamp },
+            other => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "unknown timestamp kind `{}`, expected one of: millis, seconds, naive, utc, db",
+                        other
+                    ),
+                ))
+            }
+        },
+        None => {
+            let ty = get_option_inner_type(&field.ty).unwrap_or(&field.ty);
+            match last_type_ident(ty).as_deref() {
+                Some("NaiveDateTime") => quote! { NaiveDateTime },
+                Some("DateTime") => quote! { DateTimeUtc },
+                _ => quote! { EpochMillis },
+            }
+        }
+    };
+    Ok(quote! { sqlxplus::TimestampKind::#kind })
+}
+
+/// 确定逻辑删除字段的取值方式（`sqlxplus::SoftDeleteKind`）
+///
+/// `soft_delete_kind = "int" | "bool" | "timestamp"` 优先，否则根据字段类型推断：
+/// `bool` 为布尔标记，`NaiveDateTime` / `DateTime` 为删除时间，其余为整数标记。
+/// 整数和布尔标记可以通过 `soft_delete_active` / `soft_delete_deleted` 自定义取值
+fn soft_delete_kind(
+    field: Option<&syn::Field>,
+    kind: Option<&syn::LitStr>,
+    active: Option<&syn::Expr>,
+    deleted: Option<&syn::Expr>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let inferred = field
+        .map(|field| get_option_inner_type(&field.ty).unwrap_or(&field.ty))
+        .and_then(last_type_ident);
+    let kind_name = match kind {
+        Some(lit) => lit.value(),
+        None => match inferred.as_deref() {
+            Some("bool") => "bool".to_string(),
+            Some("NaiveDateTime") | Some("DateTime") => "timestamp".to_string(),
+            _ => "int".to_string(),
+        },
+    };
+
+    match kind_name.as_str() {
+        "int" => {
+            let active = active.map(parse_int_value).transpose()?.unwrap_or(0);
+            let deleted = deleted.map(parse_int_value).transpose()?.unwrap_or(1);
+            if active == deleted {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "soft_delete_active and soft_delete_deleted must differ",
+                ));
+            }
+            Ok(quote! { sqlxplus::SoftDeleteKind::Int { active: #active, deleted: #deleted } })
+        }
+        "bool" => {
+            let active = active.map(parse_bool_value).transpose()?;
+            let deleted = deleted.map(parse_bool_value).transpose()?;
+            let deleted = match (active, deleted) {
+                (Some(active), Some(deleted)) if active == deleted => {
+                    return Err(syn::Error::new(
+                        proc_macro2::Span::call_site(),
+                        "soft_delete_active and soft_delete_deleted must differ",
+                    ))
+                }
+                (_, Some(deleted)) => deleted,
+                (Some(active), None) => !active,
+                (None, None) => true,
+            };
+            Ok(quote! { sqlxplus::SoftDeleteKind::Bool { deleted: #deleted } })
+        }
+        "timestamp" => {
+            if let Some(expr) = active.or(deleted) {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "soft_delete_active / soft_delete_deleted are not supported for timestamp soft delete",
+                ));
+            }
+            let timestamp = match field {
+                Some(field) => timestamp_kind(field)?,
+                None => quote! { sqlxplus::TimestampKind::EpochMillis },
+            };
+            Ok(quote! { sqlxplus::SoftDeleteKind::Timestamp(#timestamp) })
+        }
+        other => Err(syn::Error::new(
+            kind.map_or_else(proc_macro2::Span::call_site, |lit| lit.span()),
+            format!(
+                "unknown soft_delete_kind `{}`, expected one of: int, bool, timestamp",
+                other
+            ),
+        )),
+    }
+}
+
+/// 解析整数取值（支持 `1`、`-1`、`"1"`）
+fn parse_int_value(expr: &syn::Expr) -> syn::Result<i64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(i),
+            ..
+        }) => i.base10_parse(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(s),
+            ..
+        }) => s
+            .value()
+            .parse()
+            .map_err(|_| syn::Error::new(s.span(), "expected an integer")),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_int_value(expr).map(|v| -v),
+        _ => Err(syn::Error::new_spanned(expr, "expected an integer")),
+    }
+}
+
+/// 解析布尔取值（支持 `true`、`"true"`）
+fn parse_bool_value(expr: &syn::Expr) -> syn::Result<bool> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Bool(b),
+            ..
+        }) => Ok(b.value),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(s),
+            ..
+        }) => s
+            .value()
+            .parse()
+            .map_err(|_| syn::Error::new(s.span(), "expected true or false")),
+        _ => Err(syn::Error::new_spanned(expr, "expected true or false")),
+    }
+}
+
+/// 获取类型路径最后一段的标识符（如 `chrono::NaiveDateTime` -> `NaiveDateTime`）
+fn last_type_ident(ty: &syn::Type) -> Option<String> {
+    if let syn::Type::Path(type_path) = ty {
+        type_path.path.segments.last().map(|seg| seg.ident.to_string())
+    } else {
+        None
+    }
+}
+
+/// 整数类型（单列整数主键视为数据库自增主键）
+fn is_integer_type(ty: &syn::Type) -> bool {
+    matches!(
+        last_type_ident(ty).as_deref(),
+        Some("i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize")
+    )
+}
+
+/// 按 `#[model(pk = "...")]` 的列顺序查找主键字段（复合主键以逗号分隔，匹配字段名或列名）
+fn pk_fields<'a>(
+    name: &syn::Ident,
+    fields: &'a syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
+    pk: &str,
+) -> syn::Result<Vec<&'a syn::Field>> {
+    pk.split(',')
+        .map(str::trim)
+        .map(|pk_column| {
+            fields
+                .iter()
+                .find(|field| {
+                    field.ident.as_ref().is_some_and(|ident| {
+                        let field_name = ident.to_string();
+                        strip_raw_identifier_prefix(&field_name) == pk_column
+                            || parse_column_name(&field.attrs, &field_name) == pk_column
+                    })
+                })
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        name,
+                        format!("primary key field `{}` not found in struct", pk_column),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// 主键字段去掉 `Option` 后的类型
+fn pk_inner_type(field: &syn::Field) -> &syn::Type {
+    get_option_inner_type(&field.ty).unwrap_or(&field.ty)
+}
+
+/// 解析结构体上的 `#[relation(...)]` 属性，为每个关系生成 `sqlxplus::Related` 实现
+///
+/// - `#[relation(has_many = "Order", foreign_key = "user_id")]`：可选 `local_key`，默认当前模型主键
+/// - `#[relation(belongs_to = "User", foreign_key = "user_id")]`：可选 `owner_key`，默认关联模型主键
+fn relation_impls(
+    name: &syn::Ident,
+    attrs: &[syn::Attribute],
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let mut impls = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("relation") {
+            continue;
+        }
+
+        let mut has_many: Option<syn::LitStr> = None;
+        let mut belongs_to: Option<syn::LitStr> = None;
+        let mut foreign_key: Option<syn::LitStr> = None;
+        let mut local_key: Option<syn::LitStr> = None;
+        let mut owner_key: Option<syn::LitStr> = None;
+        attr.parse_nested_meta(|meta| {
+            let slot = if meta.path.is_ident("has_many") {
+                &mut has_many
+            } else if meta.path.is_ident("belongs_to") {
+                &mut belongs_to
+            } else if meta.path.is_ident("foreign_key") {
+                &mut foreign_key
+            } else if meta.path.is_ident("local_key") {
+                &mut local_key
+            } else if meta.path.is_ident("owner_key") {
+                &mut owner_key
+            } else {
+                return Err(meta.error("unsupported relation attribute"));
+            };
+            *slot = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+
+        let foreign_key = foreign_key
+            .ok_or_else(|| syn::Error::new_spanned(attr, "relation requires `foreign_key`"))?;
+        let (kind, target, local, remote) = match (has_many, belongs_to) {
+            (Some(target), None) => {
+                if let Some(owner_key) = &owner_key {
+                    return Err(syn::Error::new_spanned(
+                        owner_key,
+                        "`owner_key` is only valid for belongs_to",
+                    ));
+                }
+                let target: syn::Path = target.parse()?;
+                let local = match local_key {
+                    Some(key) => quote! { #key },
+                    None => quote! { <#name as sqlxplus::Model>::PK },
+                };
+                (quote! { HasMany }, target, local, quote! { #foreign_key })
+            }
+            (None, Some(target)) => {
+                if let Some(local_key) = &local_key {
+                    return Err(syn::Error::new_spanned(
+                        local_key,
+                        "`local_key` is only valid for has_many",
+                    ));
+                }
+                let target: syn::Path = target.parse()?;
+                let remote = match owner_key {
+                    Some(key) => quote! { #key },
+                    None => quote! { <#target as sqlxplus::Model>::PK },
+                };
+                (quote! { BelongsTo }, target, quote! { #foreign_key }, remote)
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "relation requires exactly one of `has_many` or `belongs_to`",
+                ))
+            }
+        };
+
+        impls.push(quote! {
+            impl sqlxplus::Related<#target> for #name {
+                const KIND: sqlxplus::RelationKind = sqlxplus::RelationKind::#kind;
+                const LOCAL_KEY: &'static str = #local;
+                const REMOTE_KEY: &'static str = #remote;
+            }
+        });
+    }
+    Ok(impls)
+}
+
 /// 生成 Model trait 的实现
 ///
-/// 自动生成 `TABLE`、`PK` 和可选的 `SOFT_DELETE_FIELD` 常量
+/// 自动生成 `TABLE`、`PK` 和可选的 `SOFT_DELETE_FIELD` 常量，
+/// 并为每个字段生成类型化的列常量（如 `User::NAME`）和 `User::columns()`
 ///
 /// 使用示例：
 /// ```ignore
@@ -74,8 +529,51 @@ fn parse_column_name(attrs: &[syn::Attribute], field_name: &str) -> String {
 ///     name: String,
 ///     is_deleted: i32, // 逻辑删除字段：0=未删除，1=已删除
 /// }
+///
+/// // 乐观锁：update 时追加 `AND version = ?` 并自增数据库中的版本，未更新到行时返回 `SqlxPlusError::StaleVersion`；
+/// // update 不修改结构体中的版本字段，再次更新同一个值前需要重新查询或自行加 1
+/// #[derive(ModelMeta)]
+/// #[model(table = "docs", pk = "id", version = "version")]
+/// struct Doc {
+///     id: i64,
+///     version: i32,
+/// }
+///
+/// // 自动时间戳：字段为 None 时由 insert / update 填充，类型按字段类型推断或 `timestamp` 指定
+/// #[derive(ModelMeta)]
+/// #[model(table = "posts", pk = "id", created_at = "create_time")]
+/// struct Post {
+///     id: i64,
+///     create_time: Option<chrono::NaiveDateTime>,
+///     #[column(timestamp = "db")]
+///     updated_at: Option<chrono::NaiveDateTime>,
+/// }
+///
+/// // 运行时元数据：`Article::meta()` 返回表、主键以及每个字段的 `#[column(...)]` 定义
+/// #[derive(ModelMeta)]
+/// #[model(table = "article", pk = "id", table_comment = "文章")]
+/// struct Article {
+///     id: i64,
+///     #[column(length = 200, not_null, index, comment = "标题")]
+///     title: Option<String>,
+/// }
+///
+/// // 多数据源：`DbRegistry::pool_for::<AuditLog>()` 返回名为 "audit" 的数据源
+/// #[derive(ModelMeta)]
+/// #[model(table = "audit_log", pk = "id", datasource = "audit")]
+/// struct AuditLog {
+///     id: i64,
+/// }
+///
+/// // 关联关系：生成 `Related<Order>` 实现，配合 `crud::load_related` / `Vec<User>::with` 使用
+/// #[derive(ModelMeta)]
+/// #[model(table = "users", pk = "id")]
+/// #[relation(has_many = "Order", foreign_key = "user_id")]
+/// struct UserWithOrders {
+///     id: i64,
+/// }
 /// ```
-#[proc_macro_derive(ModelMeta, attributes(model, column))]
+#[proc_macro_derive(ModelMeta, attributes(model, column, relation))]
 pub fn derive_model_meta(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -84,7 +582,14 @@ pub fn derive_model_meta(input: TokenStream) -> TokenStream {
     let mut table_name = None;
     let mut pk_field = None;
     let mut soft_delete_field = None;
+    let mut soft_delete_kind_attr: Option<syn::LitStr> = None;
+    let mut soft_delete_active: Option<syn::Expr> = None;
+    let mut soft_delete_deleted: Option<syn::Expr> = None;
+    let mut created_at_field_attr = None;
     let mut updated_at_field_attr = None;
+    let mut version_field = None;
+    let mut datasource = None;
+    let mut table_comment = None;
 
     for attr in &input.attrs {
         if attr.path().is_ident("model") {
@@ -119,6 +624,26 @@ pub fn derive_model_meta(input: TokenStream) -> TokenStream {
                                 {
                                     soft_delete_field = Some(s.value());
                                 }
+                            } else if nv.path.is_ident("soft_delete_kind") {
+                                if let syn::Expr::Lit(syn::ExprLit {
+                                    lit: syn::Lit::Str(s),
+                                    ..
+                                }) = nv.value
+                                {
+                                    soft_delete_kind_attr = Some(s);
+                                }
+                            } else if nv.path.is_ident("soft_delete_active") {
+                                soft_delete_active = Some(nv.value);
+                            } else if nv.path.is_ident("soft_delete_deleted") {
+                                soft_delete_deleted = Some(nv.value);
+                            } else if nv.path.is_ident("created_at") {
+                                if let syn::Expr::Lit(syn::ExprLit {
+                                    lit: syn::Lit::Str(s),
+                                    ..
+                                }) = nv.value
+                                {
+                                    created_at_field_attr = Some(s.value());
+                                }
                             } else if nv.path.is_ident("updated_at") {
                                 if let syn::Expr::Lit(syn::ExprLit {
                                     lit: syn::Lit::Str(s),
@@ -127,6 +652,30 @@ pub fn derive_model_meta(input: TokenStream) -> TokenStream {
                                 {
                                     updated_at_field_attr = Some(s.value());
                                 }
+                            } else if nv.path.is_ident("version") {
+                                if let syn::Expr::Lit(syn::ExprLit {
+                                    lit: syn::Lit::Str(s),
+                                    ..
+                                }) = nv.value
+                                {
+                                    version_field = Some(s.value());
+                                }
+                            } else if nv.path.is_ident("datasource") {
+                                if let syn::Expr::Lit(syn::ExprLit {
+                                    lit: syn::Lit::Str(s),
+                                    ..
+                                }) = nv.value
+                                {
+                                    datasource = Some(s.value());
+                                }
+                            } else if nv.path.is_ident("table_comment") || nv.path.is_ident("comment") {
+                                if let syn::Expr::Lit(syn::ExprLit {
+                                    lit: syn::Lit::Str(s),
+                                    ..
+                                }) = nv.value
+                                {
+                                    table_comment = Some(s.value());
+                                }
                             }
                         }
                     }
@@ -157,6 +706,26 @@ pub fn derive_model_meta(input: TokenStream) -> TokenStream {
                     {
                         soft_delete_field = Some(s.value());
                     }
+                } else if nv.path.is_ident("soft_delete_kind") {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) = &nv.value
+                    {
+                        soft_delete_kind_attr = Some(s.clone());
+                    }
+                } else if nv.path.is_ident("soft_delete_active") {
+                    soft_delete_active = Some(nv.value.clone());
+                } else if nv.path.is_ident("soft_delete_deleted") {
+                    soft_delete_deleted = Some(nv.value.clone());
+                } else if nv.path.is_ident("created_at") {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) = &nv.value
+                    {
+                        created_at_field_attr = Some(s.value());
+                    }
                 } else if nv.path.is_ident("updated_at") {
                     if let syn::Expr::Lit(syn::ExprLit {
                         lit: syn::Lit::Str(s),
@@ -165,6 +734,30 @@ pub fn derive_model_meta(input: TokenStream) -> TokenStream {
                     {
                         updated_at_field_attr = Some(s.value());
                     }
+                } else if nv.path.is_ident("version") {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) = &nv.value
+                    {
+                        version_field = Some(s.value());
+                    }
+                } else if nv.path.is_ident("datasource") {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) = &nv.value
+                    {
+                        datasource = Some(s.value());
+                    }
+                } else if nv.path.is_ident("table_comment") || nv.path.is_ident("comment") {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) = &nv.value
+                    {
+                        table_comment = Some(s.value());
+                    }
                 }
             }
         }
@@ -187,59 +780,242 @@ pub fn derive_model_meta(input: TokenStream) -> TokenStream {
     // 如果没有指定主键，默认使用 "id"
     let pk = pk_field.unwrap_or_else(|| "id".to_string());
 
-    // 确定 UPDATED_AT_FIELD：手动指定优先，否则自动检测列名为 "updated_at" 的字段
-    let updated_at_column: Option<String> = if updated_at_field_attr.is_some() {
-        updated_at_field_attr
-    } else {
-        let mut detected = None;
-        if let Data::Struct(DataStruct {
+    // 主键类型由主键字段类型推导，复合主键为元组；PK 常量使用主键字段的列名
+    let (pk, id_type) = match &input.data {
+        Data::Struct(DataStruct {
             fields: Fields::Named(named_fields),
             ..
-        }) = &input.data
-        {
-            for field in &named_fields.named {
-                if let Some(field_name) = &field.ident {
-                    let column_name = parse_column_name(&field.attrs, &field_name.to_string());
-                    if column_name == "updated_at" {
-                        detected = Some(column_name);
-                        break;
-                    }
+        }) => match pk_fields(name, &named_fields.named, &pk) {
+            Ok(fields) => {
+                let columns: Vec<String> = fields
+                    .iter()
+                    .map(|field| {
+                        let ident = field.ident.as_ref().unwrap();
+                        parse_column_name(&field.attrs, &ident.to_string())
+                    })
+                    .collect();
+                let types: Vec<&syn::Type> = fields.iter().map(|field| pk_inner_type(field)).collect();
+                let id_type = if types.len() == 1 {
+                    let ty = types[0];
+                    quote! { #ty }
+                } else {
+                    quote! { (#(#types),*) }
+                };
+                (columns.join(","), id_type)
+            }
+            Err(err) => return err.to_compile_error().into(),
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "ModelMeta derive only supports structs with named fields")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    // 确定 CREATED_AT_FIELD / UPDATED_AT_FIELD：手动指定优先，否则自动检测列名为
+    // "created_at" / "updated_at" 的字段；同时确定各自的时间戳类型
+    let mut created_at_column: Option<(String, proc_macro2::TokenStream)> = None;
+    let mut updated_at_column: Option<(String, proc_macro2::TokenStream)> = None;
+    if let Data::Struct(DataStruct {
+        fields: Fields::Named(named_fields),
+        ..
+    }) = &input.data
+    {
+        for field in &named_fields.named {
+            if let Some(field_name) = &field.ident {
+                let column_name = parse_column_name(&field.attrs, &field_name.to_string());
+                let is_created_at = created_at_field_attr
+                    .as_deref()
+                    .map_or(column_name == "created_at", |c| c == column_name);
+                let is_updated_at = updated_at_field_attr
+                    .as_deref()
+                    .map_or(column_name == "updated_at", |c| c == column_name);
+                if !is_created_at && !is_updated_at {
+                    continue;
+                }
+                let kind = match timestamp_kind(field) {
+                    Ok(kind) => kind,
+                    Err(err) => return err.to_compile_error().into(),
+                };
+                if is_created_at && created_at_column.is_none() {
+                    created_at_column = Some((column_name.clone(), kind.clone()));
+                }
+                if is_updated_at && updated_at_column.is_none() {
+                    updated_at_column = Some((column_name, kind));
                 }
             }
         }
-        detected
+    }
+    // 手动指定但不对应任何字段时仍保留列名，使用默认的毫秒时间戳
+    if created_at_column.is_none() {
+        created_at_column = created_at_field_attr
+            .map(|col| (col, quote! { sqlxplus::TimestampKind::EpochMillis }));
+    }
+    if updated_at_column.is_none() {
+        updated_at_column = updated_at_field_attr
+            .map(|col| (col, quote! { sqlxplus::TimestampKind::EpochMillis }));
+    }
+
+    // 收集 #[column(unique)] 标记的唯一列（upsert 默认冲突目标）和每个字段的列常量
+    let mut unique_columns: Vec<syn::LitStr> = Vec::new();
+    let mut column_const_idents: Vec<syn::Ident> = Vec::new();
+    let mut column_const_names: Vec<syn::LitStr> = Vec::new();
+    if let Data::Struct(DataStruct {
+        fields: Fields::Named(named_fields),
+        ..
+    }) = &input.data
+    {
+        for field in &named_fields.named {
+            if let Some(field_name) = &field.ident {
+                let column_name = parse_column_name(&field.attrs, &field_name.to_string());
+                if has_column_flag(&field.attrs, "unique") {
+                    unique_columns.push(syn::LitStr::new(&column_name, proc_macro2::Span::call_site()));
+                }
+                // #[skip] 字段不对应数据库列
+                if field.attrs.iter().any(|attr| attr.path().is_ident("skip")) {
+                    continue;
+                }
+                let const_name = strip_raw_identifier_prefix(&field_name.to_string()).to_uppercase();
+                column_const_idents.push(syn::Ident::new(&const_name, field_name.span()));
+                column_const_names.push(syn::LitStr::new(&column_name, proc_macro2::Span::call_site()));
+            }
+        }
+    }
+
+    // 生成运行时元数据 Model::meta()
+    let pk_columns: Vec<&str> = pk.split(',').map(str::trim).collect();
+    let field_metas: Vec<proc_macro2::TokenStream> = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(named_fields),
+            ..
+        }) => named_fields
+            .named
+            .iter()
+            .map(|field| field_meta(field, &pk_columns, soft_delete_field.as_deref()))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let soft_delete_meta = match &soft_delete_field {
+        Some(column) => quote! { Some(#column) },
+        None => quote! { None },
+    };
+    let table_comment_meta = match &table_comment {
+        Some(comment) => quote! { Some(#comment) },
+        None => quote! { None },
+    };
+
+    // 生成 #[relation(...)] 声明的关联关系
+    let relation_impls = match relation_impls(name, &input.attrs) {
+        Ok(impls) => impls,
+        Err(err) => return err.to_compile_error().into(),
     };
 
-    // 生成 UPDATED_AT_FIELD 常量
-    let updated_at_impl = if let Some(ref col) = updated_at_column {
+    // 生成 CREATED_AT_FIELD / UPDATED_AT_FIELD 常量及其时间戳类型
+    let created_at_impl = if let Some((ref col, ref kind)) = created_at_column {
         let col_lit = syn::LitStr::new(col, proc_macro2::Span::call_site());
-        quote! { const UPDATED_AT_FIELD: Option<&'static str> = Some(#col_lit); }
+        quote! {
+            const CREATED_AT_FIELD: Option<&'static str> = Some(#col_lit);
+            const CREATED_AT_KIND: sqlxplus::TimestampKind = #kind;
+        }
+    } else {
+        quote! { const CREATED_AT_FIELD: Option<&'static str> = None; }
+    };
+    let updated_at_impl = if let Some((ref col, ref kind)) = updated_at_column {
+        let col_lit = syn::LitStr::new(col, proc_macro2::Span::call_site());
+        quote! {
+            const UPDATED_AT_FIELD: Option<&'static str> = Some(#col_lit);
+            const UPDATED_AT_KIND: sqlxplus::TimestampKind = #kind;
+        }
     } else {
         quote! { const UPDATED_AT_FIELD: Option<&'static str> = None; }
     };
 
-    // 生成实现代码
-    let expanded = if let Some(soft_delete) = soft_delete_field {
-        // 如果指定了逻辑删除字段，生成包含 SOFT_DELETE_FIELD 的实现
+    // 生成 SOFT_DELETE_FIELD 常量：如果指定了逻辑删除字段则为 Some，否则为 None
+    let soft_delete_impl = if let Some(soft_delete) = soft_delete_field {
         let soft_delete_lit = syn::LitStr::new(&soft_delete, proc_macro2::Span::call_site());
+        // 逻辑删除字段对应的结构体字段（用于推断取值方式）
+        let soft_delete_struct_field = match &input.data {
+            Data::Struct(DataStruct {
+                fields: Fields::Named(named_fields),
+                ..
+            }) => named_fields.named.iter().find(|field| {
+                field.ident.as_ref().is_some_and(|ident| {
+                    parse_column_name(&field.attrs, &ident.to_string()) == soft_delete
+                })
+            }),
+            _ => None,
+        };
+        let soft_delete_kind = match soft_delete_kind(
+            soft_delete_struct_field,
+            soft_delete_kind_attr.as_ref(),
+            soft_delete_active.as_ref(),
+            soft_delete_deleted.as_ref(),
+        ) {
+            Ok(kind) => kind,
+            Err(err) => return err.to_compile_error().into(),
+        };
         quote! {
-            impl sqlxplus::Model for #name {
-                const TABLE: &'static str = #table;
-                const PK: &'static str = #pk;
-                const SOFT_DELETE_FIELD: Option<&'static str> = Some(#soft_delete_lit);
-                #updated_at_impl
-            }
+            const SOFT_DELETE_FIELD: Option<&'static str> = Some(#soft_delete_lit);
+            const SOFT_DELETE_KIND: sqlxplus::SoftDeleteKind = #soft_delete_kind;
         }
     } else {
-        // 如果没有指定逻辑删除字段，SOFT_DELETE_FIELD 为 None
-        quote! {
-            impl sqlxplus::Model for #name {
-                const TABLE: &'static str = #table;
-                const PK: &'static str = #pk;
-                const SOFT_DELETE_FIELD: Option<&'static str> = None;
-                #updated_at_impl
+        quote! { const SOFT_DELETE_FIELD: Option<&'static str> = None; }
+    };
+
+    // 生成 VERSION_FIELD 常量（乐观锁）
+    let version_impl = if let Some(version) = version_field {
+        let version_lit = syn::LitStr::new(&version, proc_macro2::Span::call_site());
+        quote! { const VERSION_FIELD: Option<&'static str> = Some(#version_lit); }
+    } else {
+        quote! { const VERSION_FIELD: Option<&'static str> = None; }
+    };
+
+    // 生成 DATASOURCE 常量（多数据源）
+    let datasource_impl = match datasource {
+        Some(datasource) => quote! { const DATASOURCE: Option<&'static str> = Some(#datasource); },
+        None => quote! { const DATASOURCE: Option<&'static str> = None; },
+    };
+
+    // 生成实现代码
+    let expanded = quote! {
+        impl sqlxplus::Model for #name {
+            const TABLE: &'static str = #table;
+            const PK: &'static str = #pk;
+            type Id = #id_type;
+            #soft_delete_impl
+            #created_at_impl
+            #updated_at_impl
+            #version_impl
+            #datasource_impl
+            const UNIQUE_FIELDS: &'static [&'static str] = &[#(#unique_columns),*];
+            const COLUMNS: &'static [&'static str] = &[#(#column_const_names),*];
+
+            fn meta() -> &'static sqlxplus::macros_api::ModelMeta {
+                static META: sqlxplus::macros_api::ModelMeta = sqlxplus::macros_api::ModelMeta {
+                    table: #table,
+                    pk: #pk,
+                    soft_delete: #soft_delete_meta,
+                    fields: &[#(#field_metas),*],
+                    table_comment: #table_comment_meta,
+                };
+                &META
             }
         }
+
+        impl #name {
+            #(
+                #[doc = concat!("列 `", #column_const_names, "`")]
+                pub const #column_const_idents: sqlxplus::Column<#name> = sqlxplus::Column::new(#column_const_names);
+            )*
+
+            /// 模型的所有列（按字段声明顺序）
+            pub fn columns() -> &'static [sqlxplus::Column<#name>] {
+                const COLUMNS: &[sqlxplus::Column<#name>] = &[#(#name::#column_const_idents),*];
+                COLUMNS
+            }
+        }
+
+        #(#relation_impls)*
     };
 
     TokenStream::from(expanded)
@@ -328,10 +1104,14 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
         }
     };
 
+    // 主键字段（复合主键按 pk 中的列顺序）
+    let pk_fields = match pk_fields(name, fields, &pk) {
+        Ok(pk_fields) => pk_fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     // 收集字段信息
-    // - pk_ident: 主键字段 Ident
     // - insert_*/update_*: 非主键字段（INSERT / UPDATE 使用）
-    let mut pk_ident_opt: Option<&syn::Ident> = None;
 
     // INSERT 使用的字段（非主键）
     let mut insert_normal_field_names: Vec<&syn::Ident> = Vec::new();
@@ -370,9 +1150,7 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
         }
 
         if !skip {
-            if field_name_str == pk {
-                // 记录主键字段
-                pk_ident_opt = Some(field_name);
+            if pk_fields.iter().any(|pk_field| pk_field.ident.as_ref() == Some(field_name)) {
                 // 主键字段也需要添加到 UpdateFields，因为 UpdateBuilder 需要获取主键值
                 let is_opt = is_option_type(&field.ty);
                 let col_lit = syn::LitStr::new(&column_name, proc_macro2::Span::call_site());
@@ -450,8 +1228,55 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
         }
     }
 
-    // 编译期确保主键字段存在
-    let pk_ident = pk_ident_opt.expect("Primary key field not found in struct");
+    // 主键值：所有主键字段都有值时为 Some（复合主键为元组）
+    let pk_value_parts: Vec<proc_macro2::TokenStream> = pk_fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            if is_option_type(&field.ty) {
+                quote! { row.#ident.clone()? }
+            } else {
+                quote! { row.#ident.clone() }
+            }
+        })
+        .collect();
+    let pk_value_fn = if let [field] = pk_fields.as_slice() {
+        let ident = field.ident.as_ref().unwrap();
+        if is_option_type(&field.ty) {
+            quote! { |row: &Self| -> Option<<Self as sqlxplus::Model>::Id> { row.#ident.clone() } }
+        } else {
+            quote! { |row: &Self| -> Option<<Self as sqlxplus::Model>::Id> { Some(row.#ident.clone()) } }
+        }
+    } else {
+        quote! { |row: &Self| -> Option<<Self as sqlxplus::Model>::Id> { Some((#(#pk_value_parts),*)) } }
+    };
+    // 插入时写入的主键值：单列非 Option 的整数主键视为自增主键，始终由数据库生成
+    let insert_pk_value_fn = match pk_fields.as_slice() {
+        [field] if !is_option_type(&field.ty) && is_integer_type(&field.ty) => {
+            quote! { |_: &Self| -> Option<<Self as sqlxplus::Model>::Id> { None } }
+        }
+        _ => pk_value_fn.clone(),
+    };
+
+    // 绑定 INSERT 的非主键字段（创建 / 更新时间字段为空时绑定当前时间）
+    let insert_binds = |row: proc_macro2::TokenStream| {
+        quote! {
+            // 非 Option 字段：始终绑定
+            #(
+                query = query.bind(&#row.#insert_normal_field_names);
+            )*
+            // Option 字段：仅当为 Some 时绑定（创建 / 更新时间字段为空时绑定当前时间）
+            #(
+                if let Some(ref val) = #row.#insert_option_field_names {
+                    query = query.bind(val);
+                } else if let Some(kind) = sqlxplus::timestamp::insert_timestamp_kind::<Self>(#insert_option_field_columns) {
+                    sqlxplus::bind_timestamp!(query, kind, now);
+                }
+            )*
+        }
+    };
+    let self_binds = insert_binds(quote! { self });
+    let row_binds = insert_binds(quote! { row });
 
     // 生成实现代码
     let expanded = quote! {
@@ -459,13 +1284,14 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
         #[async_trait::async_trait]
         impl sqlxplus::Crud for #name {
             // 泛型版本的 insert（自动类型推断）
-            async fn insert<'e, 'c: 'e, DB, E>(&self, executor: E) -> sqlxplus::Result<sqlxplus::crud::Id>
+            async fn insert<'e, 'c: 'e, DB, E>(&self, executor: E) -> sqlxplus::Result<<Self as sqlxplus::Model>::Id>
             where
                 DB: sqlx::Database + sqlxplus::DatabaseInfo,
                 for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
                 E: sqlxplus::DatabaseType<DB = DB>
                     + sqlx::Executor<'c, Database = DB>
                     + Send,
+                <Self as sqlxplus::Model>::Id: sqlxplus::BindPrimaryKey<DB>,
                 i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
                 usize: sqlx::ColumnIndex<DB::Row>,
                 // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
@@ -503,16 +1329,30 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                 let table = Self::TABLE;
                 let escaped_table = DB::escape_identifier(table);
 
-                // 判断列名是否为更新时间字段的辅助闭包
-                let is_updated_at_col = |col: &str| -> bool {
-                    Self::UPDATED_AT_FIELD.map_or(col == "updated_at", |f| f == col)
-                };
+                // 主键已赋值时写入主键列并原样返回，否则由数据库生成（仅整数主键）
+                let insert_pk_value = #insert_pk_value_fn;
+                let id = insert_pk_value(self);
+                if id.is_none() {
+                    sqlxplus::primary_key::ensure_generated::<Self>()?;
+                }
+
+                // 创建 / 更新时间字段为空时按时间戳类型写入当前时间
+                let now = sqlxplus::timestamp::now();
 
                 // 构建列名和占位符
                 let mut columns: Vec<&str> = Vec::new();
                 let mut placeholders: Vec<String> = Vec::new();
                 let mut placeholder_index = 0;
 
+                // 主键列
+                if id.is_some() {
+                    for pk in sqlxplus::primary_key::pk_columns::<Self>() {
+                        columns.push(pk);
+                        placeholders.push(DB::placeholder(placeholder_index));
+                        placeholder_index += 1;
+                    }
+                }
+
                 // 非 Option 字段：始终参与 INSERT
                 #(
                     columns.push(#insert_normal_field_columns);
@@ -520,15 +1360,31 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                     placeholder_index += 1;
                 )*
 
-                // Option 字段：仅当为 Some 时参与 INSERT（若是 created_at/updated_at 字段无论是否 Some 均参与）
+                // Option 字段：仅当为 Some 时参与 INSERT（创建 / 更新时间字段无论是否 Some 均参与）
                 #(
-                    if self.#insert_option_field_names.is_some() || #insert_option_field_columns == "created_at" || is_updated_at_col(#insert_option_field_columns) {
+                    if self.#insert_option_field_names.is_some() {
                         columns.push(#insert_option_field_columns);
                         placeholders.push(DB::placeholder(placeholder_index));
                         placeholder_index += 1;
+                    } else if let Some(kind) = sqlxplus::timestamp::insert_timestamp_kind::<Self>(#insert_option_field_columns) {
+                        columns.push(#insert_option_field_columns);
+                        placeholders.push(sqlxplus::timestamp::placeholder::<DB>(kind, &mut placeholder_index));
                     }
                 )*
 
+                if let Some(id) = id {
+                    let sql = format!(
+                        "INSERT INTO {} ({}) VALUES ({})",
+                        escaped_table,
+                        columns.join(", "),
+                        placeholders.join(", ")
+                    );
+                    let mut query = sqlxplus::BindPrimaryKey::<DB>::bind_to(id.clone(), sqlx::query(&sql));
+                    #self_binds
+                    query.execute(executor).await?;
+                    return Ok(id);
+                }
+
                 // 根据数据库类型构建 SQL
                 let sql = match DB::get_driver() {
                     DbDriver::Postgres => {
@@ -553,46 +1409,15 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                 };
 
                 // 根据数据库类型执行查询
-                match DB::get_driver() {
+                let generated_id = match DB::get_driver() {
                     DbDriver::Postgres => {
                         let mut query = sqlx::query_scalar::<_, i64>(&sql);
-                        // 非 Option 字段：始终绑定
-                        #(
-                            query = query.bind(&self.#insert_normal_field_names);
-                        )*
-                        // Option 字段：仅当为 Some 时绑定（created_at/updated_at 为空时默认系统时间）
-                        #(
-                            if #insert_option_field_columns == "created_at" || is_updated_at_col(#insert_option_field_columns) {
-                                if let Some(ref val) = self.#insert_option_field_names {
-                                    query = query.bind(val);
-                                } else {
-                                    query = query.bind(::std::time::SystemTime::now().duration_since(::std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64);
-                                }
-                            } else if let Some(ref val) = self.#insert_option_field_names {
-                                query = query.bind(val);
-                            }
-                        )*
-                        let id: i64 = query.fetch_one(executor).await?;
-                        Ok(id)
+                        #self_binds
+                        query.fetch_one(executor).await?
                     }
                     DbDriver::MySql => {
                         let mut query = sqlx::query(&sql);
-                        // 非 Option 字段：始终绑定
-                        #(
-                            query = query.bind(&self.#insert_normal_field_names);
-                        )*
-                        // Option 字段：仅当为 Some 时绑定（created_at/updated_at 为空时默认系统时间）
-                        #(
-                            if #insert_option_field_columns == "created_at" || is_updated_at_col(#insert_option_field_columns) {
-                                if let Some(ref val) = self.#insert_option_field_names {
-                                    query = query.bind(val);
-                                } else {
-                                    query = query.bind(::std::time::SystemTime::now().duration_since(::std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64);
-                                }
-                            } else if let Some(ref val) = self.#insert_option_field_names {
-                                query = query.bind(val);
-                            }
-                        )*
+                        #self_binds
                         let result = query.execute(executor).await?;
                         // 在泛型上下文中，我们需要使用 unsafe 转换来访问数据库特定的方法
                         // 这是安全的，因为我们已经通过 DB::get_driver() 确认了数据库类型
@@ -601,37 +1426,168 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                             use sqlx::mysql::MySqlQueryResult;
                             let ptr: *const DB::QueryResult = &result;
                             let mysql_ptr = ptr as *const MySqlQueryResult;
-                            Ok((*mysql_ptr).last_insert_id() as i64)
+                            (*mysql_ptr).last_insert_id() as i64
                         }
                     }
                     DbDriver::Sqlite => {
                         let mut query = sqlx::query(&sql);
-                        // 非 Option 字段：始终绑定
-                        #(
-                            query = query.bind(&self.#insert_normal_field_names);
-                        )*
-                        // Option 字段：仅当为 Some 时绑定（created_at/updated_at 为空时默认系统时间）
-                        #(
-                            if #insert_option_field_columns == "created_at" || is_updated_at_col(#insert_option_field_columns) {
-                                if let Some(ref val) = self.#insert_option_field_names {
-                                    query = query.bind(val);
-                                } else {
-                                    query = query.bind(::std::time::SystemTime::now().duration_since(::std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64);
-                                }
-                            } else if let Some(ref val) = self.#insert_option_field_names {
-                                query = query.bind(val);
-                            }
-                        )*
+                        #self_binds
                         let result = query.execute(executor).await?;
                         // 在泛型上下文中，我们需要使用 unsafe 转换来访问数据库特定的方法
                         unsafe {
                             use sqlx::sqlite::SqliteQueryResult;
                             let ptr: *const DB::QueryResult = &result;
                             let sqlite_ptr = ptr as *const SqliteQueryResult;
-                            Ok((*sqlite_ptr).last_insert_rowid() as i64)
+                            (*sqlite_ptr).last_insert_rowid()
+                        }
+                    }
+                };
+                sqlxplus::primary_key::generated_id::<Self>(generated_id)
+            }
+
+            // 泛型版本的 insert_many（多行 VALUES，按绑定参数上限拆分）
+            async fn insert_many<'e, 'c: 'e, DB, E>(models: &[Self], executor: E) -> sqlxplus::Result<Vec<<Self as sqlxplus::Model>::Id>>
+            where
+                DB: sqlx::Database + sqlxplus::DatabaseInfo,
+                for<'a> DB::Arguments<'a>: sqlx::IntoArguments<'a, DB>,
+                E: sqlxplus::DatabaseType<DB = DB>
+                    + sqlx::Acquire<'c, Database = DB>
+                    + Send,
+                <Self as sqlxplus::Model>::Id: sqlxplus::BindPrimaryKey<DB>,
+                for<'x> &'x mut DB::Connection: sqlx::Executor<'x, Database = DB>,
+                i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB>,
+                usize: sqlx::ColumnIndex<DB::Row>,
+                // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
+                // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
+                String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                i64: for<'b> sqlx::Encode<'b, DB>,
+                i32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                i16: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                f64: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                f32: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                bool: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<String>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<i64>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<i32>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<i16>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<f64>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<f32>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<bool>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                chrono::DateTime<chrono::Utc>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<chrono::DateTime<chrono::Utc>>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                chrono::NaiveDateTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<chrono::NaiveDateTime>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                chrono::NaiveDate: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<chrono::NaiveDate>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                chrono::NaiveTime: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<chrono::NaiveTime>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Vec<u8>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<Vec<u8>>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                serde_json::Value: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+                Option<serde_json::Value>: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
+            {
+                use sqlxplus::Model;
+                use sqlxplus::DatabaseInfo;
+                use sqlxplus::db_pool::DbDriver;
+
+                if models.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                // 主键已赋值的记录写入主键列并原样返回，其余由数据库生成（仅整数主键）
+                let insert_pk_value = #insert_pk_value_fn;
+                if models.iter().any(|row| insert_pk_value(row).is_none()) {
+                    sqlxplus::primary_key::ensure_generated::<Self>()?;
+                }
+
+                // 计算单条记录参与 INSERT 的列（与 insert 规则一致），标记由数据库生成当前时间的列
+                let row_columns = |row: &Self| -> Vec<(&'static str, bool)> {
+                    let mut columns: Vec<(&'static str, bool)> = Vec::new();
+                    if insert_pk_value(row).is_some() {
+                        for pk in sqlxplus::primary_key::pk_columns::<Self>() {
+                            columns.push((pk, false));
+                        }
+                    }
+                    #(
+                        columns.push((#insert_normal_field_columns, false));
+                    )*
+                    #(
+                        if row.#insert_option_field_names.is_some() {
+                            columns.push((#insert_option_field_columns, false));
+                        } else if let Some(kind) = sqlxplus::timestamp::insert_timestamp_kind::<Self>(#insert_option_field_columns) {
+                            columns.push((#insert_option_field_columns, kind.is_db_side()));
+                        }
+                    )*
+                    columns
+                };
+
+                let driver = DB::get_driver();
+                let now = sqlxplus::timestamp::now();
+                let mut ids: Vec<<Self as sqlxplus::Model>::Id> = Vec::with_capacity(models.len());
+                let mut conn = executor.acquire().await?;
+
+                let mut start = 0;
+                while start < models.len() {
+                    // 合并相邻且插入列一致的记录，单条语句不超过绑定参数上限
+                    let row = row_columns(&models[start]);
+                    let columns: Vec<&str> = row.iter().map(|(col, _)| *col).collect();
+                    let db_now_columns: Vec<&str> = row.iter().filter(|(_, db_now)| *db_now).map(|(col, _)| *col).collect();
+                    let chunk_size = sqlxplus::crud::insert_chunk_size(driver, columns.len());
+                    let mut end = start + 1;
+                    while end < models.len() && end - start < chunk_size && row_columns(&models[end]) == row {
+                        end += 1;
+                    }
+                    let chunk = &models[start..end];
+                    start = end;
+
+                    // 同一语句中的记录要么都已赋值主键，要么都由数据库生成
+                    if insert_pk_value(&chunk[0]).is_some() {
+                        let sql = sqlxplus::crud::build_insert_sql_with_db_now::<DB>(Self::TABLE, &columns, &db_now_columns, chunk.len(), None);
+                        let mut query = sqlx::query(&sql);
+                        for row in chunk {
+                            if let Some(id) = insert_pk_value(row) {
+                                query = sqlxplus::BindPrimaryKey::<DB>::bind_to(id.clone(), query);
+                                ids.push(id);
+                            }
+                            #row_binds
+                        }
+                        query.execute(&mut *conn).await?;
+                        continue;
+                    }
+
+                    match driver {
+                        DbDriver::Postgres | DbDriver::Sqlite => {
+                            let sql = sqlxplus::crud::build_insert_sql_with_db_now::<DB>(Self::TABLE, &columns, &db_now_columns, chunk.len(), Some(Self::PK));
+                            let mut query = sqlx::query_scalar::<_, i64>(&sql);
+                            for row in chunk {
+                                #row_binds
+                            }
+                            for generated_id in query.fetch_all(&mut *conn).await? {
+                                ids.push(sqlxplus::primary_key::generated_id::<Self>(generated_id)?);
+                            }
+                        }
+                        DbDriver::MySql => {
+                            let sql = sqlxplus::crud::build_insert_sql_with_db_now::<DB>(Self::TABLE, &columns, &db_now_columns, chunk.len(), None);
+                            let mut query = sqlx::query(&sql);
+                            for row in chunk {
+                                #row_binds
+                            }
+                            let result = query.execute(&mut *conn).await?;
+                            // 多行 INSERT 时 LAST_INSERT_ID() 返回本语句第一行的 ID，其余 ID 连续递增
+                            let first_id = unsafe {
+                                use sqlx::mysql::MySqlQueryResult;
+                                let ptr: *const DB::QueryResult = &result;
+                                let mysql_ptr = ptr as *const MySqlQueryResult;
+                                (*mysql_ptr).last_insert_id() as i64
+                            };
+                            for offset in 0..chunk.len() as i64 {
+                                ids.push(sqlxplus::primary_key::generated_id::<Self>(first_id + offset)?);
+                            }
                         }
                     }
                 }
+
+                Ok(ids)
             }
 
             // 泛型版本的 update（自动类型推断）
@@ -642,6 +1598,7 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                 E: sqlxplus::DatabaseType<DB = DB>
                     + sqlx::Executor<'c, Database = DB>
                     + Send,
+                <Self as sqlxplus::Model>::Id: sqlxplus::BindPrimaryKey<DB>,
                 // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
                 // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
                 String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
@@ -674,14 +1631,13 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                 use sqlxplus::Model;
                 use sqlxplus::DatabaseInfo;
                 let table = Self::TABLE;
-                let pk = Self::PK;
                 let escaped_table = DB::escape_identifier(table);
-                let escaped_pk = DB::escape_identifier(pk);
 
-                // 判断列名是否为更新时间字段的辅助闭包
-                let is_updated_at_col = |col: &str| -> bool {
-                    Self::UPDATED_AT_FIELD.map_or(col == "updated_at", |f| f == col)
-                };
+                // 更新时间字段为空时按时间戳类型写入当前时间
+                let now = sqlxplus::timestamp::now();
+                // 乐观锁版本字段：有版本值时 SET 中自增、WHERE 中校验，而不是直接写入字段值
+                let is_version_col = |col: &str| -> bool { Self::VERSION_FIELD == Some(col) };
+                let mut version_check: Option<&'static str> = None;
 
                 // 构建 UPDATE SET 子句（Patch 语义）
                 let mut set_parts: Vec<String> = Vec::new();
@@ -689,49 +1645,100 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
 
                 // 非 Option 字段
                 #(
-                    set_parts.push(format!("{} = {}", DB::escape_identifier(#update_normal_field_columns), DB::placeholder(placeholder_index)));
-                    placeholder_index += 1;
+                    if is_version_col(#update_normal_field_columns) {
+                        version_check = Self::VERSION_FIELD;
+                    } else {
+                        set_parts.push(format!("{} = {}", DB::escape_identifier(#update_normal_field_columns), DB::placeholder(placeholder_index)));
+                        placeholder_index += 1;
+                    }
                 )*
 
                 // Option 字段
                 #(
-                    if self.#update_option_field_names.is_some() || is_updated_at_col(#update_option_field_columns) {
+                    if is_version_col(#update_option_field_columns) {
+                        version_check = self.#update_option_field_names.as_ref().and(Self::VERSION_FIELD);
+                    } else if self.#update_option_field_names.is_some() {
                         set_parts.push(format!("{} = {}", DB::escape_identifier(#update_option_field_columns), DB::placeholder(placeholder_index)));
                         placeholder_index += 1;
+                    } else if let Some(kind) = sqlxplus::timestamp::update_timestamp_kind::<Self>(#update_option_field_columns) {
+                        set_parts.push(format!("{} = {}", DB::escape_identifier(#update_option_field_columns), sqlxplus::timestamp::placeholder::<DB>(kind, &mut placeholder_index)));
                     }
                 )*
 
+                if let Some(version_col) = version_check {
+                    let escaped_version = DB::escape_identifier(version_col);
+                    set_parts.push(format!("{} = {} + 1", escaped_version, escaped_version));
+                }
+
                 if set_parts.is_empty() {
                     return Ok(());
                 }
 
-                let sql = format!(
-                    "UPDATE {} SET {} WHERE {} = {}",
+                // 按主键定位记录（复合主键的每个字段都必须有值）
+                let pk_value = #pk_value_fn;
+                let id = pk_value(self).ok_or_else(|| {
+                    sqlxplus::SqlxPlusError::InvalidField(format!(
+                        "Primary key '{}' is required for update but value is None",
+                        Self::PK
+                    ))
+                })?;
+                let mut where_index = placeholder_index;
+                let mut sql = format!(
+                    "UPDATE {} SET {} WHERE {}",
                     escaped_table,
                     set_parts.join(", "),
-                    escaped_pk,
-                    DB::placeholder(placeholder_index)
+                    sqlxplus::primary_key::pk_condition::<DB, Self>(&mut where_index)
                 );
+                if let Some(version_col) = version_check {
+                    sql.push_str(&format!(
+                        " AND {} = {}",
+                        DB::escape_identifier(version_col),
+                        DB::placeholder(where_index)
+                    ));
+                }
 
                 let mut query = sqlx::query(&sql);
                 // 非 Option 字段：始终绑定
                 #(
-                    query = query.bind(&self.#update_normal_field_names);
+                    if !is_version_col(#update_normal_field_columns) {
+                        query = query.bind(&self.#update_normal_field_names);
+                    }
                 )*
-                // Option 字段：仅当为 Some 时绑定（updated_at 为空时默认系统时间）
+                // Option 字段：仅当为 Some 时绑定（更新时间字段为空时绑定当前时间）
                 #(
-                    if is_updated_at_col(#update_option_field_columns) {
-                        if let Some(ref val) = self.#update_option_field_names {
-                            query = query.bind(val);
-                        } else {
-                            query = query.bind(::std::time::SystemTime::now().duration_since(::std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64);
-                        }
+                    if is_version_col(#update_option_field_columns) {
+                        // 版本值绑定在 WHERE 子句中
                     } else if let Some(ref val) = self.#update_option_field_names {
                         query = query.bind(val);
+                    } else if let Some(kind) = sqlxplus::timestamp::update_timestamp_kind::<Self>(#update_option_field_columns) {
+                        sqlxplus::bind_timestamp!(query, kind, now);
                     }
                 )*
-                query = query.bind(&self.#pk_ident);
-                query.execute(executor).await?;
+                query = sqlxplus::BindPrimaryKey::<DB>::bind_to(id, query);
+                if version_check.is_some() {
+                    #(
+                        if is_version_col(#update_normal_field_columns) {
+                            query = query.bind(&self.#update_normal_field_names);
+                        }
+                    )*
+                    #(
+                        if is_version_col(#update_option_field_columns) {
+                            if let Some(ref val) = self.#update_option_field_names {
+                                query = query.bind(val);
+                            }
+                        }
+                    )*
+                }
+                let result = query.execute(executor).await?;
+                // 带版本条件却没有更新到任何行：记录已被其他事务修改（或已不存在）
+                if let Some(version_col) = version_check {
+                    if DB::rows_affected(&result) == 0 {
+                        return Err(sqlxplus::SqlxPlusError::StaleVersion(format!(
+                            "{} row was modified concurrently or no longer exists ({} mismatch)",
+                            table, version_col
+                        )));
+                    }
+                }
                 Ok(())
             }
 
@@ -743,6 +1750,7 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                 E: sqlxplus::DatabaseType<DB = DB>
                     + sqlx::Executor<'c, Database = DB>
                     + Send,
+                <Self as sqlxplus::Model>::Id: sqlxplus::BindPrimaryKey<DB>,
                 // 基本类型必须实现 Type<DB> 和 Encode<DB>（用于绑定值）
                 // 注意：只包含三种数据库（MySQL、PostgreSQL、SQLite）都支持的类型
                 String: sqlx::Type<DB> + for<'b> sqlx::Encode<'b, DB>,
@@ -776,14 +1784,13 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                 use sqlxplus::DatabaseInfo;
                 use sqlxplus::db_pool::DbDriver;
                 let table = Self::TABLE;
-                let pk = Self::PK;
                 let escaped_table = DB::escape_identifier(table);
-                let escaped_pk = DB::escape_identifier(pk);
 
-                // 判断列名是否为更新时间字段的辅助闭包
-                let is_updated_at_col = |col: &str| -> bool {
-                    Self::UPDATED_AT_FIELD.map_or(col == "updated_at", |f| f == col)
-                };
+                // 更新时间字段为空时按时间戳类型写入当前时间
+                let now = sqlxplus::timestamp::now();
+                // 乐观锁版本字段：有版本值时 SET 中自增、WHERE 中校验，而不是直接写入字段值
+                let is_version_col = |col: &str| -> bool { Self::VERSION_FIELD == Some(col) };
+                let mut version_check: Option<&'static str> = None;
 
                 // 构建 UPDATE SET 子句（Reset 语义）
                 let mut set_parts: Vec<String> = Vec::new();
@@ -791,8 +1798,12 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
 
                 // 非 Option 字段：始终更新为当前值
                 #(
-                    set_parts.push(format!("{} = {}", DB::escape_identifier(#update_normal_field_columns), DB::placeholder(placeholder_index)));
-                    placeholder_index += 1;
+                    if is_version_col(#update_normal_field_columns) {
+                        version_check = Self::VERSION_FIELD;
+                    } else {
+                        set_parts.push(format!("{} = {}", DB::escape_identifier(#update_normal_field_columns), DB::placeholder(placeholder_index)));
+                        placeholder_index += 1;
+                    }
                 )*
 
                 // Option 字段：根据数据库类型处理
@@ -800,18 +1811,26 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                     DbDriver::Sqlite => {
                         // SQLite 不支持 DEFAULT，跳过 None 字段
                         #(
-                            if self.#update_option_field_names.is_some() || is_updated_at_col(#update_option_field_columns) {
+                            if is_version_col(#update_option_field_columns) {
+                                version_check = self.#update_option_field_names.as_ref().and(Self::VERSION_FIELD);
+                            } else if self.#update_option_field_names.is_some() {
                                 set_parts.push(format!("{} = {}", DB::escape_identifier(#update_option_field_columns), DB::placeholder(placeholder_index)));
                                 placeholder_index += 1;
+                            } else if let Some(kind) = sqlxplus::timestamp::update_timestamp_kind::<Self>(#update_option_field_columns) {
+                                set_parts.push(format!("{} = {}", DB::escape_identifier(#update_option_field_columns), sqlxplus::timestamp::placeholder::<DB>(kind, &mut placeholder_index)));
                             }
                         )*
                     }
                     _ => {
                         // MySQL 和 PostgreSQL 使用 DEFAULT
                         #(
-                            if self.#update_option_field_names.is_some() || is_updated_at_col(#update_option_field_columns) {
+                            if is_version_col(#update_option_field_columns) {
+                                version_check = self.#update_option_field_names.as_ref().and(Self::VERSION_FIELD);
+                            } else if self.#update_option_field_names.is_some() {
                                 set_parts.push(format!("{} = {}", DB::escape_identifier(#update_option_field_columns), DB::placeholder(placeholder_index)));
                                 placeholder_index += 1;
+                            } else if let Some(kind) = sqlxplus::timestamp::update_timestamp_kind::<Self>(#update_option_field_columns) {
+                                set_parts.push(format!("{} = {}", DB::escape_identifier(#update_option_field_columns), sqlxplus::timestamp::placeholder::<DB>(kind, &mut placeholder_index)));
                             } else {
                                 set_parts.push(format!("{} = DEFAULT", DB::escape_identifier(#update_option_field_columns)));
                             }
@@ -819,37 +1838,80 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                     }
                 }
 
+                if let Some(version_col) = version_check {
+                    let escaped_version = DB::escape_identifier(version_col);
+                    set_parts.push(format!("{} = {} + 1", escaped_version, escaped_version));
+                }
+
                 if set_parts.is_empty() {
                     return Ok(());
                 }
 
-                let sql = format!(
-                    "UPDATE {} SET {} WHERE {} = {}",
+                // 按主键定位记录（复合主键的每个字段都必须有值）
+                let pk_value = #pk_value_fn;
+                let id = pk_value(self).ok_or_else(|| {
+                    sqlxplus::SqlxPlusError::InvalidField(format!(
+                        "Primary key '{}' is required for update but value is None",
+                        Self::PK
+                    ))
+                })?;
+                let mut where_index = placeholder_index;
+                let mut sql = format!(
+                    "UPDATE {} SET {} WHERE {}",
                     escaped_table,
                     set_parts.join(", "),
-                    escaped_pk,
-                    DB::placeholder(placeholder_index)
+                    sqlxplus::primary_key::pk_condition::<DB, Self>(&mut where_index)
                 );
+                if let Some(version_col) = version_check {
+                    sql.push_str(&format!(
+                        " AND {} = {}",
+                        DB::escape_identifier(version_col),
+                        DB::placeholder(where_index)
+                    ));
+                }
 
                 let mut query = sqlx::query(&sql);
                 // 非 Option 字段：始终绑定
                 #(
-                    query = query.bind(&self.#update_normal_field_names);
+                    if !is_version_col(#update_normal_field_columns) {
+                        query = query.bind(&self.#update_normal_field_names);
+                    }
                 )*
-                // Option 字段：仅当为 Some 时绑定（None 使用 DEFAULT 或跳过，updated_at 为空时绑定当前时间）
+                // Option 字段：仅当为 Some 时绑定（None 使用 DEFAULT 或跳过，更新时间字段为空时绑定当前时间）
                 #(
-                    if is_updated_at_col(#update_option_field_columns) {
-                        if let Some(ref val) = self.#update_option_field_names {
-                            query = query.bind(val);
-                        } else {
-                            query = query.bind(::std::time::SystemTime::now().duration_since(::std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64);
-                        }
+                    if is_version_col(#update_option_field_columns) {
+                        // 版本值绑定在 WHERE 子句中
                     } else if let Some(ref val) = self.#update_option_field_names {
                         query = query.bind(val);
+                    } else if let Some(kind) = sqlxplus::timestamp::update_timestamp_kind::<Self>(#update_option_field_columns) {
+                        sqlxplus::bind_timestamp!(query, kind, now);
                     }
                 )*
-                query = query.bind(&self.#pk_ident);
-                query.execute(executor).await?;
+                query = sqlxplus::BindPrimaryKey::<DB>::bind_to(id, query);
+                if version_check.is_some() {
+                    #(
+                        if is_version_col(#update_normal_field_columns) {
+                            query = query.bind(&self.#update_normal_field_names);
+                        }
+                    )*
+                    #(
+                        if is_version_col(#update_option_field_columns) {
+                            if let Some(ref val) = self.#update_option_field_names {
+                                query = query.bind(val);
+                            }
+                        }
+                    )*
+                }
+                let result = query.execute(executor).await?;
+                // 带版本条件却没有更新到任何行：记录已被其他事务修改（或已不存在）
+                if let Some(version_col) = version_check {
+                    if DB::rows_affected(&result) == 0 {
+                        return Err(sqlxplus::SqlxPlusError::StaleVersion(format!(
+                            "{} row was modified concurrently or no longer exists ({} mismatch)",
+                            table, version_col
+                        )));
+                    }
+                }
                 Ok(())
             }
         }
@@ -857,7 +1919,7 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
 
     // 生成 UpdateFields trait 实现（用于 UpdateBuilder 和 InsertBuilder）
     // 注意：只对 BindValue 支持的基本类型生成转换代码
-    // 对于复杂类型（如 DateTime、JsonValue 等），get_field_value 返回 None
+    // 对于复杂类型（如 NaiveDate、JsonValue 等），get_field_value 返回 None
     // InsertBuilder 和 UpdateBuilder 需要直接使用 sqlx::bind 来处理这些类型
     let update_fields_impl = quote! {
         impl sqlxplus::builder::update_builder::UpdateFields for #name {
@@ -889,6 +1951,11 @@ pub fn derive_crud(input: TokenStream) -> TokenStream {
                 ]
             }
 
+            fn insert_pk_value(&self) -> Option<<Self as sqlxplus::Model>::Id> {
+                let insert_pk_value = #insert_pk_value_fn;
+                insert_pk_value(self)
+            }
+
             fn has_field(field_name: &str) -> bool {
                 // 支持字段名和列名两种匹配方式（处理 r#type 这样的原始标识符）
                 #(
@@ -929,22 +1996,31 @@ fn is_option_type(ty: &syn::Type) -> bool {
 }
 
 /// 检查类型是否是 BindValue 支持的基本类型
-/// 支持的类型：String, i64, i32, i16, f64, f32, bool, Vec<u8>
+/// 支持的类型：String, i64, i32, i16, f64, f32, bool, Vec<u8>, NaiveDateTime, DateTime<Utc>
 fn is_bind_value_supported_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty {
         if let Some(seg) = type_path.path.segments.last() {
             let type_name = seg.ident.to_string();
             // 检查是否是支持的基本类型
             match type_name.as_str() {
-                "String" | "i64" | "i32" | "i16" | "f64" | "f32" | "bool" => true,
+                "String" | "i64" | "i32" | "i16" | "f64" | "f32" | "bool" | "NaiveDateTime" => true,
+                "DateTime" => {
+                    // 对于 DateTime，只支持 DateTime<Utc>
+                    if let syn::PathArguments::AngleBracketed(args) = &seg.arguments {
+                        if let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first() {
+                            return last_type_ident(inner_ty).as_deref() == Some("Utc");
+                        }
+                    }
+                    false
+                }
                 "Vec" => {
                     // 对于 Vec，检查是否是 Vec<u8>
                     if let syn::PathArguments::AngleBracketed(args) = &seg.arguments {
-                        if let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first() {
-                            if let syn::Type::Path(inner_path) = inner_ty {
-                                if let Some(inner_seg) = inner_path.path.segments.last() {
-                                    return inner_seg.ident == "u8";
-                                }
+                        if let Some(syn::GenericArgument::Type(syn::Type::Path(inner_path))) =
+                            args.args.first()
+                        {
+                            if let Some(inner_seg) = inner_path.path.segments.last() {
+                                return inner_seg.ident == "u8";
                             }
                         }
                     }
@@ -3,6 +3,13 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use sqlxplus::{Crud, DbPool, DeleteBuilder, InsertBuilder, QueryBuilder, UpdateBuilder};
 use test_models::User;
 
+/// 只包含部分字段的用户查询结果
+#[derive(Debug, sqlx::FromRow)]
+struct UserBrief {
+    id: i64,
+    username: Option<String>,
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
@@ -155,7 +162,7 @@ async fn main() -> anyhow::Result<()> {
 
     // LIKE 查询
     let builder = QueryBuilder::new("SELECT * FROM user")
-        .and_like("username", &format!("user1_{}", timestamp));
+        .and_like("username", format!("user1_{}", timestamp));
     let count = User::count(pool.sqlite_pool(), builder).await?;
     println!("LIKE 查询: {} 条记录", count);
 
@@ -523,12 +530,132 @@ async fn main() -> anyhow::Result<()> {
     // 使用复杂 WHERE 条件删除
     let affected = DeleteBuilder::<User>::new()
         .condition(|b| {
-            b.and_like("username", &format!("delete_user%_{}", timestamp))
+            b.and_like("username", format!("delete_user%_{}", timestamp))
                 .and_eq("is_del", 0i16)
         })
         .execute(pool.sqlite_pool())
         .await?;
-    println!("使用复杂条件删除，受影响行数: {}\n", affected);
+    println!("使用复杂条件删除，受影响行数: {}", affected);
+
+    // User 定义了逻辑删除字段，以上删除只是标记；force() 物理删除已标记的测试数据
+    let affected = DeleteBuilder::<User>::new()
+        .condition(|b| {
+            b.only_trashed()
+                .and_like("username", format!("delete_user%_{}", timestamp))
+        })
+        .force()
+        .execute(pool.sqlite_pool())
+        .await?;
+    println!("物理删除已标记的测试数据，受影响行数: {}\n", affected);
+
+    // ========== 23. INSERT_MANY - 批量插入 ==========
+    println!("=== 23. INSERT_MANY - 批量插入 ===");
+    let batch_users: Vec<User> = (0..3)
+        .map(|i| User {
+            id: None,
+            username: Some(format!("batch_user{}_{}", i, timestamp)),
+            email: Some(format!("batch_user{}_{}@example.com", i, timestamp)),
+            is_del: Some(0i16),
+            ..Default::default()
+        })
+        .collect();
+    let batch_ids = User::insert_many(&batch_users, pool.sqlite_pool()).await?;
+    println!("批量插入 {} 条记录，ID: {:?}", batch_ids.len(), batch_ids);
+
+    let builder_users: Vec<User> = (0..2)
+        .map(|i| User {
+            id: None,
+            username: Some(format!("batch_builder{}_{}", i, timestamp)),
+            email: Some(format!("batch_builder{}_{}@example.com", i, timestamp)),
+            ..Default::default()
+        })
+        .collect();
+    let builder_ids = InsertBuilder::many(builder_users)
+        .fields(&["username", "email"])
+        .execute_many(pool.sqlite_pool())
+        .await?;
+    println!("InsertBuilder 批量插入，ID: {:?}\n", builder_ids);
+
+    // ========== 24. UPSERT - 冲突时更新 / 忽略 ==========
+    println!("=== 24. UPSERT - 冲突时更新 / 忽略 ===");
+    let upsert_user = |nick_name: &str| User {
+        id: None,
+        username: Some(format!("upsert_user_{}", timestamp)),
+        nick_name: Some(nick_name.to_string()),
+        email: Some(format!("upsert_user_{}@example.com", timestamp)),
+        is_del: Some(0i16),
+        ..Default::default()
+    };
+    let upsert_id = InsertBuilder::new(upsert_user("upsert_nick"))
+        .on_conflict(&["email"])
+        .do_update(&["nick_name"])
+        .execute(pool.sqlite_pool())
+        .await?;
+    println!("首次 upsert，ID: {}", upsert_id);
+    let updated_id = InsertBuilder::new(upsert_user("upsert_nick_updated"))
+        .on_conflict(&["email"])
+        .do_update(&["nick_name"])
+        .execute(pool.sqlite_pool())
+        .await?;
+    println!("冲突更新 nick_name，ID: {}", updated_id);
+    let ignored_id = InsertBuilder::new(upsert_user("upsert_nick_ignored"))
+        .do_nothing()
+        .execute_optional(pool.sqlite_pool())
+        .await?;
+    println!("冲突忽略，返回 ID: {:?}\n", ignored_id);
+
+    // ========== 25. BULK UPDATE - 无模型批量更新 ==========
+    println!("=== 25. BULK UPDATE - 无模型批量更新 ===");
+    let bulk_affected = UpdateBuilder::<User>::bulk()
+        .set("state", 2i16)
+        .set_expr("num", "COALESCE(num, 0) + ?", 1i64)
+        .set_null("nick_name")
+        .condition(|q| q.and_in("id", batch_ids.clone()))
+        .execute(pool.sqlite_pool())
+        .await?;
+    println!("批量更新影响行数: {}\n", bulk_affected);
+
+    // ========== 26. TYPED COLUMNS - 类型化列常量 ==========
+    println!("=== 26. TYPED COLUMNS - 类型化列常量 ===");
+    let typed_builder = QueryBuilder::new("")
+        .and_in(User::ID, batch_ids.clone())
+        .and_eq(User::STATE, 2i16)
+        .order_by(User::ID, true);
+    let typed_users = User::find_all(pool.sqlite_pool(), Some(typed_builder)).await?;
+    println!("User 共 {} 列，查询到 {} 条记录\n", User::columns().len(), typed_users.len());
+
+    // ========== 27. FIND_ALL_AS - 列投影到 DTO ==========
+    println!("=== 27. FIND_ALL_AS - 列投影到 DTO ===");
+    let brief_builder = QueryBuilder::new("")
+        .select(&[User::ID, User::USERNAME])
+        .and_in(User::ID, batch_ids.clone());
+    let briefs = User::find_all_as::<UserBrief, _>(pool.sqlite_pool(), Some(brief_builder)).await?;
+    for brief in &briefs {
+        println!("  id={}, username={:?}", brief.id, brief.username);
+    }
+    println!();
+
+    // ========== 28. SUBQUERY - IN / EXISTS 子查询 ==========
+    println!("=== 28. SUBQUERY - IN / EXISTS 子查询 ===");
+    let state_sub = QueryBuilder::new("SELECT id FROM user").and_eq(User::STATE, 2i16);
+    let deleted_sub = QueryBuilder::new("SELECT 1 FROM user t")
+        .and_eq_column("t.id", "user.id")
+        .and_eq("t.is_del", 1i16);
+    let sub_builder = QueryBuilder::new("")
+        .and_in(User::ID, batch_ids.clone())
+        .and_in_subquery(User::ID, state_sub)
+        .and_not_exists(deleted_sub);
+    let sub_users = User::find_all(pool.sqlite_pool(), Some(sub_builder)).await?;
+    println!("子查询条件匹配 {} 条记录\n", sub_users.len());
+
+    // ========== 29. TRASHED / RESTORE - 已删除记录查询与恢复 ==========
+    println!("=== 29. TRASHED / RESTORE - 已删除记录查询与恢复 ===");
+    let trashed = User::count(pool.sqlite_pool(), QueryBuilder::new("").only_trashed()).await?;
+    let with_trashed = User::count(pool.sqlite_pool(), QueryBuilder::new("").with_trashed()).await?;
+    println!("已删除 {} 条，含已删除共 {} 条", trashed, with_trashed);
+    User::restore_by_id(pool.sqlite_pool(), id2).await?;
+    let restored = User::find_by_id(pool.sqlite_pool(), id2).await?;
+    println!("恢复 ID={} 后 find_by_id 返回记录: {}\n", id2, restored.is_some());
 
     println!("所有 CRUD 方法测试完成！");
     Ok(())